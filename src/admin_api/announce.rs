@@ -0,0 +1,24 @@
+use axum::{extract::State, http::StatusCode, Json};
+
+use crate::{world::WorldHandle, SETTINGS};
+
+use super::log_action;
+
+#[derive(Deserialize)]
+pub struct AnnounceRequest {
+    message: String,
+}
+
+pub async fn announce(
+    State(world): State<WorldHandle>,
+    Json(request): Json<AnnounceRequest>,
+) -> StatusCode {
+    if request.message.is_empty() {
+        return StatusCode::BAD_REQUEST;
+    }
+
+    let admin_name = SETTINGS.admin_api.admin_name.to_owned();
+    log_action(&world, &admin_name, "announce", &request.message, None);
+    world.broadcast_announcement(admin_name, request.message);
+    StatusCode::ACCEPTED
+}
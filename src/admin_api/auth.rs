@@ -0,0 +1,25 @@
+use axum::{
+    extract::Request,
+    http::{header::AUTHORIZATION, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+
+use subtle::ConstantTimeEq;
+
+use crate::SETTINGS;
+
+pub async fn auth(request: Request, next: Next) -> Result<Response, StatusCode> {
+    let token = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match token {
+        Some(token) if bool::from(token.as_bytes().ct_eq(SETTINGS.admin_api.token.as_bytes())) => {
+            Ok(next.run(request).await)
+        }
+        _ => Err(StatusCode::UNAUTHORIZED),
+    }
+}
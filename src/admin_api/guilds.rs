@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use axum::{extract::State, Json};
+
+use crate::world::WorldHandle;
+
+pub async fn list(State(world): State<WorldHandle>) -> Json<HashMap<String, Vec<String>>> {
+    let names: HashMap<i32, String> = world
+        .get_online_characters()
+        .await
+        .into_iter()
+        .filter_map(|character| Some((character.player_id?, character.name)))
+        .collect();
+
+    Json(
+        world
+            .get_guilds()
+            .await
+            .into_iter()
+            .map(|(tag, player_ids)| {
+                (
+                    tag,
+                    player_ids
+                        .iter()
+                        .filter_map(|player_id| names.get(player_id).cloned())
+                        .collect(),
+                )
+            })
+            .collect(),
+    )
+}
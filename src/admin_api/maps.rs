@@ -0,0 +1,13 @@
+use axum::{extract::State, Json};
+
+use crate::{map::MapStats, world::WorldHandle};
+
+pub async fn list(State(world): State<WorldHandle>) -> Json<Vec<MapStats>> {
+    let mut stats = Vec::new();
+    for map in world.get_maps().await {
+        stats.push(map.get_stats().await);
+    }
+
+    stats.sort_by_key(|map| map.id);
+    Json(stats)
+}
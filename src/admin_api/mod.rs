@@ -0,0 +1,78 @@
+use axum::{
+    middleware,
+    routing::{get, post},
+    Router,
+};
+use tokio::net::TcpListener;
+
+use crate::{
+    world::{AdminLogEntry, WorldHandle},
+    SETTINGS,
+};
+
+mod announce;
+mod auth;
+mod guilds;
+mod maps;
+mod parties;
+mod players;
+mod save;
+
+pub async fn serve(world: WorldHandle) {
+    if SETTINGS.admin_api.token.is_empty() {
+        error!("Admin API is enabled but no token is configured. Refusing to start it.");
+        return;
+    }
+
+    let listener = match TcpListener::bind(format!(
+        "{}:{}",
+        SETTINGS.admin_api.host, SETTINGS.admin_api.port
+    ))
+    .await
+    {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("Failed to bind admin API listener: {}", e);
+            return;
+        }
+    };
+
+    info!(
+        "admin api listening at {}:{}",
+        SETTINGS.admin_api.host, SETTINGS.admin_api.port
+    );
+
+    let app = Router::new()
+        .route("/players", get(players::list))
+        .route("/players/:name/kick", post(players::kick))
+        .route("/players/:name/ban", post(players::ban))
+        .route("/players/:name/jail", post(players::jail))
+        .route("/players/:name/mute", post(players::mute))
+        .route("/maps", get(maps::list))
+        .route("/parties", get(parties::list))
+        .route("/guilds", get(guilds::list))
+        .route("/announce", post(announce::announce))
+        .route("/save", post(save::save))
+        .layer(middleware::from_fn(auth::auth))
+        .with_state(world);
+
+    if let Err(e) = axum::serve(listener, app).await {
+        error!("Admin API stopped: {}", e);
+    }
+}
+
+fn log_action(
+    world: &WorldHandle,
+    admin_name: &str,
+    command: &str,
+    args: &str,
+    target: Option<&str>,
+) {
+    world.log_admin_action(AdminLogEntry {
+        admin_name: admin_name.to_owned(),
+        command: command.to_owned(),
+        args: args.to_owned(),
+        target: target.map(|target| target.to_owned()),
+        ..Default::default()
+    });
+}
@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+use axum::{extract::State, Json};
+
+use crate::world::WorldHandle;
+
+#[derive(Serialize)]
+pub struct PartyInfo {
+    leader: String,
+    members: Vec<String>,
+}
+
+pub async fn list(State(world): State<WorldHandle>) -> Json<Vec<PartyInfo>> {
+    let names: HashMap<i32, String> = world
+        .get_online_characters()
+        .await
+        .into_iter()
+        .filter_map(|character| Some((character.player_id?, character.name)))
+        .collect();
+
+    let name = |player_id: &i32| names.get(player_id).cloned().unwrap_or_default();
+
+    Json(
+        world
+            .get_parties()
+            .await
+            .iter()
+            .map(|party| PartyInfo {
+                leader: name(&party.leader),
+                members: party.members.iter().map(name).collect(),
+            })
+            .collect(),
+    )
+}
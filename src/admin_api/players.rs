@@ -0,0 +1,154 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+
+use crate::{
+    world::{BanScope, WorldHandle},
+    SETTINGS,
+};
+
+use super::log_action;
+
+#[derive(Serialize)]
+pub struct PlayerInfo {
+    name: String,
+    title: Option<String>,
+    admin_level: i32,
+    class: i32,
+    level: i32,
+    guild_tag: Option<String>,
+    map_id: i32,
+    x: i32,
+    y: i32,
+}
+
+#[derive(Default, Deserialize)]
+pub struct ActionRequest {
+    #[serde(default)]
+    silent: bool,
+    #[serde(default)]
+    duration: String,
+    #[serde(default)]
+    scope: BanScope,
+}
+
+pub async fn list(State(world): State<WorldHandle>) -> Json<Vec<PlayerInfo>> {
+    let characters = world.get_online_characters().await;
+    Json(
+        characters
+            .into_iter()
+            .map(|character| PlayerInfo {
+                name: character.name,
+                title: character.title,
+                admin_level: i32::from(character.admin_level),
+                class: character.class,
+                level: character.level,
+                guild_tag: character.guild_tag,
+                map_id: character.map_id,
+                x: character.coords.x,
+                y: character.coords.y,
+            })
+            .collect(),
+    )
+}
+
+pub async fn kick(
+    State(world): State<WorldHandle>,
+    Path(name): Path<String>,
+    request: Option<Json<ActionRequest>>,
+) -> StatusCode {
+    let request = request.map(|Json(request)| request).unwrap_or_default();
+    let name = name.to_lowercase();
+    if world.get_character_by_name(&name).await.is_err() {
+        return StatusCode::NOT_FOUND;
+    }
+
+    let admin_name = SETTINGS.admin_api.admin_name.to_owned();
+    let command = if request.silent { "skick" } else { "kick" };
+    log_action(&world, &admin_name, command, &name, Some(&name));
+    world.kick_player(name, admin_name, request.silent);
+    StatusCode::ACCEPTED
+}
+
+pub async fn ban(
+    State(world): State<WorldHandle>,
+    Path(name): Path<String>,
+    request: Option<Json<ActionRequest>>,
+) -> StatusCode {
+    let request = request.map(|Json(request)| request).unwrap_or_default();
+    let name = name.to_lowercase();
+    if let Err(status) = character_exists(&world, &name).await {
+        return status;
+    }
+
+    let admin_name = SETTINGS.admin_api.admin_name.to_owned();
+    let command = if request.silent { "sban" } else { "ban" };
+    log_action(
+        &world,
+        &admin_name,
+        command,
+        &format!("{} {} {}", name, request.duration, request.scope.as_str()),
+        Some(&name),
+    );
+    world.ban_player(
+        name,
+        request.duration,
+        request.scope,
+        admin_name,
+        request.silent,
+    );
+    StatusCode::ACCEPTED
+}
+
+pub async fn jail(State(world): State<WorldHandle>, Path(name): Path<String>) -> StatusCode {
+    let name = name.to_lowercase();
+    if let Err(status) = character_exists(&world, &name).await {
+        return status;
+    }
+
+    let admin_name = SETTINGS.admin_api.admin_name.to_owned();
+    log_action(&world, &admin_name, "jail", &name, Some(&name));
+    world.jail_player(name, admin_name);
+    StatusCode::ACCEPTED
+}
+
+pub async fn mute(
+    State(world): State<WorldHandle>,
+    Path(name): Path<String>,
+    request: Option<Json<ActionRequest>>,
+) -> StatusCode {
+    let request = request.map(|Json(request)| request).unwrap_or_default();
+    let name = name.to_lowercase();
+    let admin_name = SETTINGS.admin_api.admin_name.to_owned();
+    if world
+        .mute_player(
+            name.to_owned(),
+            request.duration.to_owned(),
+            admin_name.to_owned(),
+        )
+        .await
+        .is_err()
+    {
+        return StatusCode::BAD_REQUEST;
+    }
+
+    log_action(
+        &world,
+        &admin_name,
+        "mute",
+        &format!("{} {}", name, request.duration),
+        Some(&name),
+    );
+    StatusCode::ACCEPTED
+}
+
+/// Ban and jail also work on offline characters so this checks the database
+async fn character_exists(world: &WorldHandle, name: &str) -> Result<(), StatusCode> {
+    match world.get_character_with_account(name.to_owned()).await {
+        Ok(Some(_)) => Ok(()),
+        Ok(None) => Err(StatusCode::NOT_FOUND),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -0,0 +1,8 @@
+use axum::{extract::State, http::StatusCode};
+
+use crate::world::WorldHandle;
+
+pub async fn save(State(world): State<WorldHandle>) -> StatusCode {
+    world.save();
+    StatusCode::ACCEPTED
+}
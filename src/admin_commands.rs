@@ -0,0 +1,774 @@
+use eolib::protocol::net::server::{TalkServerServerPacket, WarpEffect};
+use eolib::protocol::net::{PacketAction, PacketFamily};
+use eolib::protocol::Coords;
+
+use crate::commands::Command;
+use crate::{
+    character::Character,
+    map::ItemHolder,
+    player::PlayerHandle,
+    world::{AdminLogEntry, BanScope, WorldHandle},
+};
+
+use crate::{ITEM_DB, NPC_DB, SETTINGS};
+
+/// Name used for actions performed from the console
+pub const CONSOLE_ADMIN_NAME: &str = "Server";
+
+/// Who ran a command, and so where its replies go
+pub enum CommandIssuer<'a> {
+    Player {
+        character: &'a Character,
+        player: &'a PlayerHandle,
+    },
+    Console,
+}
+
+impl CommandIssuer<'_> {
+    fn name(&self) -> String {
+        match self {
+            Self::Player { character, .. } => character.name.to_owned(),
+            Self::Console => CONSOLE_ADMIN_NAME.to_owned(),
+        }
+    }
+
+    fn reply(&self, message: &str) {
+        match self {
+            Self::Player { player, .. } => player.send_server_message(message),
+            Self::Console => println!("{}", message),
+        }
+    }
+
+    fn error(&self, message: String) {
+        match self {
+            Self::Player { player, .. } => send_error_message(player, message),
+            Self::Console => println!("{}", message),
+        }
+    }
+
+    /// Prints the lines to the console or shows them in an info box
+    async fn show_lines(&self, title: String, lines: Vec<String>) {
+        let (character, player) = match self {
+            Self::Player { character, player } => (character, player),
+            Self::Console => {
+                println!("{}", title);
+                for line in lines {
+                    println!("  {}", line);
+                }
+                return;
+            }
+        };
+
+        if let Ok(map) = player.get_map().await {
+            map.show_info_box(character.player_id.unwrap(), title, lines);
+        }
+    }
+}
+
+/// Joins args for commands whose last (or all but the first) args can contain spaces
+pub fn prepare_args(command: &str, mut args: Vec<String>) -> Vec<String> {
+    if command == "spawnitem" && args.len() > 1 {
+        if let Ok(amount) = args.last().unwrap().parse::<u32>() {
+            // join all but the last arg into a single string
+            let item_name = args[..args.len() - 1].join(" ");
+            args = vec![item_name, amount.to_string()];
+        } else {
+            let item_name = args[..args.len()].join(" ");
+            args = vec![item_name];
+        }
+    }
+
+    if command == "itemtrace" && args.len() > 1 {
+        // item names can have spaces so the player is only split off
+        // when the whole thing isn't an item
+        let item_name = args.join(" ");
+        if get_item_id(&item_name).is_some() {
+            args = vec![item_name];
+        } else {
+            let player_name = args.pop().unwrap();
+            args = vec![args.join(" "), player_name];
+        }
+    }
+
+    if matches!(command, "shutdown" | "maintenance") && args.len() > 2 {
+        // join all but the first arg into a single reason/message
+        let reason = args[1..].join(" ");
+        args = vec![args[0].to_owned(), reason];
+    }
+
+    args
+}
+
+/// Validates args for a command run from the console, where commands that
+/// act on the admin's own character take the player or map explicitly
+pub fn validate_console_args(command: &Command, args: &[String]) -> Result<(), String> {
+    match command.name.as_str() {
+        "warp" => match args.split_first() {
+            Some((_, args)) => command.validate_args(args),
+            None => Err("Usage: warp player map [x] [y]".to_string()),
+        },
+        "remap" => match args {
+            [map_id] if map_id.parse::<i32>().is_ok() => Ok(()),
+            _ => Err("Usage: remap map".to_string()),
+        },
+        _ => command.validate_args(args),
+    }
+}
+
+/// Runs a command that has already been validated and permission checked
+pub async fn run_command(
+    command: &Command,
+    args: &[String],
+    issuer: &CommandIssuer<'_>,
+    world: &WorldHandle,
+) {
+    // Info commands aren't worth logging and mute logs itself once the
+    // duration and character have been checked
+    if !matches!(
+        command.name.as_str(),
+        "player" | "inventory" | "auditlog" | "itemtrace" | "economy" | "bans" | "mute"
+    ) {
+        log_command(command, args, issuer, world);
+    }
+
+    let admin_name = issuer.name();
+
+    match command.name.as_str() {
+        "jail" => world.jail_player(args[0].to_owned(), admin_name),
+        "free" => world.free_player(args[0].to_owned()),
+        "kick" => world.kick_player(args[0].to_owned(), admin_name, false),
+        "skick" => world.kick_player(args[0].to_owned(), admin_name, true),
+        "ban" | "sban" => ban(args, admin_name, command.name == "sban", issuer, world),
+        "quake" => {
+            if let Some(strength) = parse_arg(args.first(), 1, issuer) {
+                world.quake(strength);
+            }
+        }
+        "mute" => mute(command, args, issuer, world).await,
+        "freeze" => world.freeze_player(args[0].to_owned(), admin_name),
+        "unfreeze" => world.unfreeze_player(args[0].to_owned(), admin_name),
+        "global" => world.toggle_global(admin_name),
+        "captcha" => {
+            if let Some(reward) = parse_arg(args.get(1), 0, issuer) {
+                world.show_captcha(args[0].to_owned(), reward);
+            }
+        }
+        "shutdown" => shutdown(args, false, issuer, world),
+        "restart" => shutdown(args, true, issuer, world),
+        "unban" => unban(args, issuer, world).await,
+        "bans" => bans(args, issuer, world).await,
+        "reload" => reload(args, issuer, world).await,
+        "maintenance" => maintenance(args, admin_name, issuer, world),
+        "player" | "inventory" => player_info(&command.name, args, issuer, world).await,
+        "auditlog" => audit_log(args, issuer, world).await,
+        "itemtrace" => item_trace(args, issuer, world).await,
+        "economy" => match world.get_economy().await {
+            Ok((title, lines)) => issuer.show_lines(title, lines).await,
+            Err(message) => issuer.error(message),
+        },
+        _ => match issuer {
+            CommandIssuer::Player { character, player } => {
+                run_in_game_command(command, args, character, player, world).await
+            }
+            CommandIssuer::Console => run_console_command(command, args, issuer, world).await,
+        },
+    }
+}
+
+fn log_command(
+    command: &Command,
+    args: &[String],
+    issuer: &CommandIssuer<'_>,
+    world: &WorldHandle,
+) {
+    let (map_id, coords) = match issuer {
+        CommandIssuer::Player { character, .. } => (Some(character.map_id), Some(character.coords)),
+        CommandIssuer::Console => (None, None),
+    };
+
+    // the console form of warp takes the player first
+    let target = match issuer {
+        CommandIssuer::Console if command.name == "warp" => {
+            args.first().map(|name| name.to_lowercase())
+        }
+        _ => command.get_target(args),
+    };
+
+    world.log_admin_action(AdminLogEntry {
+        admin_name: issuer.name(),
+        command: command.name.to_owned(),
+        args: args.join(" "),
+        target,
+        map_id,
+        coords,
+    });
+}
+
+/// Console forms of in game commands, see [validate_console_args]
+async fn run_console_command(
+    command: &Command,
+    args: &[String],
+    issuer: &CommandIssuer<'_>,
+    world: &WorldHandle,
+) {
+    match command.name.as_str() {
+        "warp" => match world.get_character_by_name(&args[0]).await {
+            Ok(character) => match character.player.as_ref() {
+                Some(player) => warp(&args[1..], player, issuer, world).await,
+                None => issuer.error(format!("{} is not online", args[0])),
+            },
+            Err(_) => issuer.error(format!("{} is not online", args[0])),
+        },
+        "remap" => {
+            if let Some(map_id) = parse_arg(args.first(), 0, issuer) {
+                world.reload_map(map_id);
+            }
+        }
+        _ => issuer.error(format!(
+            "Command not available from the console: {}",
+            command.name
+        )),
+    }
+}
+
+/// Commands that act on or around the admin's own character
+async fn run_in_game_command(
+    command: &Command,
+    args: &[String],
+    character: &Character,
+    player: &PlayerHandle,
+    world: &WorldHandle,
+) {
+    match command.name.as_str() {
+        "hide" => hide(character).await,
+        "spawnitem" => spawn_item(args, character).await,
+        "spawnnpc" => spawn_npc(args, character).await,
+        "warp" => {
+            let issuer = CommandIssuer::Player { character, player };
+            warp(args, player, &issuer, world).await
+        }
+        "warptome" => warp_to_me(args, character, world).await,
+        "warpmeto" => warp_me_to(args, character, world).await,
+        "evacuate" => evacuate(character, world).await,
+        "remap" => world.reload_map(character.map_id),
+        _ => {
+            send_error_message(player, format!("Unimplemented command: {}", command.name));
+        }
+    }
+}
+
+/// Parses an optional numeric arg, replying with an error if it doesn't fit in an i32
+fn parse_arg(arg: Option<&String>, default: i32, issuer: &CommandIssuer<'_>) -> Option<i32> {
+    match arg {
+        Some(arg) => match arg.parse::<i32>() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                issuer.error(format!("Invalid number: {}", arg));
+                None
+            }
+        },
+        None => Some(default),
+    }
+}
+
+async fn warp(
+    args: &[String],
+    player: &PlayerHandle,
+    issuer: &CommandIssuer<'_>,
+    world: &WorldHandle,
+) {
+    let map_id = match parse_arg(args.first(), 0, issuer) {
+        Some(map_id) => map_id,
+        None => return,
+    };
+
+    if let Ok(map) = world.get_map(map_id).await {
+        let coords = if args.len() >= 3 {
+            match (
+                parse_arg(args.get(1), 0, issuer),
+                parse_arg(args.get(2), 0, issuer),
+            ) {
+                (Some(x), Some(y)) => Coords { x, y },
+                _ => return,
+            }
+        } else {
+            let map_size = map.get_dimensions().await;
+            Coords {
+                x: map_size.x / 2,
+                y: map_size.y / 2,
+            }
+        };
+
+        player.request_warp(map_id, coords, false, Some(WarpEffect::Admin))
+    } else {
+        issuer.error(format!("Map {} does not exist.", map_id));
+    }
+}
+
+// TODO: warp player to where you're facing
+async fn warp_to_me(args: &[String], character: &Character, world: &WorldHandle) {
+    let target_name = &args[0];
+
+    if let Ok(target) = world.get_character_by_name(target_name).await {
+        let target = match target.player.as_ref() {
+            Some(player) => player,
+            None => return,
+        };
+
+        target.request_warp(
+            character.map_id,
+            character.coords,
+            false,
+            Some(WarpEffect::Admin),
+        );
+    }
+}
+
+// TODO: Make light guide and guardian warp near player out of site with scroll warp
+async fn warp_me_to(args: &[String], character: &Character, world: &WorldHandle) {
+    let player = match character.player.as_ref() {
+        Some(player) => player,
+        None => return,
+    };
+
+    let target_name = &args[0];
+
+    if let Ok(target) = world.get_character_by_name(target_name).await {
+        player.request_warp(target.map_id, target.coords, false, Some(WarpEffect::Admin));
+    }
+}
+
+async fn evacuate(character: &Character, world: &WorldHandle) {
+    let map = match world.get_map(character.map_id).await {
+        Ok(map) => map,
+        Err(e) => {
+            error!("Failed to get map: {}", e);
+            return;
+        }
+    };
+
+    map.start_evacuate();
+}
+
+fn get_item_id(identifier: &str) -> Option<i32> {
+    match identifier.parse::<u32>() {
+        Ok(id) => Some(id as i32),
+        Err(_) => ITEM_DB
+            .load()
+            .items
+            .iter()
+            .position(|item| item.name.to_lowercase() == identifier.to_lowercase())
+            .map(|index| index as i32 + 1),
+    }
+}
+
+fn get_item_name(item_id: i32) -> String {
+    let item_db = ITEM_DB.load();
+    match (item_id as usize)
+        .checked_sub(1)
+        .and_then(|index| item_db.items.get(index))
+    {
+        Some(item) => item.name.to_owned(),
+        None => format!("Unknown item #{}", item_id),
+    }
+}
+
+async fn spawn_item(args: &[String], character: &Character) {
+    let player = match character.player.as_ref() {
+        Some(player) => player,
+        None => return,
+    };
+
+    let item_id = match get_item_id(&args[0]) {
+        Some(item_id) => item_id,
+        None => {
+            send_error_message(player, format!("No item found with name \"{}\".", args[0]));
+            return;
+        }
+    };
+
+    let amount = if args.len() >= 2 {
+        args[1].parse::<i32>().unwrap()
+    } else {
+        1
+    };
+
+    if let Ok(map) = player.get_map().await {
+        let player_id = match player.get_player_id().await {
+            Ok(player_id) => player_id,
+            Err(e) => {
+                error!("Failed to get player id: {}", e);
+                return;
+            }
+        };
+
+        map.give_item(player_id, item_id, amount, ItemHolder::Admin);
+    }
+}
+
+async fn spawn_npc(args: &[String], character: &Character) {
+    let player = match character.player.as_ref() {
+        Some(player) => player,
+        None => return,
+    };
+
+    let identifier = (*args[0]).to_string();
+
+    let npc_id = match identifier.parse::<u32>() {
+        Ok(id) => id as i32,
+        Err(_) => {
+            // find matches from item db where name starts with identifier
+            match NPC_DB
+                .load()
+                .npcs
+                .iter()
+                .position(|npc| npc.name.to_lowercase() == identifier.to_lowercase())
+            {
+                Some(index) => index as i32 + 1,
+                None => {
+                    send_error_message(
+                        player,
+                        format!("No npc found with name \"{}\".", identifier),
+                    );
+                    return;
+                }
+            }
+        }
+    };
+
+    let amount = if args.len() >= 2 {
+        args[1].parse::<i32>().unwrap()
+    } else {
+        1
+    };
+
+    let speed = if args.len() >= 3 {
+        args[2].parse::<i32>().unwrap()
+    } else {
+        3
+    };
+
+    if let Ok(map) = player.get_map().await {
+        let player_id = match player.get_player_id().await {
+            Ok(player_id) => player_id,
+            Err(e) => {
+                error!("Failed to get player id: {}", e);
+                return;
+            }
+        };
+
+        map.spawn_npc(player_id, npc_id, amount, speed);
+    }
+}
+
+async fn hide(character: &Character) {
+    let player = match character.player.as_ref() {
+        Some(player) => player,
+        None => {
+            return;
+        }
+    };
+
+    let player_id = match player.get_player_id().await {
+        Ok(player_id) => player_id,
+        Err(e) => {
+            error!("Failed to get player id: {}", e);
+            return;
+        }
+    };
+
+    if let Ok(map) = player.get_map().await {
+        map.toggle_hidden(player_id);
+    }
+}
+
+async fn audit_log(args: &[String], issuer: &CommandIssuer<'_>, world: &WorldHandle) {
+    let mut name = None;
+    let mut count = None;
+    for arg in args {
+        match arg.parse::<i32>() {
+            Ok(amount) => count = Some(amount),
+            Err(_) => name = Some(arg.to_lowercase()),
+        }
+    }
+
+    match world.get_audit_log(name, count).await {
+        Ok((title, lines)) => issuer.show_lines(title, lines).await,
+        Err(message) => issuer.error(message),
+    }
+}
+
+async fn item_trace(args: &[String], issuer: &CommandIssuer<'_>, world: &WorldHandle) {
+    let item_id = match get_item_id(&args[0]) {
+        Some(item_id) => item_id,
+        None => return issuer.error(format!("No item found with name \"{}\".", args[0])),
+    };
+
+    let name = args.get(1).map(|name| name.to_lowercase());
+
+    match world.get_item_trace(item_id, name).await {
+        Ok((title, lines)) => issuer.show_lines(title, lines).await,
+        Err(message) => issuer.error(message),
+    }
+}
+
+async fn player_info(
+    command: &str,
+    args: &[String],
+    issuer: &CommandIssuer<'_>,
+    world: &WorldHandle,
+) {
+    let name = args[0].to_lowercase();
+
+    let character = match issuer {
+        CommandIssuer::Player { character, .. } => character,
+        CommandIssuer::Console => return print_player_info(command, name, world).await,
+    };
+
+    if command == "player" {
+        world.request_player_info(character.player_id.unwrap(), name);
+    } else {
+        world.request_player_inventory(character.player_id.unwrap(), name);
+    }
+}
+
+async fn print_player_info(command: &str, name: String, world: &WorldHandle) {
+    let (character, account) = match world.get_character_with_account(name.to_owned()).await {
+        Ok(Some(result)) => result,
+        Ok(None) => return println!("{} does not exist", name),
+        Err(message) => return println!("{}", message),
+    };
+
+    println!(
+        "{} account: {} last login ip: {}",
+        character.name,
+        account.name,
+        account.last_login_ip.unwrap_or_default()
+    );
+
+    if command == "inventory" {
+        println!("{} inventory:", character.name);
+        for item in character.items.iter() {
+            println!("  {} x{}", get_item_name(item.id), item.amount);
+        }
+
+        println!("{} bank:", character.name);
+        for item in character.bank.iter() {
+            println!("  {} x{}", get_item_name(item.id), item.amount);
+        }
+        return;
+    }
+
+    println!(
+        "{} - level {} ({} exp), usage {} minutes",
+        character.name, character.level, character.experience, character.usage
+    );
+    println!(
+        "  map {} ({}, {})",
+        character.map_id, character.coords.x, character.coords.y
+    );
+    println!(
+        "  hp {}/{} tp {}/{}",
+        character.hp, character.max_hp, character.tp, character.max_tp
+    );
+    println!(
+        "  str {} int {} wis {} agi {} con {} cha {}",
+        character.adj_strength,
+        character.adj_intelligence,
+        character.adj_wisdom,
+        character.adj_agility,
+        character.adj_constitution,
+        character.adj_charisma
+    );
+    println!(
+        "  damage {}-{} accuracy {} evade {} armor {}",
+        character.min_damage,
+        character.max_damage,
+        character.accuracy,
+        character.evasion,
+        character.armor
+    );
+    println!(
+        "  weight {}/{} gold bank {}",
+        character.weight, character.max_weight, character.gold_bank
+    );
+}
+
+async fn mute(command: &Command, args: &[String], issuer: &CommandIssuer<'_>, world: &WorldHandle) {
+    match world
+        .mute_player(
+            args[0].to_lowercase(),
+            args.get(1).cloned().unwrap_or_default(),
+            issuer.name(),
+        )
+        .await
+    {
+        Ok(()) => log_command(command, args, issuer, world),
+        Err(message) => issuer.error(message),
+    }
+}
+
+async fn reload(args: &[String], issuer: &CommandIssuer<'_>, world: &WorldHandle) {
+    match world.reload(args[0].to_owned()).await {
+        Ok(()) => issuer.reply(&format!("Reloaded {}", args[0])),
+        Err(message) => issuer.error(message),
+    }
+}
+
+fn ban(
+    args: &[String],
+    admin_name: String,
+    silent: bool,
+    issuer: &CommandIssuer<'_>,
+    world: &WorldHandle,
+) {
+    let (duration, scope) = match parse_ban_args(&args[1..]) {
+        Ok(parsed) => parsed,
+        Err(message) => return issuer.error(message),
+    };
+
+    world.ban_player(args[0].to_owned(), duration, scope, admin_name, silent);
+}
+
+/// Splits the args after the name into a duration and scope. Both are
+/// optional so either can come first.
+fn parse_ban_args(args: &[String]) -> Result<(String, BanScope), String> {
+    let mut duration = String::new();
+    let mut scope = BanScope::default();
+    for arg in args {
+        match BanScope::parse(arg) {
+            Some(parsed) => scope = parsed,
+            None if duration.is_empty() => duration = arg.to_owned(),
+            None => return Err(format!("Unknown ban scope: {}", arg)),
+        }
+    }
+
+    Ok((duration, scope))
+}
+
+async fn unban(args: &[String], issuer: &CommandIssuer<'_>, world: &WorldHandle) {
+    let scopes = match world.unban(args[0].to_owned()).await {
+        Ok(scopes) if scopes.is_empty() => {
+            return issuer.error(format!("No bans found for {}", args[0]))
+        }
+        Ok(scopes) => scopes,
+        Err(message) => return issuer.error(message),
+    };
+
+    let mut lifted: Vec<&str> = scopes
+        .iter()
+        .filter(|scope| **scope != BanScope::Account)
+        .map(|scope| scope.as_str())
+        .collect();
+    lifted.sort_unstable();
+    lifted.dedup();
+
+    if lifted.is_empty() {
+        issuer.reply(&format!("Lifted {} ban(s) on {}", scopes.len(), args[0]));
+    } else {
+        issuer.reply(&format!(
+            "Lifted {} ban(s) on {}, including {} bans",
+            scopes.len(),
+            args[0],
+            lifted.join("/")
+        ));
+    }
+}
+
+async fn bans(args: &[String], issuer: &CommandIssuer<'_>, world: &WorldHandle) {
+    let filter = args.first().map(|filter| filter.to_lowercase());
+
+    let bans = match world.get_bans(filter.clone()).await {
+        Ok(bans) => bans,
+        Err(message) => return issuer.error(message),
+    };
+
+    let mut lines: Vec<String> = bans.iter().map(|ban| ban.summary()).collect();
+    if lines.is_empty() {
+        lines.push("No active bans".to_string());
+    }
+
+    let title = match filter {
+        Some(filter) => format!("Bans - {}", filter),
+        None => "Bans".to_string(),
+    };
+
+    issuer.show_lines(title, lines).await;
+}
+
+fn maintenance(
+    args: &[String],
+    admin_name: String,
+    issuer: &CommandIssuer<'_>,
+    world: &WorldHandle,
+) {
+    let enabled = match args[0].as_str() {
+        "on" => true,
+        "off" => false,
+        _ => return issuer.error("Usage: $maintenance on|off [message]".to_string()),
+    };
+
+    world.set_maintenance(
+        enabled,
+        args.get(1).cloned().unwrap_or_default(),
+        admin_name,
+    );
+}
+
+fn shutdown(args: &[String], restart: bool, issuer: &CommandIssuer<'_>, world: &WorldHandle) {
+    let seconds = match parse_arg(args.first(), SETTINGS.shutdown.default_countdown, issuer) {
+        Some(seconds) => seconds,
+        None => return,
+    };
+
+    world.schedule_shutdown(
+        seconds,
+        if restart {
+            "".to_string()
+        } else {
+            args.get(1).cloned().unwrap_or_default()
+        },
+        restart,
+    );
+}
+
+fn send_error_message(player: &PlayerHandle, message: String) {
+    player.send(
+        PacketAction::Server,
+        PacketFamily::Talk,
+        &TalkServerServerPacket { message },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn ban_duration_and_scope_in_either_order() {
+        let expected = Ok(("1d".to_string(), BanScope::Ip));
+        assert_eq!(parse_ban_args(&args(&["1d", "ip"])), expected);
+        assert_eq!(parse_ban_args(&args(&["IP", "1d"])), expected);
+        assert_eq!(
+            parse_ban_args(&args(&["all"])),
+            Ok((String::new(), BanScope::All))
+        );
+    }
+
+    #[test]
+    fn ban_without_args_is_a_permanent_account_ban() {
+        assert_eq!(parse_ban_args(&[]), Ok((String::new(), BanScope::Account)));
+    }
+
+    #[test]
+    fn second_non_scope_ban_arg_is_an_unknown_scope() {
+        assert_eq!(
+            parse_ban_args(&args(&["1d", "machine"])),
+            Err("Unknown ban scope: machine".to_string())
+        );
+    }
+}
@@ -9,11 +9,10 @@ use eolib::protocol::{
 };
 use eoplus::Arg;
 use evalexpr::{context_map, eval_float_with_context, DefaultNumericTypes, HashMapContext};
-use mysql_async::Conn;
 use rand::Rng;
 use std::cmp;
 
-use crate::{player::PlayerHandle, EXP_TABLE, FORMULAS, QUEST_DB, SETTINGS};
+use crate::{db::Conn, player::PlayerHandle, EXP_TABLE, FORMULAS, QUEST_DB, SETTINGS};
 
 mod add_bank_item;
 mod add_item;
@@ -29,9 +28,12 @@ mod get_spawn_coords;
 mod get_spawn_map;
 mod get_stats;
 mod load;
+mod mute;
 pub use equipment_slot::EquipmentSlot;
 mod remove_item;
 mod reset;
+mod saved_state;
+pub use saved_state::SavedState;
 mod spell_target;
 pub use spell_target::SpellTarget;
 mod equip_result;
@@ -40,9 +42,11 @@ mod to_map_info;
 mod unequip;
 mod update;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Character {
     pub player_id: Option<i32>,
+    #[serde(skip)]
     pub player: Option<PlayerHandle>,
     pub is_deep: bool,
     pub id: i32,
@@ -66,7 +70,7 @@ pub struct Character {
     pub guild_rank_string: Option<String>,
     pub equipment: EquipmentPaperdoll,
     pub level: i32,
-    pub experience: i32,
+    pub experience: i64,
     pub hp: i32,
     pub max_hp: i32,
     pub tp: i32,
@@ -105,14 +109,17 @@ pub struct Character {
     pub trade_items: Vec<Item>,
     pub spells: Vec<Spell>,
     pub logged_in_at: Option<DateTime<Utc>>,
+    pub muted_until: Option<DateTime<Utc>>,
     pub quests: Vec<QuestProgress>,
     pub captcha_open: bool,
     pub warp_suck_ticks: i32,
     pub ghost_ticks: i32,
     pub auto_pickup_items: Vec<i32>,
+    #[serde(skip)]
+    pub saved_state: Option<Box<SavedState>>,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct QuestProgress {
     pub id: i32,
     pub state: i32,
@@ -184,7 +191,7 @@ impl Character {
             }
         };
 
-        let hit_rate = match eval_float_with_context(&FORMULAS.hit_rate, &context) {
+        let hit_rate = match eval_float_with_context(&FORMULAS.load().hit_rate, &context) {
             Ok(hit_rate) => hit_rate,
             Err(e) => {
                 error!("Failed to calculate hit rate: {}", e);
@@ -198,7 +205,7 @@ impl Character {
         let damage = if hit_rate < rand {
             0
         } else {
-            match eval_float_with_context(&FORMULAS.damage, &context) {
+            match eval_float_with_context(&FORMULAS.load().damage, &context) {
                 Ok(amount) => amount.floor() as i32,
                 Err(e) => {
                     error!("Failed to calculate damage: {}", e);
@@ -312,14 +319,18 @@ impl Character {
         }
     }
 
-    pub fn add_experience(&mut self, experience: i32) -> bool {
-        self.experience += experience;
+    pub fn add_experience(&mut self, experience: i64) -> bool {
+        self.experience = self.experience.saturating_add(experience);
 
         let mut leveled_up = false;
 
-        // TODO: Make this more accurate like official server
-        // http://archive.today/brypq
-        while self.experience > EXP_TABLE[self.level as usize + 1] {
+        // Experience keeps counting at the level cap but no more levels are
+        // gained
+        while let Some(next_level_exp) = EXP_TABLE.load().get(self.level as usize + 1) {
+            if self.experience < *next_level_exp {
+                break;
+            }
+
             self.level += 1;
             self.stat_points += SETTINGS.world.stat_points_per_level;
             self.skill_points += SETTINGS.world.skill_points_per_level;
@@ -330,10 +341,16 @@ impl Character {
         leveled_up
     }
 
+    /// Experience as sent to clients, which only have room for an i32
+    pub fn get_packet_experience(&self) -> i32 {
+        self.experience.clamp(0, i32::MAX as i64) as i32
+    }
+
     pub fn talked_to_npc(&mut self, behavior_id: i32, quest_id: i32, action_id: Option<i32>) {
         let mut progressed = false;
+        let quest_db = QUEST_DB.load();
         if let Some(progress) = self.quests.iter_mut().find(|q| q.id == quest_id) {
-            let quest = match QUEST_DB.get(&progress.id) {
+            let quest = match quest_db.get(&progress.id) {
                 Some(quest) => quest,
                 None => return,
             };
@@ -394,8 +411,9 @@ impl Character {
 
     pub fn killed_npc(&mut self, npc_id: i32) {
         let mut quests_progressed = Vec::new();
+        let quest_db = QUEST_DB.load();
         for progress in self.quests.iter_mut() {
-            let quest = match QUEST_DB.get(&progress.id) {
+            let quest = match quest_db.get(&progress.id) {
                 Some(quest) => quest,
                 None => continue,
             };
@@ -444,8 +462,9 @@ impl Character {
     pub fn entered_map(&mut self) {
         let mut quests_progressed = Vec::new();
         let map_id = self.map_id;
+        let quest_db = QUEST_DB.load();
         for progress in self.quests.iter_mut() {
-            let quest = match QUEST_DB.get(&progress.id) {
+            let quest = match quest_db.get(&progress.id) {
                 Some(quest) => quest,
                 None => continue,
             };
@@ -485,8 +504,9 @@ impl Character {
         let mut quests_progressed = Vec::new();
         let map_id = self.map_id;
         let coords = self.coords;
+        let quest_db = QUEST_DB.load();
         for progress in self.quests.iter_mut() {
-            let quest = match QUEST_DB.get(&progress.id) {
+            let quest = match quest_db.get(&progress.id) {
                 Some(quest) => quest,
                 None => continue,
             };
@@ -530,7 +550,8 @@ impl Character {
             None => return,
         };
 
-        let quest = match QUEST_DB.get(&quest_id) {
+        let quest_db = QUEST_DB.load();
+        let quest = match quest_db.get(&quest_id) {
             Some(quest) => quest,
             None => return,
         };
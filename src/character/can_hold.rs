@@ -10,7 +10,8 @@ impl Character {
             return 0;
         }
 
-        let item_data = match ITEM_DB.items.get(item_id as usize - 1) {
+        let item_db = ITEM_DB.load();
+        let item_data = match item_db.items.get(item_id as usize - 1) {
             Some(item_data) => item_data,
             None => return 0,
         };
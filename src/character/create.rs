@@ -1,6 +1,7 @@
-use mysql_async::{prelude::*, Conn, TxOpts};
-
-use crate::SETTINGS;
+use crate::{
+    db::{params, Conn, Queryable},
+    SETTINGS,
+};
 
 use super::Character;
 
@@ -9,10 +10,10 @@ impl Character {
         &mut self,
         conn: &mut Conn,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let mut tx = conn.start_transaction(TxOpts::default()).await?;
+        let mut tx = conn.start_transaction().await?;
 
         tx.exec_drop(
-            include_str!("../sql/create_character.sql"),
+            query!("create_character"),
             params! {
                 "account_id" => &self.account_id,
                 "name" => &self.name,
@@ -1,4 +1,4 @@
-use mysql_async::{prelude::*, Conn, TxOpts};
+use crate::db::{params, Conn, Queryable};
 
 use super::Character;
 
@@ -7,7 +7,7 @@ impl Character {
         &self,
         conn: &mut Conn,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let mut tx = conn.start_transaction(TxOpts::default()).await?;
+        let mut tx = conn.start_transaction().await?;
 
         tx.exec_drop(
             r"DELETE FROM `Stats` WHERE `character_id` = :character_id;",
@@ -3,11 +3,13 @@ use eolib::protocol::{
     net::{server::SitState, Item, Spell},
     AdminLevel, Direction, Gender,
 };
-use mysql_async::{prelude::*, Conn, Params, Row};
 
-use crate::SETTINGS;
+use crate::{
+    db::{params, Conn, Params, Queryable, Row},
+    SETTINGS,
+};
 
-use super::{Character, QuestProgress};
+use super::{Character, QuestProgress, SavedState};
 
 impl Character {
     pub async fn load(
@@ -50,6 +52,10 @@ impl Character {
         character.gold_bank = row.take("gold_bank").unwrap();
         character.guild_rank = row.take("guild_rank").unwrap();
         character.guild_rank_string = row.take("guild_rank_string").unwrap();
+        character.muted_until = row
+            .take::<Option<NaiveDateTime>, &str>("muted_until")
+            .unwrap()
+            .map(|muted_until| Utc.from_local_datetime(&muted_until).unwrap());
         character.equipment.boots = row.take("boots").unwrap();
         character.equipment.accessory = row.take("accessory").unwrap();
         character.equipment.gloves = row.take("gloves").unwrap();
@@ -178,6 +184,7 @@ impl Character {
 
         character.warp_suck_ticks = SETTINGS.world.warp_suck_rate;
         character.ghost_ticks = SETTINGS.world.ghost_rate;
+        character.saved_state = Some(Box::new(SavedState::new(&character)));
 
         Ok(character)
     }
@@ -0,0 +1,26 @@
+use chrono::Utc;
+
+use crate::LANG;
+
+use super::Character;
+
+impl Character {
+    pub fn is_muted(&self) -> bool {
+        match self.muted_until {
+            Some(muted_until) => muted_until > Utc::now(),
+            None => false,
+        }
+    }
+
+    pub fn send_muted_message(&self) {
+        let (player, muted_until) = match (&self.player, self.muted_until) {
+            (Some(player), Some(muted_until)) => (player, muted_until),
+            _ => return,
+        };
+
+        player.send_server_message(&get_lang_string!(
+            &LANG.load().muted,
+            time = muted_until.format("%Y-%m-%d %H:%M:%S UTC")
+        ));
+    }
+}
@@ -17,7 +17,7 @@ impl Character {
             existing_item.amount -= amount;
         }
 
-        if let Some(item) = ITEM_DB.items.get(item_id as usize - 1) {
+        if let Some(item) = ITEM_DB.load().items.get(item_id as usize - 1) {
             self.weight -= item.weight * amount;
         }
     }
@@ -28,8 +28,9 @@ impl Character {
         let total_amount = self.get_item_amount(item_id);
 
         let mut quests_progressed = Vec::new();
+        let quest_db = QUEST_DB.load();
         for progress in self.quests.iter_mut() {
-            let quest = match QUEST_DB.get(&progress.id) {
+            let quest = match quest_db.get(&progress.id) {
                 Some(quest) => quest,
                 None => continue,
             };
@@ -0,0 +1,114 @@
+use eolib::protocol::net::{Item, Spell};
+
+use crate::db::{params, Params};
+
+use super::{Character, QuestProgress};
+
+/// What was last written to the database for a character. Saves compare the
+/// live character against this so only rows that changed are written.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedState {
+    /// `None` when the row's contents aren't known and must be written
+    pub character: Option<Params>,
+    pub paperdoll: Option<Params>,
+    pub position: Option<Params>,
+    pub stats: Option<Params>,
+    pub items: Vec<Item>,
+    pub bank: Vec<Item>,
+    pub spells: Vec<Spell>,
+    pub quests: Vec<QuestProgress>,
+    pub auto_pickup_items: Vec<i32>,
+}
+
+impl SavedState {
+    pub fn new(character: &Character) -> Self {
+        Self {
+            character: Some(character.character_params()),
+            paperdoll: Some(character.paperdoll_params()),
+            position: Some(character.position_params()),
+            stats: Some(character.stats_params()),
+            items: character.items.clone(),
+            bank: character.bank.clone(),
+            spells: character.spells.clone(),
+            quests: character.quests.clone(),
+            auto_pickup_items: character.auto_pickup_items.clone(),
+        }
+    }
+}
+
+impl Character {
+    pub fn character_params(&self) -> Params {
+        params! {
+            "character_id" => self.id,
+            "title" => &self.title,
+            "home" => &self.home,
+            "fiance" => &self.fiance,
+            "partner" => &self.partner,
+            "admin_level" => i32::from(self.admin_level),
+            "class" => self.class as u32,
+            "gender" => i32::from(self.gender),
+            "race" => self.skin,
+            "hair_style" => self.hair_style as u32,
+            "hair_color" => self.hair_color as u32,
+            "bank_level" => self.bank_level,
+            "gold_bank" => self.gold_bank,
+            "guild_tag" => &self.guild_tag,
+            "guild_rank" => self.guild_rank,
+            "guild_rank_string" => &self.guild_rank_string,
+            "muted_until" => self.muted_until.map(|muted_until| muted_until.naive_utc()),
+        }
+    }
+
+    pub fn paperdoll_params(&self) -> Params {
+        params! {
+            "character_id" => self.id,
+            "boots" => self.equipment.boots as u32,
+            "accessory" => self.equipment.accessory as u32,
+            "gloves" => self.equipment.gloves as u32,
+            "belt" => self.equipment.belt as u32,
+            "armor" => self.equipment.armor as u32,
+            "necklace" => self.equipment.necklace as u32,
+            "hat" => self.equipment.hat as u32,
+            "shield" => self.equipment.shield as u32,
+            "weapon" => self.equipment.weapon as u32,
+            "ring" => self.equipment.ring[0] as u32,
+            "ring2" => self.equipment.ring[1] as u32,
+            "armlet" => self.equipment.armlet[0] as u32,
+            "armlet2" => self.equipment.armlet[1] as u32,
+            "bracer" => self.equipment.bracer[0] as u32,
+            "bracer2" => self.equipment.bracer[1] as u32,
+        }
+    }
+
+    pub fn position_params(&self) -> Params {
+        params! {
+            "character_id" => self.id,
+            "map_id" => self.map_id as u32,
+            "x" => self.coords.x as u32,
+            "y" => self.coords.y as u32,
+            "direction" => i32::from(self.direction),
+            "sitting" => i32::from(self.sit_state),
+            "hidden" => i32::from(self.hidden),
+        }
+    }
+
+    pub fn stats_params(&self) -> Params {
+        params! {
+            "character_id" => self.id,
+            "level" => self.level as u32,
+            "experience" => self.experience,
+            "hp" => self.hp as u32,
+            "tp" => self.tp as u32,
+            "strength" => self.base_strength as u32,
+            "intelligence" => self.base_intelligence as u32,
+            "wisdom" => self.base_wisdom as u32,
+            "agility" => self.base_agility as u32,
+            "constitution" => self.base_constitution as u32,
+            "charisma" => self.base_charisma as u32,
+            "stat_points" => self.stat_points as u32,
+            "skill_points" => self.skill_points as u32,
+            "karma" => self.karma as u32,
+            "usage" => self.usage,
+        }
+    }
+}
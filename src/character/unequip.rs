@@ -11,7 +11,8 @@ impl Character {
             return false;
         }
 
-        let item_record = match ITEM_DB.items.get(item_id as usize - 1) {
+        let item_db = ITEM_DB.load();
+        let item_record = match item_db.items.get(item_id as usize - 1) {
             Some(item) => item,
             None => return false,
         };
@@ -111,8 +112,9 @@ impl Character {
         }
 
         let mut quests_progressed = Vec::new();
+        let quest_db = QUEST_DB.load();
         for progress in self.quests.iter_mut() {
-            let quest = match QUEST_DB.get(&progress.id) {
+            let quest = match quest_db.get(&progress.id) {
                 Some(quest) => quest,
                 None => continue,
             };
@@ -1,161 +1,77 @@
 use eolib::protocol::net::{Item, Spell};
-use mysql_async::{prelude::*, Conn, Row, TxOpts};
 
-use super::{Character, QuestProgress};
+use crate::{
+    db::{params, Conn, Queryable, Row},
+    metrics::{CHARACTER_SAVES, CHARACTER_SAVE_DURATION, CHARACTER_SAVE_ROWS},
+};
+
+use super::{Character, QuestProgress, SavedState};
 
 impl Character {
     pub async fn update(
-        &self,
+        &mut self,
         conn: &mut Conn,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let old_items = conn
-            .exec_map(
-                include_str!("../sql/get_character_inventory.sql"),
-                params! {
-                    "character_id" => self.id,
-                },
-                |mut row: Row| Item {
-                    id: row.take(0).unwrap(),
-                    amount: row.take(1).unwrap(),
-                },
-            )
-            .await?;
+        let timer = CHARACTER_SAVE_DURATION.start_timer();
 
-        let old_bank = conn
-            .exec_map(
-                include_str!("../sql/get_character_bank.sql"),
-                params! {
-                    "character_id" => self.id,
-                },
-                |mut row: Row| Item {
-                    id: row.take(0).unwrap(),
-                    amount: row.take(1).unwrap(),
-                },
+        // Taken rather than borrowed so a failed save falls back to reading
+        // the rows from the database next time
+        let saved = match self.saved_state.take() {
+            Some(saved) => *saved,
+            None => self.load_saved_state(conn).await?,
+        };
+
+        let current = SavedState::new(self);
+
+        if current == saved {
+            self.saved_state = Some(Box::new(saved));
+            CHARACTER_SAVES.with_label_values(&["unchanged"]).inc();
+            timer.stop_and_discard();
+            return Ok(());
+        }
+
+        let mut writes: Vec<(&str, &str)> = Vec::new();
+
+        let mut tx = conn.start_transaction().await?;
+
+        if current.character != saved.character {
+            tx.exec_drop(
+                include_str!("../sql/update_character.sql"),
+                current.character.clone().unwrap(),
             )
             .await?;
+            writes.push(("Character", "update"));
+        }
 
-        let old_spells = conn
-            .exec_map(
-                include_str!("../sql/get_character_spells.sql"),
-                params! {
-                    "character_id" => self.id,
-                },
-                |mut row: Row| Spell {
-                    id: row.take(0).unwrap(),
-                    level: row.take(1).unwrap(),
-                },
+        if current.paperdoll != saved.paperdoll {
+            tx.exec_drop(
+                include_str!("../sql/update_paperdoll.sql"),
+                current.paperdoll.clone().unwrap(),
             )
             .await?;
+            writes.push(("Paperdoll", "update"));
+        }
 
-        let old_quests = conn
-            .exec_map(
-                include_str!("../sql/get_character_quest_progress.sql"),
-                params! {
-                    "character_id" => self.id,
-                },
-                |mut row: Row| QuestProgress {
-                    id: row.take(0).unwrap(),
-                    ..Default::default()
-                },
+        if current.position != saved.position {
+            tx.exec_drop(
+                include_str!("../sql/update_position.sql"),
+                current.position.clone().unwrap(),
             )
             .await?;
+            writes.push(("Position", "update"));
+        }
 
-        let old_auto_pickup = conn
-            .exec_map(
-                include_str!("../sql/get_character_auto_pickup.sql"),
-                params! {
-                    "character_id" => self.id,
-                },
-                |mut row: Row| row.take::<i32, usize>(0).unwrap(),
+        if current.stats != saved.stats {
+            tx.exec_drop(
+                include_str!("../sql/update_stats.sql"),
+                current.stats.clone().unwrap(),
             )
             .await?;
+            writes.push(("Stats", "update"));
+        }
 
-        let mut tx = conn.start_transaction(TxOpts::default()).await?;
-
-        tx.exec_drop(
-            include_str!("../sql/update_character.sql"),
-            params! {
-                "character_id" => self.id,
-                "title" => &self.title,
-                "home" => &self.home,
-                "fiance" => &self.fiance,
-                "partner" => &self.partner,
-                "admin_level" => i32::from(self.admin_level),
-                "class" => self.class as u32,
-                "gender" => i32::from(self.gender),
-                "race" => self.skin,
-                "hair_style" => self.hair_style as u32,
-                "hair_color" => self.hair_color as u32,
-                "bank_level" => self.bank_level,
-                "gold_bank" => self.gold_bank,
-                "guild_tag" => &self.guild_tag,
-                "guild_rank" => self.guild_rank,
-                "guild_rank_string" => &self.guild_rank_string,
-            },
-        )
-        .await?;
-
-        tx.exec_drop(
-            include_str!("../sql/update_paperdoll.sql"),
-            params! {
-                "character_id" => self.id,
-                "boots" => self.equipment.boots as u32,
-                "accessory" => self.equipment.accessory as u32,
-                "gloves" => self.equipment.gloves as u32,
-                "belt" => self.equipment.belt as u32,
-                "armor" => self.equipment.armor as u32,
-                "necklace" => self.equipment.necklace as u32,
-                "hat" => self.equipment.hat as u32,
-                "shield" => self.equipment.shield as u32,
-                "weapon" => self.equipment.weapon as u32,
-                "ring" => self.equipment.ring[0] as u32,
-                "ring2" => self.equipment.ring[1] as u32,
-                "armlet" => self.equipment.armlet[0] as u32,
-                "armlet2" => self.equipment.armlet[1] as u32,
-                "bracer" => self.equipment.bracer[0] as u32,
-                "bracer2" => self.equipment.bracer[1] as u32,
-            },
-        )
-        .await?;
-
-        tx.exec_drop(
-            include_str!("../sql/update_position.sql"),
-            params! {
-                "character_id" => self.id,
-                "map_id" => self.map_id as u32,
-                "x" => self.coords.x as u32,
-                "y" => self.coords.y as u32,
-                "direction" => i32::from(self.direction),
-                "sitting" => i32::from(self.sit_state),
-                "hidden" => i32::from(self.hidden),
-            },
-        )
-        .await?;
-
-        tx.exec_drop(
-            include_str!("../sql/update_stats.sql"),
-            params! {
-                "character_id" => self.id,
-                "level" => self.level as u32,
-                "experience" => self.experience,
-                "hp" => self.hp as u32,
-                "tp" => self.tp as u32,
-                "strength" => self.base_strength as u32,
-                "intelligence" => self.base_intelligence as u32,
-                "wisdom" => self.base_wisdom as u32,
-                "agility" => self.base_agility as u32,
-                "constitution" => self.base_constitution as u32,
-                "charisma" => self.base_charisma as u32,
-                "stat_points" => self.stat_points as u32,
-                "skill_points" => self.skill_points as u32,
-                "karma" => self.karma as u32,
-                "usage" => self.usage,
-            },
-        )
-        .await?;
-
-        for spell in &old_spells {
-            if !self.has_spell(spell.id) {
+        for spell in &saved.spells {
+            if !current.spells.iter().any(|s| s.id == spell.id) {
                 tx.exec_drop(
                     include_str!("../sql/delete_spell.sql"),
                     params! {
@@ -164,35 +80,42 @@ impl Character {
                     },
                 )
                 .await?;
+                writes.push(("Spell", "delete"));
             }
         }
 
-        for spell in &self.spells {
-            if !old_spells.iter().any(|s| s.id == spell.id) {
-                tx.exec_drop(
-                    include_str!("../sql/create_spell.sql"),
-                    params! {
-                        "character_id" => self.id,
-                        "spell_id" => spell.id,
-                        "level" => spell.level,
-                    },
-                )
-                .await?;
-            } else {
-                tx.exec_drop(
-                    include_str!("../sql/update_spell.sql"),
-                    params! {
-                        "character_id" => self.id,
-                        "spell_id" => spell.id,
-                        "level" => spell.level,
-                    },
-                )
-                .await?;
+        for spell in &current.spells {
+            match saved.spells.iter().find(|s| s.id == spell.id) {
+                None => {
+                    tx.exec_drop(
+                        include_str!("../sql/create_spell.sql"),
+                        params! {
+                            "character_id" => self.id,
+                            "spell_id" => spell.id,
+                            "level" => spell.level,
+                        },
+                    )
+                    .await?;
+                    writes.push(("Spell", "insert"));
+                }
+                Some(old) if old != spell => {
+                    tx.exec_drop(
+                        include_str!("../sql/update_spell.sql"),
+                        params! {
+                            "character_id" => self.id,
+                            "spell_id" => spell.id,
+                            "level" => spell.level,
+                        },
+                    )
+                    .await?;
+                    writes.push(("Spell", "update"));
+                }
+                _ => {}
             }
         }
 
-        for item in &old_items {
-            if !self.items.iter().any(|i| i.id == item.id) {
+        for item in &saved.items {
+            if !current.items.iter().any(|i| i.id == item.id) {
                 tx.exec_drop(
                     include_str!("../sql/delete_inventory_item.sql"),
                     params! {
@@ -201,35 +124,42 @@ impl Character {
                     },
                 )
                 .await?;
+                writes.push(("Inventory", "delete"));
             }
         }
 
-        for item in &self.items {
-            if !old_items.iter().any(|i| i.id == item.id) {
-                tx.exec_drop(
-                    include_str!("../sql/create_inventory_item.sql"),
-                    params! {
-                        "character_id" => self.id,
-                        "item_id" => item.id,
-                        "quantity" => item.amount,
-                    },
-                )
-                .await?;
-            } else {
-                tx.exec_drop(
-                    include_str!("../sql/update_inventory_item.sql"),
-                    params! {
-                        "character_id" => self.id,
-                        "item_id" => item.id,
-                        "quantity" => item.amount,
-                    },
-                )
-                .await?;
+        for item in &current.items {
+            match saved.items.iter().find(|i| i.id == item.id) {
+                None => {
+                    tx.exec_drop(
+                        include_str!("../sql/create_inventory_item.sql"),
+                        params! {
+                            "character_id" => self.id,
+                            "item_id" => item.id,
+                            "quantity" => item.amount,
+                        },
+                    )
+                    .await?;
+                    writes.push(("Inventory", "insert"));
+                }
+                Some(old) if old != item => {
+                    tx.exec_drop(
+                        include_str!("../sql/update_inventory_item.sql"),
+                        params! {
+                            "character_id" => self.id,
+                            "item_id" => item.id,
+                            "quantity" => item.amount,
+                        },
+                    )
+                    .await?;
+                    writes.push(("Inventory", "update"));
+                }
+                _ => {}
             }
         }
 
-        for item in &old_bank {
-            if !self.bank.iter().any(|i| i.id == item.id) {
+        for item in &saved.bank {
+            if !current.bank.iter().any(|i| i.id == item.id) {
                 tx.exec_drop(
                     include_str!("../sql/delete_bank_item.sql"),
                     params! {
@@ -238,35 +168,42 @@ impl Character {
                     },
                 )
                 .await?;
+                writes.push(("Bank", "delete"));
             }
         }
 
-        for item in &self.bank {
-            if !old_bank.iter().any(|i| i.id == item.id) {
-                tx.exec_drop(
-                    include_str!("../sql/create_bank_item.sql"),
-                    params! {
-                        "character_id" => self.id,
-                        "item_id" => item.id,
-                        "quantity" => item.amount,
-                    },
-                )
-                .await?;
-            } else {
-                tx.exec_drop(
-                    include_str!("../sql/update_bank_item.sql"),
-                    params! {
-                        "character_id" => self.id,
-                        "item_id" => item.id,
-                        "quantity" => item.amount,
-                    },
-                )
-                .await?;
+        for item in &current.bank {
+            match saved.bank.iter().find(|i| i.id == item.id) {
+                None => {
+                    tx.exec_drop(
+                        include_str!("../sql/create_bank_item.sql"),
+                        params! {
+                            "character_id" => self.id,
+                            "item_id" => item.id,
+                            "quantity" => item.amount,
+                        },
+                    )
+                    .await?;
+                    writes.push(("Bank", "insert"));
+                }
+                Some(old) if old != item => {
+                    tx.exec_drop(
+                        include_str!("../sql/update_bank_item.sql"),
+                        params! {
+                            "character_id" => self.id,
+                            "item_id" => item.id,
+                            "quantity" => item.amount,
+                        },
+                    )
+                    .await?;
+                    writes.push(("Bank", "update"));
+                }
+                _ => {}
             }
         }
 
-        for quest in &old_quests {
-            if !self.quests.iter().any(|q| q.id == quest.id) {
+        for quest in &saved.quests {
+            if !current.quests.iter().any(|q| q.id == quest.id) {
                 tx.exec_drop(
                     include_str!("../sql/delete_quest_progress.sql"),
                     params! {
@@ -275,10 +212,16 @@ impl Character {
                     },
                 )
                 .await?;
+                writes.push(("QuestProgress", "delete"));
             }
         }
 
-        for quest in &self.quests {
+        for quest in &current.quests {
+            let old = saved.quests.iter().find(|q| q.id == quest.id);
+            if old == Some(quest) {
+                continue;
+            }
+
             let mut npc_kills = String::from('{');
             for (index, (npc_id, kills)) in quest.npc_kills.iter().enumerate() {
                 npc_kills.push_str(&format!(
@@ -294,7 +237,7 @@ impl Character {
             }
             npc_kills.push('}');
 
-            if !old_quests.iter().any(|q| q.id == quest.id) {
+            if old.is_none() {
                 tx.exec_drop(
                     include_str!("../sql/create_quest_progress.sql"),
                     params! {
@@ -308,6 +251,7 @@ impl Character {
                     },
                 )
                 .await?;
+                writes.push(("QuestProgress", "insert"));
             } else {
                 tx.exec_drop(
                     include_str!("../sql/update_quest_progress.sql"),
@@ -322,11 +266,12 @@ impl Character {
                     },
                 )
                 .await?;
+                writes.push(("QuestProgress", "update"));
             }
         }
 
-        for item_id in &old_auto_pickup {
-            if !self.auto_pickup_items.iter().any(|i| i == item_id) {
+        for item_id in &saved.auto_pickup_items {
+            if !current.auto_pickup_items.contains(item_id) {
                 tx.exec_drop(
                     include_str!("../sql/delete_auto_pickup_item.sql"),
                     params! {
@@ -335,11 +280,12 @@ impl Character {
                     },
                 )
                 .await?;
+                writes.push(("AutoPickup", "delete"));
             }
         }
 
-        for item_id in &self.auto_pickup_items {
-            if !old_auto_pickup.iter().any(|i| i == item_id) {
+        for item_id in &current.auto_pickup_items {
+            if !saved.auto_pickup_items.contains(item_id) {
                 tx.exec_drop(
                     include_str!("../sql/create_auto_pickup_item.sql"),
                     params! {
@@ -348,11 +294,105 @@ impl Character {
                     },
                 )
                 .await?;
+                writes.push(("AutoPickup", "insert"));
             }
         }
 
         tx.commit().await?;
 
+        self.saved_state = Some(Box::new(current));
+
+        CHARACTER_SAVES.with_label_values(&["written"]).inc();
+        for (table, operation) in writes {
+            CHARACTER_SAVE_ROWS
+                .with_label_values(&[table, operation])
+                .inc();
+        }
+        timer.observe_duration();
+
         Ok(())
     }
+
+    /// Reads the stored collections for a character that has no snapshot,
+    /// e.g. one that was just created. Every row is treated as changed.
+    async fn load_saved_state(
+        &self,
+        conn: &mut Conn,
+    ) -> Result<SavedState, Box<dyn std::error::Error + Send + Sync>> {
+        let items = conn
+            .exec_map(
+                include_str!("../sql/get_character_inventory.sql"),
+                params! {
+                    "character_id" => self.id,
+                },
+                |mut row: Row| Item {
+                    id: row.take(0).unwrap(),
+                    amount: row.take(1).unwrap(),
+                },
+            )
+            .await?;
+
+        let bank = conn
+            .exec_map(
+                include_str!("../sql/get_character_bank.sql"),
+                params! {
+                    "character_id" => self.id,
+                },
+                |mut row: Row| Item {
+                    id: row.take(0).unwrap(),
+                    amount: row.take(1).unwrap(),
+                },
+            )
+            .await?;
+
+        let spells = conn
+            .exec_map(
+                include_str!("../sql/get_character_spells.sql"),
+                params! {
+                    "character_id" => self.id,
+                },
+                |mut row: Row| Spell {
+                    id: row.take(0).unwrap(),
+                    level: row.take(1).unwrap(),
+                },
+            )
+            .await?;
+
+        // Ids only, so every existing quest is rewritten
+        let quests = conn
+            .exec_map(
+                include_str!("../sql/get_character_quest_progress.sql"),
+                params! {
+                    "character_id" => self.id,
+                },
+                |mut row: Row| QuestProgress {
+                    id: row.take(0).unwrap(),
+                    state: -1,
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let auto_pickup_items = conn
+            .exec_map(
+                include_str!("../sql/get_character_auto_pickup.sql"),
+                params! {
+                    "character_id" => self.id,
+                },
+                |mut row: Row| row.take::<i32, usize>(0).unwrap(),
+            )
+            .await?;
+
+        Ok(SavedState {
+            character: None,
+            paperdoll: None,
+            position: None,
+            stats: None,
+            items,
+            bank,
+            spells,
+            quests,
+            auto_pickup_items,
+        })
+    }
 }
@@ -10,6 +10,8 @@ pub enum ArgType {
 
 #[derive(Debug, Deserialize)]
 pub struct Arg {
+    #[serde(default)]
+    pub name: String,
     pub r#type: ArgType,
     #[serde(default)]
     pub required: bool,
@@ -39,3 +41,53 @@ impl Commands {
         s.try_deserialize()
     }
 }
+
+impl Command {
+    /// Returns the player targeted by the command, if it has a `player` arg
+    pub fn get_target(&self, args: &[String]) -> Option<String> {
+        self.args
+            .iter()
+            .position(|arg| arg.name == "player")
+            .and_then(|index| args.get(index))
+            .map(|name| name.to_lowercase())
+    }
+
+    pub fn validate_args(&self, args: &[String]) -> Result<(), String> {
+        let required_args_length = self.args.iter().filter(|arg| arg.required).count();
+        if args.len() < required_args_length {
+            return Err(format!(
+                "Wrong number of args. Got {}, expected: {}. (usage: \"{}\")",
+                args.len(),
+                required_args_length,
+                self.usage
+            ));
+        }
+
+        if args.len() > self.args.len() {
+            return Err(format!(
+                "Too many args. Got {}, expected: {}. (usage: \"{}\")",
+                args.len(),
+                self.args.len(),
+                self.usage
+            ));
+        }
+
+        for (i, raw_arg) in args.iter().enumerate() {
+            let arg = &self.args[i];
+            let valid_type = match arg.r#type {
+                // handlers parse these as i32
+                ArgType::UInt => raw_arg.parse::<i32>().is_ok_and(|value| value >= 0),
+                ArgType::String => true,
+                ArgType::Bool => raw_arg.parse::<bool>().is_ok(),
+            };
+            if !valid_type {
+                return Err(format!(
+                    "Invalid arg type. Got {}, expected: {:?}. (usage: \"{}\")",
+                    raw_arg, arg.r#type, self.usage
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    admin_commands::{
+        prepare_args, run_command, validate_console_args, CommandIssuer, CONSOLE_ADMIN_NAME,
+    },
+    world::{AdminLogEntry, WorldHandle},
+    COMMANDS,
+};
+
+pub async fn handle_command(command: &str, args: Vec<String>, world: &WorldHandle) {
+    match command {
+        "help" => return help(),
+        "players" => return players(world).await,
+        "announce" => {
+            if args.is_empty() {
+                println!("Usage: announce message");
+            } else {
+                log_command(world, "announce", &args);
+                world.broadcast_announcement(CONSOLE_ADMIN_NAME.to_owned(), args.join(" "));
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    let commands = COMMANDS.load();
+    let command = match commands
+        .commands
+        .iter()
+        .find(|c| c.name == command || c.alias == command)
+    {
+        Some(command) => command,
+        None => {
+            println!("Unknown command: {}", command);
+            return;
+        }
+    };
+
+    let args = prepare_args(&command.name, args);
+
+    if let Err(message) = validate_console_args(command, &args) {
+        println!("{}", message);
+        return;
+    }
+
+    run_command(command, &args, &CommandIssuer::Console, world).await;
+}
+
+fn log_command(world: &WorldHandle, command: &str, args: &[String]) {
+    world.log_admin_action(AdminLogEntry {
+        admin_name: CONSOLE_ADMIN_NAME.to_owned(),
+        command: command.to_owned(),
+        args: args.join(" "),
+        ..Default::default()
+    });
+}
+
+fn help() {
+    println!("Console commands:");
+    println!("  players - list online players");
+    println!("  announce message - send an announcement");
+    println!("  warp player map [x] [y] - warp a player");
+    println!("  remap map - reload a map file");
+    for command in COMMANDS
+        .load()
+        .commands
+        .iter()
+        .filter(|command| !matches!(command.name.as_str(), "warp" | "remap"))
+    {
+        println!("  {}", command.usage.trim_start_matches('$'));
+    }
+}
+
+async fn players(world: &WorldHandle) {
+    let characters = world.get_online_characters().await;
+    println!("{} player(s) online", characters.len());
+    for character in characters {
+        println!(
+            "  {} (level {}) map {} ({}, {})",
+            character.name,
+            character.level,
+            character.map_id,
+            character.coords.x,
+            character.coords.y
+        );
+    }
+}
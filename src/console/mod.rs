@@ -0,0 +1,32 @@
+use tokio::io::{stdin, AsyncBufReadExt, BufReader};
+
+use crate::world::WorldHandle;
+
+mod handle_command;
+use handle_command::handle_command;
+
+pub async fn run(world: WorldHandle) {
+    let mut lines = BufReader::new(stdin()).lines();
+
+    loop {
+        let line = match lines.next_line().await {
+            Ok(Some(line)) => line,
+            Ok(None) => return,
+            Err(e) => {
+                error!("Failed to read from console: {}", e);
+                return;
+            }
+        };
+
+        let args: Vec<&str> = line.split_whitespace().collect();
+        if args.is_empty() {
+            continue;
+        }
+
+        // allow commands to be typed the same way as in game
+        let command = args[0].trim_start_matches('$');
+        let args: Vec<String> = args[1..].iter().map(|arg| arg.to_string()).collect();
+
+        handle_command(command, args, &world).await;
+    }
+}
@@ -0,0 +1,54 @@
+use mysql_async::{prelude::Queryable as _, TxOpts};
+use mysql_common::{params::Params, Row};
+
+use super::{sqlite::SqliteConn, Error, Query, Queryable, Transaction};
+
+pub enum Conn {
+    MySql(mysql_async::Conn),
+    Sqlite(SqliteConn),
+}
+
+impl Conn {
+    pub async fn start_transaction(&mut self) -> Result<Transaction<'_>, Error> {
+        match self {
+            Self::MySql(conn) => Ok(Transaction::MySql(
+                conn.start_transaction(TxOpts::default()).await?,
+            )),
+            Self::Sqlite(conn) => Ok(Transaction::Sqlite(conn.start_transaction().await?)),
+        }
+    }
+}
+
+impl Queryable for Conn {
+    async fn exec_rows(&mut self, query: Query<'_>, params: Params) -> Result<Vec<Row>, Error> {
+        match self {
+            Self::MySql(conn) => Ok(conn.exec(query.mysql(), params).await?),
+            Self::Sqlite(conn) => conn.exec(query.sqlite(), params).await,
+        }
+    }
+
+    async fn query_drop<'a, Q>(&mut self, query: Q) -> Result<(), Error>
+    where
+        Q: Into<Query<'a>> + Send,
+    {
+        let query = query.into();
+        match self {
+            Self::MySql(conn) => Ok(conn.query_drop(query.mysql()).await?),
+            Self::Sqlite(conn) => conn.exec_batch(query.sqlite()).await,
+        }
+    }
+
+    fn last_insert_id(&self) -> Option<u64> {
+        match self {
+            Self::MySql(conn) => conn.last_insert_id(),
+            Self::Sqlite(conn) => conn.last_insert_id(),
+        }
+    }
+
+    fn affected_rows(&self) -> u64 {
+        match self {
+            Self::MySql(conn) => conn.affected_rows(),
+            Self::Sqlite(conn) => conn.affected_rows(),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+#[derive(Debug)]
+pub enum Error {
+    MySql(mysql_async::Error),
+    Sqlite(rusqlite::Error),
+    FromRow(String),
+    ConnectionLost,
+}
+
+impl std::error::Error for Error {}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MySql(e) => write!(f, "{}", e),
+            Self::Sqlite(e) => write!(f, "{}", e),
+            Self::FromRow(e) => write!(f, "Failed to convert row: {}", e),
+            Self::ConnectionLost => write!(f, "Database connection was lost"),
+        }
+    }
+}
+
+impl From<mysql_async::Error> for Error {
+    fn from(e: mysql_async::Error) -> Self {
+        Self::MySql(e)
+    }
+}
+
+impl From<mysql_async::UrlError> for Error {
+    fn from(e: mysql_async::UrlError) -> Self {
+        Self::MySql(e.into())
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(e: rusqlite::Error) -> Self {
+        Self::Sqlite(e)
+    }
+}
+
+impl From<tokio::task::JoinError> for Error {
+    fn from(_: tokio::task::JoinError) -> Self {
+        Self::ConnectionLost
+    }
+}
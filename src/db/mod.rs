@@ -0,0 +1,39 @@
+//! Storage backend shared by every part of the server that touches the
+//! database. Queries are written once against the [`Queryable`] trait and run
+//! on whichever driver is configured under `[database]`.
+
+macro_rules! query {
+    ($name:expr) => {
+        $crate::db::Query::new(
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/src/sql/",
+                $name,
+                ".sql"
+            )),
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/src/sql/sqlite/",
+                $name,
+                ".sql"
+            )),
+        )
+    };
+}
+
+mod conn;
+pub use conn::Conn;
+mod error;
+pub use error::Error;
+mod pool;
+pub use pool::Pool;
+mod query;
+pub use query::Query;
+mod queryable;
+pub use queryable::Queryable;
+mod sqlite;
+mod transaction;
+pub use transaction::Transaction;
+
+pub use mysql_common::{params, params::Params, Row, Value};
+
@@ -0,0 +1,45 @@
+use crate::settings::{Database, DatabaseDriver};
+
+use super::{sqlite::SqlitePool, Conn, Error};
+
+#[derive(Debug, Clone)]
+pub enum Pool {
+    MySql(mysql_async::Pool),
+    Sqlite(SqlitePool),
+}
+
+impl Pool {
+    pub fn new(settings: &Database) -> Result<Self, Error> {
+        match settings.driver {
+            DatabaseDriver::Mysql => {
+                let database_url = format!(
+                    "mysql://{}:{}@{}:{}/{}",
+                    settings.username,
+                    settings.password,
+                    settings.host,
+                    settings.port,
+                    settings.name
+                );
+
+                Ok(Self::MySql(mysql_async::Pool::new(
+                    mysql_async::Opts::from_url(&database_url)?,
+                )))
+            }
+            DatabaseDriver::Sqlite => Ok(Self::Sqlite(SqlitePool::new(&settings.path))),
+        }
+    }
+
+    pub fn driver(&self) -> DatabaseDriver {
+        match self {
+            Self::MySql(_) => DatabaseDriver::Mysql,
+            Self::Sqlite(_) => DatabaseDriver::Sqlite,
+        }
+    }
+
+    pub async fn get_conn(&self) -> Result<Conn, Error> {
+        match self {
+            Self::MySql(pool) => Ok(Conn::MySql(pool.get_conn().await?)),
+            Self::Sqlite(pool) => Ok(Conn::Sqlite(pool.get_conn().await?)),
+        }
+    }
+}
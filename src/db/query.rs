@@ -0,0 +1,27 @@
+/// SQL text for each driver. Most queries are portable and share the same
+/// text, the rest are loaded with `query!` from `src/sql/sqlite`.
+#[derive(Debug, Clone, Copy)]
+pub struct Query<'a> {
+    mysql: &'a str,
+    sqlite: &'a str,
+}
+
+impl<'a> Query<'a> {
+    pub const fn new(mysql: &'a str, sqlite: &'a str) -> Self {
+        Self { mysql, sqlite }
+    }
+
+    pub fn mysql(&self) -> &'a str {
+        self.mysql
+    }
+
+    pub fn sqlite(&self) -> &'a str {
+        self.sqlite
+    }
+}
+
+impl<'a> From<&'a str> for Query<'a> {
+    fn from(query: &'a str) -> Self {
+        Self::new(query, query)
+    }
+}
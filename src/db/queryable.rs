@@ -0,0 +1,90 @@
+use std::future::Future;
+
+use mysql_common::{params::Params, prelude::FromRow, Row};
+
+use super::{Error, Query};
+
+/// Query methods shared by connections and transactions of every driver.
+/// Rows are always returned as MySQL rows so callers can read them the same
+/// way regardless of the backend.
+pub trait Queryable: Send {
+    fn exec_rows(
+        &mut self,
+        query: Query<'_>,
+        params: Params,
+    ) -> impl Future<Output = Result<Vec<Row>, Error>> + Send;
+
+    /// Runs one or more statements without parameters
+    fn query_drop<'a, Q>(&mut self, query: Q) -> impl Future<Output = Result<(), Error>> + Send
+    where
+        Q: Into<Query<'a>> + Send;
+
+    fn last_insert_id(&self) -> Option<u64>;
+
+    fn affected_rows(&self) -> u64;
+
+    fn exec_drop<'a, Q, P>(
+        &mut self,
+        query: Q,
+        params: P,
+    ) -> impl Future<Output = Result<(), Error>> + Send
+    where
+        Q: Into<Query<'a>> + Send,
+        P: Into<Params> + Send,
+    {
+        async move {
+            self.exec_rows(query.into(), params.into()).await?;
+            Ok(())
+        }
+    }
+
+    fn exec_map<'a, T, Q, P, F, U>(
+        &mut self,
+        query: Q,
+        params: P,
+        mut f: F,
+    ) -> impl Future<Output = Result<Vec<U>, Error>> + Send
+    where
+        T: FromRow,
+        Q: Into<Query<'a>> + Send,
+        P: Into<Params> + Send,
+        F: FnMut(T) -> U + Send,
+        U: Send,
+    {
+        async move {
+            let rows = self.exec_rows(query.into(), params.into()).await?;
+            rows.into_iter().map(|row| Ok(f(from_row(row)?))).collect()
+        }
+    }
+
+    fn exec_first<'a, T, Q, P>(
+        &mut self,
+        query: Q,
+        params: P,
+    ) -> impl Future<Output = Result<Option<T>, Error>> + Send
+    where
+        T: FromRow,
+        Q: Into<Query<'a>> + Send,
+        P: Into<Params> + Send,
+    {
+        async move {
+            let rows = self.exec_rows(query.into(), params.into()).await?;
+            rows.into_iter().next().map(from_row).transpose()
+        }
+    }
+
+    fn query_first<'a, T, Q>(
+        &mut self,
+        query: Q,
+    ) -> impl Future<Output = Result<Option<T>, Error>> + Send
+    where
+        T: FromRow,
+        Q: Into<Query<'a>> + Send,
+    {
+        self.exec_first(query, Params::Empty)
+    }
+}
+
+fn from_row<T: FromRow>(row: Row) -> Result<T, Error> {
+    T::from_row_opt(row).map_err(|e| Error::FromRow(e.to_string()))
+}
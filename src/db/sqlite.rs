@@ -0,0 +1,243 @@
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+use mysql_common::{
+    constants::ColumnType, packets::Column, params::Params, row::new_row, Row, Value,
+};
+use rusqlite::{
+    types::{Value as SqliteValue, ValueRef},
+    Connection, Statement,
+};
+
+use super::Error;
+
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+pub struct SqlitePool {
+    path: Arc<PathBuf>,
+}
+
+impl SqlitePool {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: Arc::new(PathBuf::from(path)),
+        }
+    }
+
+    pub async fn get_conn(&self) -> Result<SqliteConn, Error> {
+        let path = self.path.clone();
+        let conn = tokio::task::spawn_blocking(move || open(&path)).await??;
+        Ok(SqliteConn {
+            inner: Some(conn),
+            last_insert_id: None,
+            affected_rows: 0,
+        })
+    }
+}
+
+fn open(path: &PathBuf) -> rusqlite::Result<Connection> {
+    let conn = Connection::open(path)?;
+    conn.busy_timeout(BUSY_TIMEOUT)?;
+    conn.pragma_update_and_check(None, "journal_mode", "WAL", |_| Ok(()))?;
+    conn.pragma_update(None, "foreign_keys", true)?;
+    Ok(conn)
+}
+
+/// rusqlite is synchronous so every call is moved onto the blocking pool
+/// along with the connection and handed back once it finishes.
+#[derive(Debug)]
+pub struct SqliteConn {
+    inner: Option<Connection>,
+    last_insert_id: Option<u64>,
+    affected_rows: u64,
+}
+
+impl SqliteConn {
+    async fn run<F, R>(&mut self, f: F) -> Result<R, Error>
+    where
+        F: FnOnce(&Connection) -> rusqlite::Result<R> + Send + 'static,
+        R: Send + 'static,
+    {
+        let conn = self.inner.take().ok_or(Error::ConnectionLost)?;
+        let (conn, result) = tokio::task::spawn_blocking(move || {
+            let result = f(&conn);
+            (conn, result)
+        })
+        .await?;
+        self.inner = Some(conn);
+        Ok(result?)
+    }
+
+    pub async fn exec(&mut self, query: &str, params: Params) -> Result<Vec<Row>, Error> {
+        let query = query.to_owned();
+        let (rows, last_insert_id, affected_rows) = self
+            .run(move |conn| {
+                let mut stmt = conn.prepare_cached(&query)?;
+                bind_params(&mut stmt, params)?;
+
+                let columns: Arc<[Column]> = stmt
+                    .column_names()
+                    .into_iter()
+                    .map(|name| {
+                        Column::new(ColumnType::MYSQL_TYPE_VAR_STRING).with_name(name.as_bytes())
+                    })
+                    .collect();
+
+                let mut rows = Vec::new();
+                let mut results = stmt.raw_query();
+                while let Some(row) = results.next()? {
+                    let mut values = Vec::with_capacity(columns.len());
+                    for index in 0..columns.len() {
+                        values.push(from_sqlite_value(row.get_ref(index)?));
+                    }
+                    rows.push(new_row(values, columns.clone()));
+                }
+
+                Ok((rows, conn.last_insert_rowid(), conn.changes()))
+            })
+            .await?;
+
+        self.last_insert_id = u64::try_from(last_insert_id).ok().filter(|id| *id > 0);
+        self.affected_rows = affected_rows;
+
+        Ok(rows)
+    }
+
+    pub async fn exec_batch(&mut self, query: &str) -> Result<(), Error> {
+        let query = query.to_owned();
+        self.run(move |conn| conn.execute_batch(&query)).await
+    }
+
+    pub async fn start_transaction(&mut self) -> Result<SqliteTransaction<'_>, Error> {
+        // IMMEDIATE takes the write lock up front so two transactions can't
+        // deadlock upgrading from a read lock
+        self.exec_batch("BEGIN IMMEDIATE").await?;
+        Ok(SqliteTransaction {
+            conn: self,
+            finished: false,
+        })
+    }
+
+    pub fn last_insert_id(&self) -> Option<u64> {
+        self.last_insert_id
+    }
+
+    pub fn affected_rows(&self) -> u64 {
+        self.affected_rows
+    }
+}
+
+#[derive(Debug)]
+pub struct SqliteTransaction<'a> {
+    conn: &'a mut SqliteConn,
+    finished: bool,
+}
+
+impl SqliteTransaction<'_> {
+    pub async fn exec(&mut self, query: &str, params: Params) -> Result<Vec<Row>, Error> {
+        self.conn.exec(query, params).await
+    }
+
+    pub async fn exec_batch(&mut self, query: &str) -> Result<(), Error> {
+        self.conn.exec_batch(query).await
+    }
+
+    pub async fn commit(mut self) -> Result<(), Error> {
+        self.conn.exec_batch("COMMIT").await?;
+        self.finished = true;
+        Ok(())
+    }
+
+    pub fn last_insert_id(&self) -> Option<u64> {
+        self.conn.last_insert_id()
+    }
+
+    pub fn affected_rows(&self) -> u64 {
+        self.conn.affected_rows()
+    }
+}
+
+impl Drop for SqliteTransaction<'_> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        if let Some(conn) = &self.conn.inner {
+            if let Err(e) = conn.execute_batch("ROLLBACK") {
+                error!("Failed to roll back transaction: {}", e);
+            }
+        }
+    }
+}
+
+fn bind_params(stmt: &mut Statement, params: Params) -> rusqlite::Result<()> {
+    match params {
+        Params::Empty => {}
+        Params::Positional(values) => {
+            for (index, value) in values.into_iter().enumerate() {
+                stmt.raw_bind_parameter(index + 1, to_sqlite_value(value))?;
+            }
+        }
+        Params::Named(values) => {
+            // Parameter indexes start at 1 and repeated names share an index
+            for index in 1..=stmt.parameter_count() {
+                let name = match stmt.parameter_name(index) {
+                    Some(name) => name.to_owned(),
+                    None => continue,
+                };
+
+                let value = match values.get(name.trim_start_matches(':').as_bytes()) {
+                    Some(value) => value.clone(),
+                    None => return Err(rusqlite::Error::InvalidParameterName(name)),
+                };
+
+                stmt.raw_bind_parameter(index, to_sqlite_value(value))?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn to_sqlite_value(value: Value) -> SqliteValue {
+    match value {
+        Value::NULL => SqliteValue::Null,
+        Value::Bytes(bytes) => match String::from_utf8(bytes) {
+            Ok(text) => SqliteValue::Text(text),
+            Err(e) => SqliteValue::Blob(e.into_bytes()),
+        },
+        Value::Int(value) => SqliteValue::Integer(value),
+        Value::UInt(value) => SqliteValue::Integer(value as i64),
+        Value::Float(value) => SqliteValue::Real(value as f64),
+        Value::Double(value) => SqliteValue::Real(value),
+        // Stored as text in the same format as CURRENT_TIMESTAMP so dates
+        // compare and sort correctly
+        Value::Date(year, month, day, hour, minute, second, micros) => {
+            let mut text = format!(
+                "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+                year, month, day, hour, minute, second
+            );
+            if micros > 0 {
+                text.push_str(&format!(".{:06}", micros));
+            }
+            SqliteValue::Text(text)
+        }
+        Value::Time(negative, days, hours, minutes, seconds, _) => SqliteValue::Text(format!(
+            "{}{:02}:{:02}:{:02}",
+            if negative { "-" } else { "" },
+            days * 24 + hours as u32,
+            minutes,
+            seconds
+        )),
+    }
+}
+
+fn from_sqlite_value(value: ValueRef) -> Value {
+    match value {
+        ValueRef::Null => Value::NULL,
+        ValueRef::Integer(value) => Value::Int(value),
+        ValueRef::Real(value) => Value::Double(value),
+        ValueRef::Text(bytes) | ValueRef::Blob(bytes) => Value::Bytes(bytes.to_vec()),
+    }
+}
@@ -0,0 +1,53 @@
+use mysql_async::prelude::Queryable as _;
+use mysql_common::{params::Params, Row};
+
+use super::{sqlite::SqliteTransaction, Error, Query, Queryable};
+
+/// Rolled back when dropped without calling [`Transaction::commit`].
+pub enum Transaction<'a> {
+    MySql(mysql_async::Transaction<'a>),
+    Sqlite(SqliteTransaction<'a>),
+}
+
+impl Transaction<'_> {
+    pub async fn commit(self) -> Result<(), Error> {
+        match self {
+            Self::MySql(tx) => Ok(tx.commit().await?),
+            Self::Sqlite(tx) => tx.commit().await,
+        }
+    }
+}
+
+impl Queryable for Transaction<'_> {
+    async fn exec_rows(&mut self, query: Query<'_>, params: Params) -> Result<Vec<Row>, Error> {
+        match self {
+            Self::MySql(tx) => Ok(tx.exec(query.mysql(), params).await?),
+            Self::Sqlite(tx) => tx.exec(query.sqlite(), params).await,
+        }
+    }
+
+    async fn query_drop<'a, Q>(&mut self, query: Q) -> Result<(), Error>
+    where
+        Q: Into<Query<'a>> + Send,
+    {
+        let query = query.into();
+        match self {
+            Self::MySql(tx) => Ok(tx.query_drop(query.mysql()).await?),
+            Self::Sqlite(tx) => tx.exec_batch(query.sqlite()).await,
+        }
+    }
+
+    fn last_insert_id(&self) -> Option<u64> {
+        match self {
+            Self::MySql(tx) => tx.last_insert_id(),
+            Self::Sqlite(tx) => tx.last_insert_id(),
+        }
+    }
+
+    fn affected_rows(&self) -> u64 {
+        match self {
+            Self::MySql(tx) => tx.affected_rows(),
+            Self::Sqlite(tx) => tx.affected_rows(),
+        }
+    }
+}
@@ -1,5 +1,6 @@
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
 pub enum AccountRecoverPinReply {
+    #[default]
     WrongPin,
     OK,
     Unrecognized(i32),
@@ -24,9 +25,3 @@ impl From<AccountRecoverPinReply> for i32 {
         }
     }
 }
-
-impl Default for AccountRecoverPinReply {
-    fn default() -> Self {
-        Self::WrongPin
-    }
-}
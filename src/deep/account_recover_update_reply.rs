@@ -1,5 +1,6 @@
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
 pub enum AccountRecoverUpdateReply {
+    #[default]
     Error,
     OK,
     Unrecognized(i32),
@@ -24,9 +25,3 @@ impl From<AccountRecoverUpdateReply> for i32 {
         }
     }
 }
-
-impl Default for AccountRecoverUpdateReply {
-    fn default() -> Self {
-        Self::Error
-    }
-}
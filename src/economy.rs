@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDateTime, Utc};
+
+use crate::db::{params, Conn, Pool, Queryable, Row};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct GoldTotals {
+    pub inventory: i64,
+    pub bank: i64,
+    pub guild: i64,
+}
+
+impl GoldTotals {
+    pub fn total(&self) -> i64 {
+        self.inventory + self.bank + self.guild
+    }
+}
+
+#[derive(Debug)]
+pub struct EconomySnapshot {
+    pub id: i32,
+    pub gold: GoldTotals,
+    pub created_at: NaiveDateTime,
+}
+
+/// Records current gold totals and the supply of every item held by
+/// characters so inflation can be tracked over time
+pub async fn take_economy_snapshot(
+    pool: &Pool,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let mut conn = pool.get_conn().await?;
+    let gold = get_gold_totals(&mut conn).await?;
+
+    let mut tx = conn.start_transaction().await?;
+
+    tx.exec_drop(
+        include_str!("sql/create_economy_snapshot.sql"),
+        params! {
+            "inventory_gold" => gold.inventory,
+            "bank_gold" => gold.bank,
+            "guild_gold" => gold.guild,
+            "created_at" => Utc::now().naive_utc(),
+        },
+    )
+    .await?;
+
+    let snapshot_id = tx
+        .last_insert_id()
+        .ok_or("Economy snapshot insert returned no id")?;
+
+    tx.exec_drop(
+        include_str!("sql/create_economy_snapshot_items.sql"),
+        params! {
+            "snapshot_id" => snapshot_id,
+        },
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+pub async fn get_gold_totals(
+    conn: &mut Conn,
+) -> Result<GoldTotals, Box<dyn std::error::Error + Send + Sync>> {
+    let mut row: Row = match conn
+        .query_first(include_str!("sql/get_gold_totals.sql"))
+        .await?
+    {
+        Some(row) => row,
+        None => return Ok(GoldTotals::default()),
+    };
+
+    Ok(GoldTotals {
+        inventory: row.take("inventory_gold").unwrap(),
+        bank: row.take("bank_gold").unwrap(),
+        guild: row.take("guild_gold").unwrap(),
+    })
+}
+
+pub async fn get_item_supply(
+    conn: &mut Conn,
+) -> Result<HashMap<i32, i64>, Box<dyn std::error::Error + Send + Sync>> {
+    let supply = conn
+        .exec_map(
+            include_str!("sql/get_item_supply.sql"),
+            (),
+            |mut row: Row| {
+                (
+                    row.take::<i32, &str>("item_id").unwrap(),
+                    row.take::<i64, &str>("amount").unwrap(),
+                )
+            },
+        )
+        .await?;
+
+    Ok(supply.into_iter().collect())
+}
+
+/// The most recent snapshot taken at or before `before`
+pub async fn get_economy_snapshot(
+    conn: &mut Conn,
+    before: NaiveDateTime,
+) -> Result<Option<EconomySnapshot>, Box<dyn std::error::Error + Send + Sync>> {
+    let mut row: Row = match conn
+        .exec_first(
+            include_str!("sql/get_economy_snapshot.sql"),
+            params! {
+                "before" => before,
+            },
+        )
+        .await?
+    {
+        Some(row) => row,
+        None => return Ok(None),
+    };
+
+    Ok(Some(EconomySnapshot {
+        id: row.take("id").unwrap(),
+        gold: GoldTotals {
+            inventory: row.take("inventory_gold").unwrap(),
+            bank: row.take("bank_gold").unwrap(),
+            guild: row.take("guild_gold").unwrap(),
+        },
+        created_at: row.take("created_at").unwrap(),
+    }))
+}
+
+pub async fn get_economy_snapshot_items(
+    conn: &mut Conn,
+    snapshot_id: i32,
+) -> Result<HashMap<i32, i64>, Box<dyn std::error::Error + Send + Sync>> {
+    let supply = conn
+        .exec_map(
+            include_str!("sql/get_economy_snapshot_items.sql"),
+            params! {
+                "snapshot_id" => snapshot_id,
+            },
+            |mut row: Row| {
+                (
+                    row.take::<i32, &str>("item_id").unwrap(),
+                    row.take::<i64, &str>("amount").unwrap(),
+                )
+            },
+        )
+        .await?;
+
+    Ok(supply.into_iter().collect())
+}
+
+pub async fn get_top_gold_holders(
+    conn: &mut Conn,
+    limit: i32,
+) -> Result<Vec<(String, i64)>, Box<dyn std::error::Error + Send + Sync>> {
+    let holders = conn
+        .exec_map(
+            include_str!("sql/get_top_gold_holders.sql"),
+            params! {
+                "limit" => limit,
+            },
+            |mut row: Row| {
+                (
+                    row.take::<String, &str>("name").unwrap(),
+                    row.take::<i64, &str>("gold").unwrap(),
+                )
+            },
+        )
+        .await?;
+
+    Ok(holders)
+}
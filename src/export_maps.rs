@@ -0,0 +1,104 @@
+use std::{fs, path::Path};
+
+use eolib::data::{EoSerialize, EoWriter};
+use glob::glob;
+
+use crate::utils::{load_map_file, MapJson};
+
+/// Where exported maps are written. The server never loads from here, so
+/// exporting can't change which file a map is loaded from.
+const EXPORT_DIR: &str = "data/maps/export";
+
+/// Writes a JSON copy of every EMF in data/maps to data/maps/export. Existing
+/// exports are left alone unless `--force` is passed. Returns the process
+/// exit code.
+pub fn export_maps(force: bool) -> i32 {
+    if let Err(e) = fs::create_dir_all(EXPORT_DIR) {
+        println!("error: {}: {}", EXPORT_DIR, e);
+        return 1;
+    }
+
+    let entries = match glob("data/maps/*.emf") {
+        Ok(entries) => entries,
+        Err(e) => {
+            println!("error: {}", e);
+            return 1;
+        }
+    };
+
+    let mut exported = 0;
+    let mut skipped = 0;
+    let mut failed = 0;
+
+    for entry in entries {
+        let path = match entry {
+            Ok(path) => path,
+            Err(e) => {
+                println!("error: {}", e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let json_path = match path.file_stem() {
+            Some(stem) => Path::new(EXPORT_DIR).join(stem).with_extension("json"),
+            None => continue,
+        };
+
+        if json_path.exists() && !force {
+            skipped += 1;
+            continue;
+        }
+
+        match export_map(&path, &json_path) {
+            Ok(()) => exported += 1,
+            Err(e) => {
+                println!("error: {}: {}", path.display(), e);
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "{} maps exported, {} skipped, {} failed",
+        exported, skipped, failed
+    );
+
+    if skipped > 0 {
+        println!("Existing exports were skipped, pass --force to overwrite them");
+    }
+
+    if failed > 0 {
+        1
+    } else {
+        0
+    }
+}
+
+fn export_map(
+    path: &Path,
+    json_path: &Path,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let (emf, _) = load_map_file(path)?;
+
+    let json = serde_json::to_string_pretty(&MapJson::from(&emf))?;
+
+    // Everything after the rid has to survive the trip back to EMF, a map
+    // that doesn't would play differently once the JSON is used
+    let compiled = serde_json::from_str::<MapJson>(&json)?.compile()?;
+    if serialize(&emf)?[7..] != serialize(&compiled)?[7..] {
+        return Err("does not compile back to the same EMF".into());
+    }
+
+    fs::write(json_path, json)?;
+
+    Ok(())
+}
+
+fn serialize<T: EoSerialize>(
+    file: &T,
+) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+    let mut writer = EoWriter::new();
+    file.serialize(&mut writer)?;
+    Ok(writer.to_byte_array().to_vec())
+}
@@ -17,6 +17,9 @@ pub struct Formulas {
     pub hit_rate: String,
     pub damage: String,
     pub party_exp_share: String,
+    pub exp: String,
+    pub exp_table: Option<String>,
+    pub max_level: i32,
     pub classes: Vec<ClassFormulas>,
 }
 
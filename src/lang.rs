@@ -8,6 +8,7 @@ pub struct Lang {
     pub announce_unfreeze: String,
     pub announce_remove: String,
     pub announce_mute: String,
+    pub muted: String,
     pub announce_global: String,
     pub global_locked: String,
     pub wedding_start: String,
@@ -22,6 +23,13 @@ pub struct Lang {
     pub wedding_error: String,
     pub evacuate_warning: String,
     pub evacuate_last_warning: String,
+    pub shutdown_warning: String,
+    pub restart_warning: String,
+    pub maintenance_on: String,
+    pub maintenance_off: String,
+    pub maintenance_kick_warning: String,
+    pub maintenance_title: String,
+    pub login_queue_position: String,
 }
 
 impl Lang {
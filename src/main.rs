@@ -23,12 +23,18 @@ use lazy_static::lazy_static;
 
 #[macro_use]
 mod utils;
+#[macro_use]
+mod db;
+mod admin_api;
+mod admin_commands;
 mod arenas;
 mod character;
 mod deep;
+mod economy;
 use arenas::Arenas;
 mod commands;
 use commands::Commands;
+mod console;
 mod player_commands;
 use player::Socket;
 use player_commands::PlayerCommands;
@@ -37,55 +43,80 @@ mod formulas;
 use formulas::Formulas;
 mod emails;
 mod errors;
+mod export_maps;
 mod lang;
 mod map;
+mod metrics;
+mod migrations;
 mod player;
+mod pub_files;
+use pub_files::PubFiles;
+mod save_queue;
 mod settings;
 use settings::Settings;
 mod packet_rate_limits;
 use packet_rate_limits::PacketRateLimits;
 mod sln;
 use sln::ping_sln;
+mod spawns;
+use spawns::Spawns;
+mod validate;
 mod world;
-use mysql_async::prelude::*;
 
 use tokio::{net::TcpListener, signal, time};
 use tokio_tungstenite::accept_async;
 use world::WorldHandle;
 
 use crate::{
+    db::{Pool, Queryable, Row},
     emails::Emails,
     lang::Lang,
     player::PlayerHandle,
     utils::{
-        load_class_file, load_drop_file, load_inn_file, load_item_file, load_npc_file, load_quests,
-        load_shop_file, load_skill_master_file, load_spell_file, load_talk_file,
+        load_class_file, load_drop_file, load_exp_table, load_inn_file, load_item_file,
+        load_npc_file, load_quests, load_shop_file, load_skill_master_file, load_spell_file,
+        load_talk_file, Reloadable,
     },
 };
 
 lazy_static! {
     static ref SETTINGS: Settings = Settings::new().expect("Failed to load settings!");
-    static ref ARENAS: Arenas = Arenas::new().expect("Failed to load arenas!");
+    static ref ARENAS: Reloadable<Arenas> =
+        Reloadable::new(Arenas::new().expect("Failed to load arenas!"));
     static ref PACKET_RATE_LIMITS: PacketRateLimits =
         PacketRateLimits::new().expect("Failed to load packet rate limits!");
-    static ref COMMANDS: Commands = Commands::new().expect("Failed to load commands!");
-    static ref PLAYER_COMMANDS: PlayerCommands =
-        PlayerCommands::new().expect("Failed to load player commands!");
-    static ref FORMULAS: Formulas = Formulas::new().expect("Failed to load formulas!");
-    static ref LANG: Lang = Lang::new().expect("Failed to load lang!");
+    static ref COMMANDS: Reloadable<Commands> =
+        Reloadable::new(Commands::new().expect("Failed to load commands!"));
+    static ref PLAYER_COMMANDS: Reloadable<PlayerCommands> =
+        Reloadable::new(PlayerCommands::new().expect("Failed to load player commands!"));
+    static ref FORMULAS: Reloadable<Formulas> =
+        Reloadable::new(Formulas::new().expect("Failed to load formulas!"));
+    static ref LANG: Reloadable<Lang> = Reloadable::new(Lang::new().expect("Failed to load lang!"));
     static ref EMAILS: Emails = Emails::new().expect("Failed to load emails!");
-    static ref CLASS_DB: Ecf = load_class_file().expect("Failed to load ECF file!");
-    static ref DROP_DB: DropFile = load_drop_file().expect("Failed to load Drop file!");
-    static ref INN_DB: InnFile = load_inn_file().expect("Failed to load Inn file!");
-    static ref ITEM_DB: Eif = load_item_file().expect("Failed to load EIF file!");
-    static ref NPC_DB: Enf = load_npc_file().expect("Failed to load ENF file!");
-    static ref SHOP_DB: ShopFile = load_shop_file().expect("Failed to load Shop file!");
-    static ref SKILL_MASTER_DB: SkillMasterFile =
-        load_skill_master_file().expect("Failed to load Skill Master file!");
-    static ref SPELL_DB: Esf = load_spell_file().expect("Failed to load ESF file!");
-    static ref TALK_DB: TalkFile = load_talk_file().expect("Failed to load Talk file!");
-    static ref QUEST_DB: HashMap<i32, Quest> = load_quests();
-    static ref EXP_TABLE: [i32; 254] = load_exp_table();
+    static ref CLASS_DB: Reloadable<Ecf> =
+        Reloadable::new(load_class_file().expect("Failed to load ECF file!"));
+    static ref DROP_DB: Reloadable<DropFile> =
+        Reloadable::new(load_drop_file().expect("Failed to load Drop file!"));
+    static ref INN_DB: Reloadable<InnFile> =
+        Reloadable::new(load_inn_file().expect("Failed to load Inn file!"));
+    static ref ITEM_DB: Reloadable<Eif> =
+        Reloadable::new(load_item_file().expect("Failed to load EIF file!"));
+    static ref NPC_DB: Reloadable<Enf> =
+        Reloadable::new(load_npc_file().expect("Failed to load ENF file!"));
+    static ref SHOP_DB: Reloadable<ShopFile> =
+        Reloadable::new(load_shop_file().expect("Failed to load Shop file!"));
+    static ref SKILL_MASTER_DB: Reloadable<SkillMasterFile> =
+        Reloadable::new(load_skill_master_file().expect("Failed to load Skill Master file!"));
+    static ref SPELL_DB: Reloadable<Esf> =
+        Reloadable::new(load_spell_file().expect("Failed to load ESF file!"));
+    static ref TALK_DB: Reloadable<TalkFile> =
+        Reloadable::new(load_talk_file().expect("Failed to load Talk file!"));
+    static ref QUEST_DB: Reloadable<HashMap<i32, Quest>> = Reloadable::new(load_quests());
+    static ref PUB_FILES: Reloadable<PubFiles> = Reloadable::new(PubFiles::new());
+    static ref EXP_TABLE: Reloadable<Vec<i64>> =
+        Reloadable::new(load_exp_table(&FORMULAS.load()).expect("Failed to load exp table!"));
+    static ref SPAWNS: Reloadable<Spawns> =
+        Reloadable::new(Spawns::new().expect("Failed to load spawns!"));
 }
 
 #[tokio::main]
@@ -107,49 +138,59 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         VERSION
     );
 
-    let database_url = format!(
-        "mysql://{}:{}@{}:{}/{}",
-        SETTINGS.database.username,
-        SETTINGS.database.password,
-        SETTINGS.database.host,
-        SETTINGS.database.port,
-        SETTINGS.database.name
-    );
+    if std::env::args().nth(1).as_deref() == Some("validate") {
+        std::process::exit(validate::validate());
+    }
+
+    if std::env::args().nth(1).as_deref() == Some("export-maps") {
+        let force = std::env::args().any(|arg| arg == "--force");
+        std::process::exit(export_maps::export_maps(force));
+    }
+
+    let pool = Pool::new(&SETTINGS.database).expect("Invalid database settings!");
+
+    if let Err(e) = migrations::run_migrations(&pool).await {
+        error!("Failed to migrate database: {}", e);
+        std::process::exit(1);
+    }
+
+    if std::env::args().any(|arg| arg == "--migrate-only") {
+        return Ok(());
+    }
 
-    let pool = mysql_async::Pool::new(mysql_async::Opts::from_url(&database_url).unwrap());
     {
-        let conn = pool
+        let mut conn = pool
             .get_conn()
             .await
-            .expect("Failed to get connection from pool! Is MySQL running?");
-        let mut results = r"SELECT
+            .expect("Failed to get connection from pool! Is the database running?");
+        let row: Row = conn
+            .query_first(
+                r"SELECT
         (SELECT COUNT(*) FROM `Account`) 'accounts',
         (SELECT COUNT(*) FROM `Character`) 'characters',
         (SELECT COUNT(*) FROM `Guild`) 'guilds',
-        (SELECT COUNT(*) FROM `Character` WHERE `admin_level` > 0) 'admins'"
-            .with(())
-            .run(conn)
-            .await?;
-
-        results
-            .for_each(|row| {
-                info!("Accounts: {}", row.get::<i64, usize>(0).unwrap());
-                info!(
-                    "Characters: {} (Admins: {})",
-                    row.get::<i64, usize>(1).unwrap(),
-                    row.get::<i64, usize>(3).unwrap()
-                );
-                info!("Guilds: {}", row.get::<i64, usize>(2).unwrap());
-            })
-            .await
-            .unwrap();
+        (SELECT COUNT(*) FROM `Character` WHERE `admin_level` > 0) 'admins'",
+            )
+            .await?
+            .expect("Failed to count database rows!");
+
+        info!("Accounts: {}", row.get::<i64, usize>(0).unwrap());
+        info!(
+            "Characters: {} (Admins: {})",
+            row.get::<i64, usize>(1).unwrap(),
+            row.get::<i64, usize>(3).unwrap()
+        );
+        info!("Guilds: {}", row.get::<i64, usize>(2).unwrap());
     }
 
-    info!("Classes: {}", CLASS_DB.classes.len());
-    info!("Items: {}", ITEM_DB.items.len());
-    info!("NPCs: {}", NPC_DB.npcs.len());
-    info!("Skills: {}", SPELL_DB.skills.len());
-    info!("Quests: {}", QUEST_DB.len());
+    info!("Classes: {}", CLASS_DB.load().classes.len());
+    info!("Items: {}", ITEM_DB.load().items.len());
+    info!("NPCs: {}", NPC_DB.load().npcs.len());
+    info!("Skills: {}", SPELL_DB.load().skills.len());
+    info!("Quests: {}", QUEST_DB.load().len());
+    info!("Spawn files: {}", SPAWNS.load().files.len());
+
+    lazy_static::initialize(&PUB_FILES);
 
     let world = WorldHandle::new(pool.clone());
     {
@@ -178,6 +219,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         });
     }
 
+    if SETTINGS.economy.snapshot_rate > 0 {
+        let mut economy_interval = time::interval(Duration::from_secs(
+            SETTINGS.economy.snapshot_rate as u64 * 60,
+        ));
+        let economy_pool = pool.clone();
+        tokio::spawn(async move {
+            loop {
+                economy_interval.tick().await;
+                if let Err(e) = economy::take_economy_snapshot(&economy_pool).await {
+                    error!("Failed to take economy snapshot: {}", e);
+                }
+            }
+        });
+    }
+
     if SETTINGS.sln.enabled {
         let mut sln_interval = time::interval(Duration::from_secs(SETTINGS.sln.rate as u64 * 60));
         tokio::spawn(async move {
@@ -219,7 +275,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tokio::spawn(async move {
         while server_world.is_alive {
             let (socket, addr) = tcp_listener.accept().await.unwrap();
-            let ip = addr.ip().to_string();
+            let ip = addr.ip().to_canonical().to_string();
             let now = Utc::now();
 
             let player_count = server_world.get_connection_count().await;
@@ -289,7 +345,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 };
 
-                let ip = addr.ip().to_string();
+                let ip = addr.ip().to_canonical().to_string();
                 let now = Utc::now();
 
                 let player_count = websocket_world.get_connection_count().await;
@@ -329,7 +385,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                 let player = PlayerHandle::new(
                     player_id,
-                    Socket::Web(websocket),
+                    Socket::Web(Box::new(websocket)),
                     ip,
                     now,
                     websocket_world.clone(),
@@ -347,6 +403,36 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         });
     }
 
+    if SETTINGS.admin_api.enabled {
+        let admin_api_world = world.clone();
+        tokio::spawn(async move {
+            admin_api::serve(admin_api_world).await;
+        });
+    }
+
+    if SETTINGS.metrics.enabled {
+        tokio::spawn(async move {
+            metrics::serve().await;
+        });
+    }
+
+    if SETTINGS.server.console {
+        let console_world = world.clone();
+        tokio::spawn(async move {
+            console::run(console_world).await;
+        });
+    }
+
+    #[cfg(unix)]
+    {
+        let signal_world = world.clone();
+        tokio::spawn(async move {
+            if let Err(err) = timed_shutdown_signals(signal_world).await {
+                error!("Unable to listen for timed shutdown signals: {}", err);
+            }
+        });
+    }
+
     tokio::select! {
         ctrl_c = signal::ctrl_c() => match ctrl_c {
             Ok(()) => {},
@@ -359,6 +445,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             Err(err) => {
                 error!("Unable to listen for shutdown signal: {}", err);
             }
+        },
+        exit_code = world.wait_for_shutdown() => {
+            // The world has already saved and disconnected everyone
+            std::process::exit(exit_code);
         }
     }
 
@@ -382,12 +472,16 @@ async fn close() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-fn load_exp_table() -> [i32; 254] {
-    let mut exp_table = [0; 254];
-
-    for (i, exp) in exp_table.iter_mut().enumerate() {
-        *exp = ((i as f64).powf(3.0) * 133.1).round() as i32;
+#[cfg(unix)]
+async fn timed_shutdown_signals(world: WorldHandle) -> Result<(), Box<dyn std::error::Error>> {
+    let mut shutdown_stream = signal::unix::signal(signal::unix::SignalKind::user_defined1())?;
+    let mut restart_stream = signal::unix::signal(signal::unix::SignalKind::user_defined2())?;
+    loop {
+        let restart = tokio::select! {
+            _ = shutdown_stream.recv() => false,
+            _ = restart_stream.recv() => true,
+        };
+
+        world.schedule_shutdown(SETTINGS.shutdown.default_countdown, String::new(), restart);
     }
-
-    exp_table
 }
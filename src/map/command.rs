@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 use eolib::protocol::{
     map::Emf,
     net::{
@@ -15,6 +16,8 @@ use crate::{
     player::PartyRequest,
 };
 
+use super::{ItemHolder, MapStats};
+
 #[derive(Debug)]
 pub enum Command {
     AcceptGuildCreationRequest {
@@ -177,6 +180,9 @@ pub enum Command {
     GetRidAndSize {
         respond_to: oneshot::Sender<([i32; 2], i32)>,
     },
+    GetStats {
+        respond_to: oneshot::Sender<MapStats>,
+    },
     GetPlayerCount {
         respond_to: oneshot::Sender<usize>,
         filter: fn(&Character) -> bool,
@@ -189,6 +195,7 @@ pub enum Command {
         player_id: i32,
         item_id: i32,
         amount: i32,
+        source: ItemHolder,
     },
     GiveKarma {
         player_id: i32,
@@ -241,6 +248,10 @@ pub enum Command {
         player_id: i32,
         skill_id: i32,
     },
+    Mute {
+        player_id: i32,
+        muted_until: DateTime<Utc>,
+    },
     OpenBank {
         player_id: i32,
         npc_index: i32,
@@ -316,6 +327,7 @@ pub enum Command {
         file: Box<Emf>,
         file_size: i32,
     },
+    ReloadSpawns,
     RemoveBoardPost {
         player_id: i32,
         board_id: i32,
@@ -428,6 +440,11 @@ pub enum Command {
         player_id: i32,
         class_id: i32,
     },
+    ShowInfoBox {
+        player_id: i32,
+        title: String,
+        lines: Vec<String>,
+    },
     Sit {
         player_id: i32,
     },
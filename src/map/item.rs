@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use eolib::protocol::{net::server::ItemMapInfo, Coords};
 
 #[derive(Debug, Default)]
@@ -5,8 +6,10 @@ pub struct Item {
     pub id: i32,
     pub amount: i32,
     pub coords: Coords,
+    /// Character id, rather than player id, so protection survives a restart
     pub owner: i32,
     pub protected_ticks: i32,
+    pub dropped_at: DateTime<Utc>,
 }
 
 impl Item {
@@ -0,0 +1,48 @@
+use std::fmt;
+
+use eolib::protocol::Coords;
+
+/// An item moving between two holders, recorded in the item ledger
+#[derive(Debug)]
+pub struct ItemTransfer {
+    pub item_id: i32,
+    pub amount: i32,
+    pub source: ItemHolder,
+    pub destination: ItemHolder,
+    pub coords: Coords,
+}
+
+#[derive(Debug, Clone)]
+pub enum ItemHolder {
+    Character(String),
+    Admin,
+    Chest,
+    Craft,
+    Ground,
+    Junk,
+    Locker,
+    Npc(i32),
+    Quest,
+    Shop,
+    Wedding,
+}
+
+// Everything but characters is prefixed with @ so it can't collide with a
+// character name
+impl fmt::Display for ItemHolder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Character(name) => write!(f, "{}", name),
+            Self::Admin => write!(f, "@admin"),
+            Self::Chest => write!(f, "@chest"),
+            Self::Craft => write!(f, "@craft"),
+            Self::Ground => write!(f, "@ground"),
+            Self::Junk => write!(f, "@junk"),
+            Self::Locker => write!(f, "@locker"),
+            Self::Npc(id) => write!(f, "@npc:{}", id),
+            Self::Quest => write!(f, "@quest"),
+            Self::Shop => write!(f, "@shop"),
+            Self::Wedding => write!(f, "@wedding"),
+        }
+    }
+}
@@ -1,13 +1,13 @@
 use std::collections::HashMap;
 
+use bytes::Bytes;
 use eolib::protocol::{
-    map::{Emf, MapTileSpec},
+    map::{Emf, MapNpc, MapTileSpec},
     Coords,
 };
-use mysql_async::Pool;
 use tokio::sync::mpsc::UnboundedReceiver;
 
-use crate::{character::Character, world::WorldHandle, SETTINGS};
+use crate::{character::Character, db::Pool, world::WorldHandle, SETTINGS, SPAWNS};
 
 use super::{Chest, Command, Door, Item, Npc, Wedding};
 
@@ -17,11 +17,14 @@ pub struct Map {
     id: i32,
     file: Emf,
     file_size: i32,
+    file_bytes: Option<Bytes>,
     chests: Vec<Chest>,
     doors: Vec<Door>,
     items: HashMap<i32, Item>,
+    has_saved_items: bool,
     npcs: HashMap<i32, Npc>,
     npcs_initialized: bool,
+    npc_spawns: Vec<MapNpc>,
     characters: HashMap<i32, Character>,
     pool: Pool,
     quake_ticks: i32,
@@ -101,18 +104,22 @@ impl Map {
         }
 
         let chests = utils::create_chests(id, &file);
+        let npc_spawns = SPAWNS.load().get_npc_spawns(id, &file.npcs);
 
         Self {
             id,
             world,
             file_size,
             file,
+            file_bytes: None,
             rx,
             chests,
             doors,
             items: HashMap::new(),
+            has_saved_items: false,
             npcs: HashMap::new(),
             npcs_initialized: false,
+            npc_spawns,
             characters: HashMap::new(),
             pool,
             arena_ticks: 0,
@@ -327,6 +334,7 @@ impl Map {
             Command::GetRidAndSize { respond_to } => {
                 self.get_rid_and_size(respond_to);
             }
+            Command::GetStats { respond_to } => self.get_stats(respond_to),
             Command::GetPlayerCount { respond_to, filter } => {
                 let _ = respond_to.send(self.characters.values().filter(|c| filter(c)).count());
             }
@@ -338,7 +346,8 @@ impl Map {
                 player_id: target_player_id,
                 item_id,
                 amount,
-            } => self.give_item(target_player_id, item_id, amount),
+                source,
+            } => self.give_item(target_player_id, item_id, amount, source),
 
             Command::GiveKarma { player_id, amount } => self.give_karma(player_id, amount),
 
@@ -398,6 +407,11 @@ impl Map {
                 skill_id,
             } => self.level_skill(player_id, skill_id),
 
+            Command::Mute {
+                player_id,
+                muted_until,
+            } => self.mute(player_id, muted_until),
+
             Command::OpenBank {
                 player_id,
                 npc_index,
@@ -518,6 +532,8 @@ impl Map {
 
             Command::Reload { file, file_size } => self.reload(file, file_size),
 
+            Command::ReloadSpawns => self.reload_spawns(),
+
             Command::RequestDivorce {
                 player_id,
                 npc_index,
@@ -596,6 +612,16 @@ impl Map {
                 class_id,
             } => self.set_class(player_id, class_id),
 
+            Command::ShowInfoBox {
+                player_id,
+                title,
+                lines,
+            } => self.show_info_box(
+                player_id,
+                &title,
+                lines.iter().map(|line| line.as_str()).collect(),
+            ),
+
             Command::Sit { player_id } => self.sit(player_id),
 
             Command::SitChair { player_id, coords } => self.sit_chair(player_id, coords),
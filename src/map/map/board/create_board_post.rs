@@ -1,6 +1,9 @@
-use mysql_async::{params, prelude::Queryable, Conn, Row};
-
-use crate::{errors::DataNotFoundError, utils::get_board_tile_spec, SETTINGS};
+use crate::{
+    db::{params, Conn, Error, Queryable, Row},
+    errors::DataNotFoundError,
+    utils::get_board_tile_spec,
+    SETTINGS,
+};
 
 use super::super::Map;
 
@@ -17,6 +20,11 @@ impl Map {
             None => return,
         };
 
+        if character.is_muted() {
+            character.send_muted_message();
+            return;
+        }
+
         let player = match &character.player {
             Some(player) => player.clone(),
             None => return,
@@ -100,7 +108,7 @@ async fn get_board_post_counts(
 
     let mut row: Row = match conn
         .exec_first(
-            include_str!("../../../sql/get_recent_post_count.sql"),
+            query!("get_recent_post_count"),
             params! {
                 "board_id" => board_id,
                 "character_id" => character_id,
@@ -159,7 +167,7 @@ async fn insert_post(
     character_id: i32,
     subject: String,
     body: String,
-) -> Result<(), mysql_async::Error> {
+) -> Result<(), Error> {
     conn.exec_drop(
         include_str!("../../../sql/create_board_post.sql"),
         params! {
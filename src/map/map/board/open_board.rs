@@ -3,9 +3,9 @@ use eolib::protocol::net::{
     server::{BoardOpenServerPacket, BoardPostListing},
     PacketAction, PacketFamily,
 };
-use mysql_async::{params, prelude::Queryable, Row};
 
 use crate::{
+    db::{params, Queryable, Row},
     utils::{format_duration, get_board_tile_spec},
     SETTINGS,
 };
@@ -1,6 +1,7 @@
-use mysql_async::{params, prelude::Queryable, Conn};
-
-use crate::utils::get_board_tile_spec;
+use crate::{
+    db::{params, Conn, Error, Queryable},
+    utils::get_board_tile_spec,
+};
 
 use super::super::Map;
 
@@ -56,7 +57,7 @@ impl Map {
     }
 }
 
-async fn delete_post(conn: &mut Conn, post_id: i32) -> Result<(), mysql_async::Error> {
+async fn delete_post(conn: &mut Conn, post_id: i32) -> Result<(), Error> {
     conn.exec_drop(
         include_str!("../../../sql/delete_board_post.sql"),
         params! {
@@ -1,7 +1,9 @@
 use eolib::protocol::net::{server::BoardPlayerServerPacket, PacketAction, PacketFamily};
-use mysql_async::{params, prelude::Queryable, Row};
 
-use crate::utils::get_board_tile_spec;
+use crate::{
+    db::{params, Queryable, Row},
+    utils::get_board_tile_spec,
+};
 
 use super::super::Map;
 
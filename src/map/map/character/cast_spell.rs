@@ -4,8 +4,9 @@ use eolib::protocol::net::server::{GroupHealTargetPlayer, SpellTargetGroupServer
 use eolib::protocol::{
     net::{
         server::{
-            AvatarAdminServerPacket, RecoverPlayerServerPacket, SpellTargetOtherServerPacket,
-            SpellTargetSelfServerPacket,NpcSpecServerPacket,NpcAgreeServerPacket,NpcKilledData,NpcMapInfo 
+            AvatarAdminServerPacket, NpcAgreeServerPacket, NpcKilledData, NpcMapInfo,
+            NpcSpecServerPacket, RecoverPlayerServerPacket, SpellTargetOtherServerPacket,
+            SpellTargetSelfServerPacket,
         },
         PacketAction, PacketFamily,
     },
@@ -29,7 +30,8 @@ impl Map {
             None => return,
         }
 
-        let spell_data = match SPELL_DB.skills.get(spell_id as usize - 1) {
+        let spell_db = SPELL_DB.load();
+        let spell_data = match spell_db.skills.get(spell_id as usize - 1) {
             Some(spell_data) => spell_data,
             None => return,
         };
@@ -310,14 +312,12 @@ impl Map {
 
         match target {
             SpellTarget::Npc(npc_index) => {
-                // polymorph 
-                if spell_data.name == "polymorph"{
-                    self.polymorph_spell(player_id, npc_index, spell_data)
-                    .await 
-                }
-                else{
+                // polymorph
+                if spell_data.name == "polymorph" {
+                    self.polymorph_spell(player_id, npc_index, spell_data).await
+                } else {
                     self.cast_damage_npc(player_id, npc_index, spell_id, spell_data)
-                    .await
+                        .await
                 }
             }
             SpellTarget::OtherPlayer(target_player_id) => {
@@ -328,14 +328,7 @@ impl Map {
         }
     }
 
-
-    async fn polymorph_spell(
-        &mut self,
-        player_id: i32,
-        npc_index: i32,
-        spell_data: &EsfRecord,
-    ) {
-       
+    async fn polymorph_spell(&mut self, player_id: i32, npc_index: i32, spell_data: &EsfRecord) {
         let character = match self.characters.get_mut(&player_id) {
             Some(character) => character,
             None => return,
@@ -347,8 +340,7 @@ impl Map {
 
         let new_npc_id = 170;
 
-    
-       // Check if target NPC exists and isn't already polymorphed
+        // Check if target NPC exists and isn't already polymorphed
         let (coords, direction) = match self.npcs.get(&npc_index) {
             Some(npc) => {
                 if npc.polymorphed {
@@ -362,7 +354,7 @@ impl Map {
         // as with cast_damage_npc
         character.tp -= spell_data.tp_cost;
 
-        // then we clear the npc 
+        // then we clear the npc
 
         // Clear the NPC slot on clients first
         self.send_packet_all(
@@ -382,15 +374,15 @@ impl Map {
             npc.old_id = npc.id;
             npc.id = new_npc_id;
             npc.polymorphed = true;
-            
+
             // Reset HP to max for the new form
-            if let Some(new_data) = NPC_DB.npcs.get(new_npc_id as usize - 1) {
+            if let Some(new_data) = NPC_DB.load().npcs.get(new_npc_id as usize - 1) {
                 npc.max_hp = new_data.hp;
                 npc.hp = new_data.hp;
             }
         }
 
-         // Spawn "polymorphed" NPC on clients
+        // Spawn "polymorphed" NPC on clients
         self.send_packet_all(
             PacketAction::Agree,
             PacketFamily::Npc,
@@ -426,7 +418,6 @@ impl Map {
         spell_id: i32,
         spell_data: &EsfRecord,
     ) {
-       
         let character = match self.characters.get_mut(&player_id) {
             Some(character) => character,
             None => return,
@@ -443,7 +434,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -18,7 +18,7 @@ impl Map {
             return;
         }
 
-        let leveled_up = character.add_experience(experience);
+        let leveled_up = character.add_experience(experience.into());
         character.captcha_open = false;
 
         let character = match self.characters.get(&player_id) {
@@ -31,7 +31,7 @@ impl Map {
                 PacketAction::Close,
                 PacketFamily::Unrecognized(FAMILY_CAPTCHA),
                 &CaptchaCloseServerPacket {
-                    experience: character.experience,
+                    experience: character.get_packet_experience(),
                 },
             );
 
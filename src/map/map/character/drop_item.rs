@@ -1,5 +1,6 @@
 use std::cmp;
 
+use chrono::Utc;
 use eolib::protocol::{
     net::{
         client::ByteCoords,
@@ -10,7 +11,11 @@ use eolib::protocol::{
     Coords,
 };
 
-use crate::{utils::get_distance, ITEM_DB, SETTINGS};
+use crate::{
+    map::{ItemHolder, ItemTransfer},
+    utils::get_distance,
+    ITEM_DB, SETTINGS,
+};
 
 use super::super::Map;
 
@@ -23,7 +28,8 @@ impl Map {
             return;
         }
 
-        let item_record = match ITEM_DB.items.get(item.id as usize - 1) {
+        let item_db = ITEM_DB.load();
+        let item_record = match item_db.items.get(item.id as usize - 1) {
             Some(item) => item,
             None => return,
         };
@@ -107,14 +113,23 @@ impl Map {
             );
         }
 
+        self.log_item_transfer(ItemTransfer {
+            item_id: item.id,
+            amount: amount_to_drop,
+            source: ItemHolder::Character(character.name.clone()),
+            destination: ItemHolder::Ground,
+            coords,
+        });
+
         self.items.insert(
             item_index,
             super::super::Item {
                 id: item.id,
                 amount: amount_to_drop,
                 coords,
-                owner: player_id,
+                owner: character.id,
                 protected_ticks: SETTINGS.world.drop_protect_player,
+                dropped_at: Utc::now(),
             },
         );
 
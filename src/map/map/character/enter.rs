@@ -43,10 +43,11 @@ impl Map {
 
         character.entered_map();
 
+        let npc_db = NPC_DB.load();
         if character.is_deep {
             if let Some(player) = &character.player {
                 for (npc_index, npc) in self.npcs.iter().filter(|(_, npc)| {
-                    let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+                    let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
                         Some(npc) => npc,
                         None => return false,
                     };
@@ -3,7 +3,11 @@ use eolib::protocol::net::{
     PacketAction, PacketFamily, ThreeItem,
 };
 
-use crate::{utils::get_distance, SETTINGS};
+use crate::{
+    map::{ItemHolder, ItemTransfer},
+    utils::get_distance,
+    SETTINGS,
+};
 
 use super::super::Map;
 
@@ -21,15 +25,15 @@ impl Map {
                 None => return,
             };
 
-        if protected_ticks > 0 && owner != player_id {
-            return;
-        }
-
         let character = match self.characters.get_mut(&player_id) {
             Some(character) => character,
             None => return,
         };
 
+        if protected_ticks > 0 && owner != character.id {
+            return;
+        }
+
         let distance = get_distance(&item_coords, &character.coords);
         if distance > SETTINGS.world.drop_distance {
             return;
@@ -41,6 +45,7 @@ impl Map {
         }
 
         character.add_item(item_id, amount_picked_up);
+        let character_name = character.name.clone();
 
         if let Some(player) = character.player.as_ref() {
             player.send(
@@ -57,6 +62,14 @@ impl Map {
             );
         }
 
+        self.log_item_transfer(ItemTransfer {
+            item_id,
+            amount: amount_picked_up,
+            source: ItemHolder::Ground,
+            destination: ItemHolder::Character(character_name),
+            coords: item_coords,
+        });
+
         if amount_picked_up == item_amount {
             self.items.remove(&item_index);
         } else {
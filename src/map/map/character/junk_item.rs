@@ -2,7 +2,10 @@ use std::cmp;
 
 use eolib::protocol::net::{server::ItemJunkServerPacket, PacketAction, PacketFamily, ThreeItem};
 
-use crate::SETTINGS;
+use crate::{
+    map::{ItemHolder, ItemTransfer},
+    SETTINGS,
+};
 
 use super::super::Map;
 
@@ -47,5 +50,16 @@ impl Map {
                 },
             );
         }
+
+        let name = character.name.clone();
+        let coords = character.coords;
+
+        self.log_item_transfer(ItemTransfer {
+            item_id,
+            amount: amount_to_junk,
+            source: ItemHolder::Character(name),
+            destination: ItemHolder::Junk,
+            coords,
+        });
     }
 }
@@ -2,7 +2,10 @@ use std::cmp;
 
 use eolib::protocol::net::{server::ItemKickServerPacket, Item, PacketAction, PacketFamily};
 
-use crate::SETTINGS;
+use crate::{
+    map::{ItemHolder, ItemTransfer},
+    SETTINGS,
+};
 
 use super::super::Map;
 
@@ -57,5 +60,13 @@ impl Map {
                 },
             );
         }
+
+        self.log_item_transfer(ItemTransfer {
+            item_id,
+            amount: amount_to_junk,
+            source: ItemHolder::Character(character.name.clone()),
+            destination: ItemHolder::Quest,
+            coords: character.coords,
+        });
     }
 }
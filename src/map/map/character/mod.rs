@@ -14,6 +14,7 @@ mod leave;
 mod level_skill;
 mod level_stat;
 mod lose_item;
+mod mute;
 mod open_captcha;
 mod open_door;
 mod party_request;
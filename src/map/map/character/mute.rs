@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+
+use super::super::Map;
+
+impl Map {
+    pub fn mute(&mut self, player_id: i32, muted_until: DateTime<Utc>) {
+        let character = match self.characters.get_mut(&player_id) {
+            Some(character) => character,
+            None => return,
+        };
+
+        character.muted_until = Some(muted_until);
+    }
+}
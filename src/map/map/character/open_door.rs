@@ -25,9 +25,10 @@ impl Map {
         }
 
         // Key 1 just means it's an unlocked door
+        let item_db = ITEM_DB.load();
         if door.key > 1
             && !character.items.iter().any(|item| {
-                let item_data = match ITEM_DB.items.get(item.id as usize - 1) {
+                let item_data = match item_db.items.get(item.id as usize - 1) {
                     Some(item_data) => item_data,
                     None => return false,
                 };
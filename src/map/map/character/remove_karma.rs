@@ -28,7 +28,7 @@ impl Map {
             PacketAction::Reply,
             PacketFamily::Recover,
             &RecoverReplyServerPacket {
-                experience: character.experience,
+                experience: character.get_packet_experience(),
                 karma: character.karma,
                 ..Default::default()
             },
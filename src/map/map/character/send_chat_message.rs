@@ -13,6 +13,11 @@ impl Map {
             return;
         }
 
+        if character.is_muted() {
+            character.send_muted_message();
+            return;
+        }
+
         self.send_packet_near_player(
             player_id,
             PacketAction::Player,
@@ -47,7 +47,8 @@ impl Map {
             return;
         }
 
-        let item = match ITEM_DB.items.get(item_id as usize - 1) {
+        let item_db = ITEM_DB.load();
+        let item = match item_db.items.get(item_id as usize - 1) {
             Some(item) => item,
             None => {
                 return;
@@ -98,7 +99,12 @@ impl Map {
 
                 let (map_id, coords) = {
                     match item.spec1 {
-                        0 => match INN_DB.inns.iter().find(|inn| inn.name == character.home) {
+                        0 => match INN_DB
+                            .load()
+                            .inns
+                            .iter()
+                            .find(|inn| inn.name == character.home)
+                        {
                             Some(inn) => (
                                 inn.spawn_map,
                                 Coords {
@@ -175,10 +181,10 @@ impl Map {
             }
             ItemType::ExpReward => {
                 packet.item_type = ItemType::ExpReward;
-                let leveled_up = character.add_experience(item.spec1);
+                let leveled_up = character.add_experience(item.spec1.into());
                 packet.item_type_data = Some(ItemReplyServerPacketItemTypeData::ExpReward(
                     ItemReplyServerPacketItemTypeDataExpReward {
-                        experience: character.experience,
+                        experience: character.get_packet_experience(),
                         level_up: if leveled_up { character.level } else { 0 },
                         stat_points: character.stat_points,
                         skill_points: character.skill_points,
@@ -198,7 +204,7 @@ impl Map {
                 }
             }
             ItemType::Reserved7 => {
-                if SPELL_DB.skills.len() < item.spec1 as usize {
+                if SPELL_DB.load().skills.len() < item.spec1 as usize {
                     return;
                 }
 
@@ -213,7 +219,7 @@ impl Map {
                         continue;
                     }
 
-                    let item = match ITEM_DB.items.get(*item_id as usize - 1) {
+                    let item = match item_db.items.get(*item_id as usize - 1) {
                         Some(item) => item,
                         None => {
                             continue;
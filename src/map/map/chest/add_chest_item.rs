@@ -10,7 +10,7 @@ use eolib::{
 };
 
 use crate::{
-    map::{chest::ChestItem, Chest},
+    map::{chest::ChestItem, Chest, ItemHolder, ItemTransfer},
     utils::get_distance,
     SETTINGS,
 };
@@ -104,6 +104,16 @@ impl Map {
             })
             .collect();
 
+        let chest_coords = chest.coords;
+
+        self.log_item_transfer(ItemTransfer {
+            item_id: item.id,
+            amount,
+            source: ItemHolder::Character(character.name.clone()),
+            destination: ItemHolder::Chest,
+            coords: chest_coords,
+        });
+
         if let Some(player) = character.player.as_ref() {
             player.send(
                 PacketAction::Reply,
@@ -129,7 +139,7 @@ impl Map {
         let buf = writer.to_byte_array();
 
         for (id, character) in self.characters.iter() {
-            let distance = get_distance(&character.coords, &chest.coords);
+            let distance = get_distance(&character.coords, &chest_coords);
             if *id == player_id || distance > 1 {
                 continue;
             }
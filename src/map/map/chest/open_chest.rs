@@ -30,9 +30,10 @@ impl Map {
             return;
         }
 
+        let item_db = ITEM_DB.load();
         if let Some(key) = chest.key {
             if !character.items.iter().any(|item| {
-                let item_data = match ITEM_DB.items.get(item.id as usize - 1) {
+                let item_data = match item_db.items.get(item.id as usize - 1) {
                     Some(item_data) => item_data,
                     None => return false,
                 };
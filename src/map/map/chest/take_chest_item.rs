@@ -7,7 +7,10 @@ use eolib::{
     },
 };
 
-use crate::{map::Chest, utils::get_distance};
+use crate::{
+    map::{Chest, ItemHolder, ItemTransfer},
+    utils::get_distance,
+};
 
 use super::super::Map;
 
@@ -97,6 +100,17 @@ impl Map {
             );
         }
 
+        let name = character.name.clone();
+        let chest_coords = chest.coords;
+
+        self.log_item_transfer(ItemTransfer {
+            item_id: item.item_id,
+            amount: item.amount,
+            source: ItemHolder::Chest,
+            destination: ItemHolder::Character(name),
+            coords: chest_coords,
+        });
+
         let packet = ChestAgreeServerPacket {
             items: remaining_items,
         };
@@ -111,7 +125,7 @@ impl Map {
         let buf = writer.to_byte_array();
 
         for (id, character) in self.characters.iter() {
-            let distance = get_distance(&character.coords, &chest.coords);
+            let distance = get_distance(&character.coords, &chest_coords);
             if *id == player_id || distance > 1 {
                 continue;
             }
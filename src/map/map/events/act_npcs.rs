@@ -26,7 +26,8 @@ use super::super::Map;
 
 impl Map {
     fn act_npc_talk(&mut self, index: i32, npc_id: i32) -> Option<NpcUpdateChat> {
-        let talk_record = TALK_DB.npcs.iter().find(|record| record.npc_id == npc_id)?;
+        let talk_db = TALK_DB.load();
+        let talk_record = talk_db.npcs.iter().find(|record| record.npc_id == npc_id)?;
 
         let npc = self.npcs.get_mut(&index)?;
 
@@ -155,7 +156,8 @@ impl Map {
 
     // TODO: Party stuff
     fn npc_get_chase_target_player_id(&self, index: i32, npc_id: i32) -> Option<i32> {
-        let npc_data = NPC_DB.npcs.get(npc_id as usize - 1)?;
+        let npc_db = NPC_DB.load();
+        let npc_data = npc_db.npcs.get(npc_id as usize - 1)?;
 
         let npc = self.npcs.get(&index)?;
 
@@ -221,7 +223,8 @@ impl Map {
         if let Some(opponent) = adjacent_opponent {
             Some(opponent.player_id)
         } else {
-            let npc_data = NPC_DB.npcs.get(npc.id as usize - 1)?;
+            let npc_db = NPC_DB.load();
+            let npc_data = npc_db.npcs.get(npc.id as usize - 1)?;
 
             // TODO: also attack adjacent players if blocking path to opponent(s)
             // Choose a random player if npc is aggressive
@@ -294,7 +297,8 @@ impl Map {
 
         let idle_rate = act_rate + walk_idle_for;
 
-        let npc_data = NPC_DB.npcs.get(npc_id as usize - 1)?;
+        let npc_db = NPC_DB.load();
+        let npc_data = npc_db.npcs.get(npc_id as usize - 1)?;
 
         if npc_data.r#type == NpcType::Aggressive || has_opponent {
             self.act_npc_move_chase(index, npc_id, npc_data.r#type)
@@ -313,7 +317,8 @@ impl Map {
 
             let npc = self.npcs.get(&index)?;
 
-            let npc_data = NPC_DB.npcs.get(npc_id as usize - 1)?;
+            let npc_db = NPC_DB.load();
+            let npc_data = npc_db.npcs.get(npc_id as usize - 1)?;
 
             let xdiff = npc.coords.x - character.coords.x;
             let ydiff = npc.coords.y - character.coords.y;
@@ -444,7 +449,7 @@ impl Map {
 
         if !self.npcs_initialized {
             self.npcs_initialized = true;
-            for (spawn_index, spawn) in self.file.npcs.iter().enumerate() {
+            for (spawn_index, spawn) in self.npc_spawns.iter().enumerate() {
                 let npcs = {
                     self.npcs
                         .iter()
@@ -576,7 +581,7 @@ fn get_damage_amount(npc: &Npc, npc_data: &EnfRecord, character: &Character) ->
         }
     };
 
-    let hit_rate = match eval_float_with_context(&FORMULAS.hit_rate, &context) {
+    let hit_rate = match eval_float_with_context(&FORMULAS.load().hit_rate, &context) {
         Ok(hit_rate) => hit_rate,
         Err(e) => {
             error!("Failed to calculate hit rate: {}", e);
@@ -588,7 +593,7 @@ fn get_damage_amount(npc: &Npc, npc_data: &EnfRecord, character: &Character) ->
         return 0;
     }
 
-    match eval_float_with_context(&FORMULAS.damage, &context) {
+    match eval_float_with_context(&FORMULAS.load().damage, &context) {
         Ok(amount) => cmp::min(amount.floor() as i32, character.hp),
         Err(e) => {
             error!("Failed to calculate damage: {}", e);
@@ -11,15 +11,17 @@ impl Map {
     pub fn spawn_npcs(&mut self) {
         self.npcs.retain(|_, n| n.spawn_index.is_some() || n.alive);
 
-        if self.file.npcs.is_empty() {
+        if self.npc_spawns.is_empty() {
             return;
         }
 
+        let npc_db = NPC_DB.load();
+
         if self.npcs.is_empty() {
             let mut npc_index: i32 = 0;
 
-            for (spawn_index, spawn) in self.file.npcs.iter().enumerate() {
-                let data_record = match NPC_DB.npcs.get(spawn.id as usize - 1) {
+            for (spawn_index, spawn) in self.npc_spawns.iter().enumerate() {
+                let data_record = match npc_db.npcs.get(spawn.id as usize - 1) {
                     Some(npc) => npc,
                     None => {
                         error!(
@@ -71,8 +73,8 @@ impl Map {
 
                         npc.spawn_ticks = cmp::max(npc.spawn_ticks - 1, 0);
 
-                        let spawn = &self.file.npcs[spawn_index];
-                        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+                        let spawn = &self.npc_spawns[spawn_index];
+                        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
                             Some(npc_data) => npc_data,
                             None => continue,
                         };
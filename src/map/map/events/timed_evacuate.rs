@@ -30,11 +30,11 @@ impl Map {
         }
 
         if steps.contains(&seconds) {
-            self.send_evac_warning(&LANG.evacuate_warning, seconds);
+            self.send_evac_warning(&LANG.load().evacuate_warning, seconds);
         }
 
         if seconds == SETTINGS.evacuate.timer_step {
-            self.send_evac_warning(&LANG.evacuate_last_warning, seconds);
+            self.send_evac_warning(&LANG.load().evacuate_last_warning, seconds);
         }
 
         if seconds == 0 {
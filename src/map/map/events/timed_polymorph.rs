@@ -7,22 +7,19 @@ use super::super::Map;
 impl Map {
     pub fn timed_polymorph(&mut self) {
         use eolib::protocol::net::{
-            server::{
-                NpcSpecServerPacket, NpcAgreeServerPacket, NpcKilledData, NpcMapInfo,
-            },
+            server::{NpcAgreeServerPacket, NpcKilledData, NpcMapInfo, NpcSpecServerPacket},
             PacketAction, PacketFamily,
         };
 
         let mut npcs_to_reverse: Vec<(i32, i32, i32, Coords, Direction)> = Vec::new();
-        
+
         for (npc_index, npc) in &self.npcs {
             if npc.polymorphed && npc.alive {
                 npcs_to_reverse.push((*npc_index, npc.old_id, npc.id, npc.coords, npc.direction));
             }
         }
 
-        for (npc_index, old_id, current_id, coords, direction) in npcs_to_reverse {
-            
+        for (npc_index, old_id, _current_id, coords, direction) in npcs_to_reverse {
             self.send_packet_all(
                 PacketAction::Spec,
                 PacketFamily::Npc,
@@ -38,13 +35,13 @@ impl Map {
             if let Some(npc) = self.npcs.get_mut(&npc_index) {
                 // Double-check the NPC is still alive and polymorphed before reversing
                 if npc.alive && npc.polymorphed {
-                    npc.id = old_id; 
-                    npc.polymorphed = false; 
-                    npc.old_id = 0;  
-                    
-                    if let Some(original_data) = NPC_DB.npcs.get(old_id as usize - 1) {
+                    npc.id = old_id;
+                    npc.polymorphed = false;
+                    npc.old_id = 0;
+
+                    if let Some(original_data) = NPC_DB.load().npcs.get(old_id as usize - 1) {
                         npc.max_hp = original_data.hp;
-                        npc.hp = original_data.hp; 
+                        npc.hp = original_data.hp;
                     }
 
                     self.send_packet_all(
@@ -63,4 +60,4 @@ impl Map {
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -6,7 +6,10 @@ use eolib::{
     },
 };
 
-use crate::{map::WeddingState, LANG, SETTINGS};
+use crate::{
+    map::{ItemHolder, WeddingState},
+    LANG, SETTINGS,
+};
 
 use super::super::Map;
 
@@ -41,7 +44,7 @@ impl Map {
             let character = match self.characters.get(&player_id) {
                 Some(character) => character,
                 None => {
-                    self.npc_chat(npc_index, &LANG.wedding_error);
+                    self.npc_chat(npc_index, &LANG.load().wedding_error);
                     self.wedding = None;
                     self.wedding_ticks = 0;
                     return;
@@ -51,7 +54,7 @@ impl Map {
             let partner = match self.characters.get(&partner_id) {
                 Some(character) => character,
                 None => {
-                    self.npc_chat(npc_index, &LANG.wedding_error);
+                    self.npc_chat(npc_index, &LANG.load().wedding_error);
                     self.wedding = None;
                     self.wedding_ticks = 0;
                     return;
@@ -63,7 +66,7 @@ impl Map {
                     self.npc_chat(
                         npc_index,
                         &get_lang_string!(
-                            &LANG.wedding_start,
+                            &LANG.load().wedding_start,
                             delay = SETTINGS.marriage.ceremony_start_delay_seconds
                         ),
                     );
@@ -96,7 +99,7 @@ impl Map {
                     self.npc_chat(
                         npc_index,
                         &get_lang_string!(
-                            &LANG.wedding_one,
+                            &LANG.load().wedding_one,
                             partner = partner.name,
                             name = character.name
                         ),
@@ -107,7 +110,7 @@ impl Map {
                     self.npc_chat(
                         npc_index,
                         &get_lang_string!(
-                            &LANG.wedding_two,
+                            &LANG.load().wedding_two,
                             partner = partner.name,
                             name = character.name
                         ),
@@ -118,7 +121,7 @@ impl Map {
                     self.npc_chat(
                         npc_index,
                         &get_lang_string!(
-                            &LANG.wedding_do_you,
+                            &LANG.load().wedding_do_you,
                             partner = partner.name,
                             name = character.name
                         ),
@@ -141,20 +144,20 @@ impl Map {
                     WeddingState::WaitingForPartner
                 }
                 WeddingState::WaitingForPartner | WeddingState::WaitingForPlayer => {
-                    self.npc_chat(npc_index, &LANG.wedding_error);
+                    self.npc_chat(npc_index, &LANG.load().wedding_error);
                     self.wedding = None;
                     self.wedding_ticks = 0;
                     return;
                 }
                 WeddingState::PartnerAgrees => {
-                    self.player_chat(partner_id, &LANG.wedding_i_do);
+                    self.player_chat(partner_id, &LANG.load().wedding_i_do);
                     WeddingState::PriestDoYouPlayer
                 }
                 WeddingState::PriestDoYouPlayer => {
                     self.npc_chat(
                         npc_index,
                         &get_lang_string!(
-                            &LANG.wedding_do_you,
+                            &LANG.load().wedding_do_you,
                             name = partner.name,
                             partner = character.name
                         ),
@@ -177,22 +180,32 @@ impl Map {
                     WeddingState::WaitingForPlayer
                 }
                 WeddingState::PlayerAgrees => {
-                    self.player_chat(player_id, &LANG.wedding_i_do);
+                    self.player_chat(player_id, &LANG.load().wedding_i_do);
                     WeddingState::PriestDialog3
                 }
                 WeddingState::PriestDialog3 => {
-                    self.npc_chat(npc_index, &LANG.wedding_three);
+                    self.npc_chat(npc_index, &LANG.load().wedding_three);
                     let partner_name = partner.name.to_owned();
                     let character_name = character.name.to_owned();
 
-                    self.give_item(player_id, SETTINGS.marriage.ring_item_id, 1);
-                    self.give_item(partner_id, SETTINGS.marriage.ring_item_id, 1);
+                    self.give_item(
+                        player_id,
+                        SETTINGS.marriage.ring_item_id,
+                        1,
+                        ItemHolder::Wedding,
+                    );
+                    self.give_item(
+                        partner_id,
+                        SETTINGS.marriage.ring_item_id,
+                        1,
+                        ItemHolder::Wedding,
+                    );
                     self.set_partner(player_id, partner_name);
                     self.set_partner(partner_id, character_name);
                     WeddingState::PriestDialog4
                 }
                 WeddingState::PriestDialog4 => {
-                    self.npc_chat(npc_index, &LANG.wedding_four);
+                    self.npc_chat(npc_index, &LANG.load().wedding_four);
                     WeddingState::Hearts
                 }
                 WeddingState::Hearts => {
@@ -206,7 +219,7 @@ impl Map {
                     self.npc_chat(
                         npc_index,
                         &get_lang_string!(
-                            &LANG.wedding_five,
+                            &LANG.load().wedding_five,
                             partner = partner.name,
                             name = character.name
                         ),
@@ -217,7 +230,7 @@ impl Map {
                     WeddingState::Done
                 }
                 WeddingState::Done => {
-                    self.npc_chat(npc_index, &LANG.wedding_end);
+                    self.npc_chat(npc_index, &LANG.load().wedding_end);
                     self.wedding = None;
                     self.wedding_ticks = 0;
                     return;
@@ -4,10 +4,11 @@ use eolib::protocol::{
     net::{server::GuildBuyServerPacket, PacketAction, PacketFamily},
     r#pub::NpcType,
 };
-use mysql_async::{prelude::Queryable, Params};
-use mysql_common::{params, Row};
 
-use crate::{NPC_DB, SETTINGS};
+use crate::{
+    db::{params, Params, Queryable, Row},
+    NPC_DB, SETTINGS,
+};
 
 use super::super::Map;
 
@@ -24,7 +25,7 @@ impl Map {
         };
 
         match self.npcs.get(&npc_index) {
-            Some(npc) => match NPC_DB.npcs.get(npc.id as usize - 1) {
+            Some(npc) => match NPC_DB.load().npcs.get(npc.id as usize - 1) {
                 Some(npc_data) => {
                     if npc_data.r#type != NpcType::Guild {
                         return;
@@ -22,16 +22,15 @@ impl Map {
             return;
         }
 
-        if !SETTINGS
-            .bard
-            .instrument_items
-            .iter()
-            .any(|id| instrument_id == *id)
-        {
+        if !SETTINGS.bard.instrument_items.contains(&instrument_id) {
             return;
         }
 
-        match ITEM_DB.items.get(character.equipment.weapon as usize - 1) {
+        match ITEM_DB
+            .load()
+            .items
+            .get(character.equipment.weapon as usize - 1)
+        {
             Some(item_data) => {
                 if item_data.spec1 != instrument_id {
                     return;
@@ -40,8 +39,9 @@ impl Map {
             None => return,
         };
 
+        let spell_db = SPELL_DB.load();
         if !character.spells.iter().any(|s| {
-            let spell_data = match SPELL_DB.skills.get(s.id as usize - 1) {
+            let spell_data = match spell_db.skills.get(s.id as usize - 1) {
                 Some(spell) => spell,
                 None => return false,
             };
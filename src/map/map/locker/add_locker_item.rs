@@ -9,7 +9,10 @@ use eolib::protocol::{
     Coords,
 };
 
-use crate::SETTINGS;
+use crate::{
+    map::{ItemHolder, ItemTransfer},
+    SETTINGS,
+};
 
 use super::super::Map;
 
@@ -103,5 +106,16 @@ impl Map {
                 },
             );
         }
+
+        let name = character.name.clone();
+        let coords = character.coords;
+
+        self.log_item_transfer(ItemTransfer {
+            item_id: item.id,
+            amount,
+            source: ItemHolder::Character(name),
+            destination: ItemHolder::Locker,
+            coords,
+        });
     }
 }
@@ -6,6 +6,8 @@ use eolib::protocol::{
     Coords,
 };
 
+use crate::map::{ItemHolder, ItemTransfer};
+
 use super::super::Map;
 
 impl Map {
@@ -80,5 +82,16 @@ impl Map {
                 },
             );
         }
+
+        let name = character.name.clone();
+        let coords = character.coords;
+
+        self.log_item_transfer(ItemTransfer {
+            item_id,
+            amount,
+            source: ItemHolder::Locker,
+            destination: ItemHolder::Character(name),
+            coords,
+        });
     }
 }
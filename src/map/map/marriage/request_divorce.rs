@@ -8,18 +8,20 @@ use eolib::protocol::{
     },
     r#pub::NpcType,
 };
-use mysql_async::prelude::Queryable;
-use mysql_common::params;
 
-use crate::{NPC_DB, SETTINGS};
+use crate::{
+    db::{params, Queryable},
+    NPC_DB, SETTINGS,
+};
 
 use super::super::Map;
 
 impl Map {
     pub fn request_divorce(&mut self, player_id: i32, npc_index: i32, name: String) {
+        let npc_db = NPC_DB.load();
         match self.npcs.get(&npc_index) {
             Some(npc) => {
-                let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+                let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
                     Some(npc_data) => npc_data,
                     None => return,
                 };
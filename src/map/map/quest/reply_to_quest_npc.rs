@@ -35,7 +35,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -48,7 +49,8 @@ impl Map {
 
         character.talked_to_npc(npc_data.behavior_id, quest_id, action_id);
 
-        let quests_for_npc = QUEST_DB
+        let quest_db = QUEST_DB.load();
+        let quests_for_npc = quest_db
             .iter()
             .filter(|(id, quest)| {
                 let progress = character.get_quest_progress(**id);
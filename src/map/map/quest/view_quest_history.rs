@@ -26,7 +26,10 @@ impl Map {
             .iter()
             .filter_map(|q| {
                 if q.done_at.is_some() && q.state != 0 {
-                    QUEST_DB.get(&q.id).map(|quest| quest.name.to_owned())
+                    QUEST_DB
+                        .load()
+                        .get(&q.id)
+                        .map(|quest| quest.name.to_owned())
                 } else {
                     None
                 }
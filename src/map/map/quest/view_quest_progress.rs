@@ -23,6 +23,7 @@ impl Map {
             None => return,
         };
 
+        let quest_db = QUEST_DB.load();
         let quest_progress_entries = character
             .quests
             .iter()
@@ -31,7 +32,7 @@ impl Map {
                     return None;
                 }
 
-                let quest = match QUEST_DB.get(&q.id) {
+                let quest = match quest_db.get(&q.id) {
                     Some(quest) => quest,
                     None => return None,
                 };
@@ -3,7 +3,10 @@ use eolib::protocol::{
     r#pub::NpcType,
 };
 
-use crate::{NPC_DB, SHOP_DB};
+use crate::{
+    map::{ItemHolder, ItemTransfer},
+    NPC_DB, SHOP_DB,
+};
 
 use super::super::Map;
 
@@ -19,7 +22,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -28,7 +32,8 @@ impl Map {
             return;
         }
 
-        let shop = match SHOP_DB
+        let shop_db = SHOP_DB.load();
+        let shop = match shop_db
             .shops
             .iter()
             .find(|shop| shop.behavior_id == npc_data.behavior_id)
@@ -86,5 +91,28 @@ impl Map {
                 },
             );
         }
+
+        let name = character.name.clone();
+        let coords = character.coords;
+
+        for ingredient in craft.ingredients.iter() {
+            if ingredient.item_id > 0 {
+                self.log_item_transfer(ItemTransfer {
+                    item_id: ingredient.item_id,
+                    amount: ingredient.amount,
+                    source: ItemHolder::Character(name.clone()),
+                    destination: ItemHolder::Craft,
+                    coords,
+                });
+            }
+        }
+
+        self.log_item_transfer(ItemTransfer {
+            item_id,
+            amount: 1,
+            source: ItemHolder::Craft,
+            destination: ItemHolder::Character(name),
+            coords,
+        });
     }
 }
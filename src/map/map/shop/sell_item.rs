@@ -8,7 +8,10 @@ use eolib::protocol::{
     r#pub::NpcType,
 };
 
-use crate::{NPC_DB, SETTINGS, SHOP_DB};
+use crate::{
+    map::{ItemHolder, ItemTransfer},
+    NPC_DB, SETTINGS, SHOP_DB,
+};
 
 use super::super::Map;
 
@@ -28,7 +31,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -37,7 +41,8 @@ impl Map {
             return;
         }
 
-        let shop = match SHOP_DB
+        let shop_db = SHOP_DB.load();
+        let shop = match shop_db
             .shops
             .iter()
             .find(|shop| shop.behavior_id == npc_data.behavior_id)
@@ -82,5 +87,24 @@ impl Map {
                 },
             );
         }
+
+        let name = character.name.clone();
+        let coords = character.coords;
+
+        self.log_item_transfer(ItemTransfer {
+            item_id: item.id,
+            amount,
+            source: ItemHolder::Character(name.clone()),
+            destination: ItemHolder::Shop,
+            coords,
+        });
+
+        self.log_item_transfer(ItemTransfer {
+            item_id: 1,
+            amount: price,
+            source: ItemHolder::Shop,
+            destination: ItemHolder::Character(name),
+            coords,
+        });
     }
 }
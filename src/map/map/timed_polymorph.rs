@@ -22,7 +22,12 @@ impl Map {
                 if let Some(expire_time) = npc.polymorph_expire_time {
                     if now >= expire_time {
                         if let Some(original_id) = npc.original_id {
-                            npcs_to_restore.push((npc_index, original_id, npc.coords, npc.direction));
+                            npcs_to_restore.push((
+                                npc_index,
+                                original_id,
+                                npc.coords,
+                                npc.direction,
+                            ));
                         }
                     }
                 }
@@ -36,7 +41,7 @@ impl Map {
     }
 
     /// Polymorph an NPC into a different form for a specified duration
-    pub fn polymorph_npc(&mut self, npc_index: i32, new_npc_id: i32, duration_seconds: u32){
+    pub fn polymorph_npc(&mut self, npc_index: i32, new_npc_id: i32, duration_seconds: u32) {
         // Check if target NPC exists and isn't already polymorphed
         let (coords, direction, original_id) = match self.npcs.get(&npc_index) {
             Some(npc) => {
@@ -66,12 +71,14 @@ impl Map {
             npc.original_id = Some(original_id); // Store original ID
             npc.id = new_npc_id;
             npc.polymorphed = true;
-            
+
             // Set polymorph to expire after specified duration
-            npc.polymorph_expire_time = Some(std::time::Instant::now() + std::time::Duration::from_secs(duration_seconds as u64));
-            
+            npc.polymorph_expire_time = Some(
+                std::time::Instant::now() + std::time::Duration::from_secs(duration_seconds as u64),
+            );
+
             // Reset HP to max for the new form
-            if let Some(new_data) = NPC_DB.npcs.get(new_npc_id as usize - 1) {
+            if let Some(new_data) = NPC_DB.load().npcs.get(new_npc_id as usize - 1) {
                 npc.max_hp = new_data.hp;
                 npc.hp = new_data.hp;
             }
@@ -93,7 +100,13 @@ impl Map {
     }
 
     /// Restore an NPC from its polymorphed form back to original
-    fn restore_npc_from_polymorph(&mut self, npc_index: i32, original_id: i32, coords: Coords, direction: Direction) {
+    fn restore_npc_from_polymorph(
+        &mut self,
+        npc_index: i32,
+        original_id: i32,
+        coords: Coords,
+        direction: Direction,
+    ) {
         // Clear the polymorphed NPC from clients first
         self.send_packet_all(
             PacketAction::Spec,
@@ -113,9 +126,9 @@ impl Map {
             npc.polymorphed = false;
             npc.original_id = None;
             npc.polymorph_expire_time = None;
-            
+
             // Reset HP to max for the original form
-            if let Some(original_data) = NPC_DB.npcs.get(original_id as usize - 1) {
+            if let Some(original_data) = NPC_DB.load().npcs.get(original_id as usize - 1) {
                 npc.max_hp = original_data.hp;
                 npc.hp = original_data.hp;
             }
@@ -148,4 +161,4 @@ impl Map {
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -30,7 +30,8 @@ impl Map {
             return;
         }
 
-        let item_data = match ITEM_DB.items.get(item.id as usize - 1) {
+        let item_db = ITEM_DB.load();
+        let item_data = match item_db.items.get(item.id as usize - 1) {
             Some(item_data) => item_data,
             None => return,
         };
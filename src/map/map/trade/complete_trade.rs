@@ -8,7 +8,10 @@ use eolib::protocol::{
     Emote,
 };
 
-use crate::SETTINGS;
+use crate::{
+    map::{ItemHolder, ItemTransfer},
+    SETTINGS,
+};
 
 use super::super::Map;
 
@@ -128,6 +131,26 @@ impl Map {
             );
         }
 
+        for item in &trade_items {
+            self.log_item_transfer(ItemTransfer {
+                item_id: item.id,
+                amount: item.amount,
+                source: ItemHolder::Character(character.name.clone()),
+                destination: ItemHolder::Character(partner_character.name.clone()),
+                coords: character.coords,
+            });
+        }
+
+        for item in &partner_trade_items {
+            self.log_item_transfer(ItemTransfer {
+                item_id: item.id,
+                amount: item.amount,
+                source: ItemHolder::Character(partner_character.name.clone()),
+                destination: ItemHolder::Character(character.name.clone()),
+                coords: partner_character.coords,
+            });
+        }
+
         self.emote(player_id, Emote::Trade);
         self.emote(partner_id, Emote::Trade);
     }
@@ -1,3 +1,4 @@
+use chrono::Utc;
 use eolib::{
     data::{EoSerialize, EoWriter},
     protocol::{
@@ -19,7 +20,7 @@ use rand::Rng;
 
 use crate::{
     deep::{BossPingServerPacket, FAMILY_BOSS},
-    map::Item,
+    map::{Item, ItemHolder, ItemTransfer},
     utils::in_client_range,
     DROP_DB, FORMULAS, NPC_DB, SETTINGS,
 };
@@ -181,7 +182,8 @@ impl Map {
             None => return,
         };
 
-        let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(npc_id as usize - 1) {
             Some(npc_data) => npc_data,
             None => return,
         };
@@ -209,7 +211,7 @@ impl Map {
                     }
                 };
 
-                match eval_float_with_context(&FORMULAS.party_exp_share, &context) {
+                match eval_float_with_context(&FORMULAS.load().party_exp_share, &context) {
                     Ok(experience) => experience as i32,
                     Err(e) => {
                         error!("Failed to calculate party experience share: {}", e);
@@ -243,7 +245,11 @@ impl Map {
             });
         }
 
-        let drop = get_drop(killer_player_id, npc_id, &npc_coords);
+        let killer_character_id = match self.characters.get(&killer_player_id) {
+            Some(character) => character.id,
+            None => 0,
+        };
+        let drop = get_drop(killer_character_id, npc_id, &npc_coords);
 
         let (drop_index, drop_item_id, drop_amount) = match drop {
             Some(drop) => {
@@ -251,6 +257,13 @@ impl Map {
                 let drop_item_id = drop.id;
                 let drop_amount = drop.amount;
                 self.items.insert(index, drop);
+                self.log_item_transfer(ItemTransfer {
+                    item_id: drop_item_id,
+                    amount: drop_amount,
+                    source: ItemHolder::Npc(npc_id),
+                    destination: ItemHolder::Ground,
+                    coords: npc_coords,
+                });
                 (index, drop_item_id, drop_amount)
             }
             None => (0, 0, 0),
@@ -317,7 +330,7 @@ impl Map {
                                     None
                                 },
                                 experience: if *player_id == killer_player_id {
-                                    Some(character.experience)
+                                    Some(character.get_packet_experience())
                                 } else {
                                     None
                                 },
@@ -364,7 +377,7 @@ impl Map {
                         &NpcAcceptServerPacket {
                             npc_killed_data: npc_killed_data.clone(),
                             experience: if *player_id == killer_player_id {
-                                Some(character.experience)
+                                Some(character.get_packet_experience())
                             } else {
                                 None
                             },
@@ -390,7 +403,7 @@ impl Map {
                     &NpcSpecServerPacket {
                         npc_killed_data: npc_killed_data.clone(),
                         experience: if *player_id == killer_player_id {
-                            Some(character.experience)
+                            Some(character.get_packet_experience())
                         } else {
                             None
                         },
@@ -499,7 +512,7 @@ impl Map {
                     PacketAction::Reply,
                     PacketFamily::Recover,
                     &RecoverReplyServerPacket {
-                        experience: character.experience,
+                        experience: character.get_packet_experience(),
                         karma: character.karma,
                         level_up: Some(character.level),
                         stat_points: Some(character.stat_points),
@@ -511,11 +524,11 @@ impl Map {
     }
 }
 
-fn get_drop(target_player_id: i32, npc_id: i32, npc_coords: &Coords) -> Option<Item> {
-    if let Some(drop_npc) = DROP_DB.npcs.iter().find(|d| d.npc_id == npc_id) {
+fn get_drop(owner: i32, npc_id: i32, npc_coords: &Coords) -> Option<Item> {
+    if let Some(drop_npc) = DROP_DB.load().npcs.iter().find(|d| d.npc_id == npc_id) {
         let mut rng = rand::thread_rng();
         let mut drops = drop_npc.drops.clone();
-        drops.sort_by(|a, b| a.rate.cmp(&b.rate));
+        drops.sort_by_key(|drop| drop.rate);
 
         for drop in drops {
             let roll = rng.gen_range(0..=64000);
@@ -525,8 +538,9 @@ fn get_drop(target_player_id: i32, npc_id: i32, npc_coords: &Coords) -> Option<I
                     id: drop.item_id,
                     amount,
                     coords: *npc_coords,
-                    owner: target_player_id,
+                    owner,
                     protected_ticks: SETTINGS.world.drop_protect_npc,
+                    dropped_at: Utc::now(),
                 });
             }
         }
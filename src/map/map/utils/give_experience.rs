@@ -6,13 +6,13 @@ impl Map {
     pub fn give_experience(&mut self, player_id: i32, experience: i32) -> (bool, i32, i32, i32) {
         match self.characters.get_mut(&player_id) {
             Some(character) => {
-                let experience = experience * SETTINGS.world.exp_multiplier;
+                let experience = experience as i64 * SETTINGS.world.exp_multiplier as i64;
                 let leveled_up = character.add_experience(experience);
                 (
                     leveled_up,
                     character.level,
-                    character.experience,
-                    experience,
+                    character.get_packet_experience(),
+                    experience.min(i32::MAX as i64) as i32,
                 )
             }
             None => (false, 0, 0, 0),
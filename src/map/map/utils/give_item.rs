@@ -2,12 +2,15 @@ use std::cmp;
 
 use eolib::protocol::net::{server::ItemGetServerPacket, PacketAction, PacketFamily, ThreeItem};
 
-use crate::SETTINGS;
+use crate::{
+    map::{ItemHolder, ItemTransfer},
+    SETTINGS,
+};
 
 use super::super::Map;
 
 impl Map {
-    pub fn give_item(&mut self, player_id: i32, item_id: i32, amount: i32) {
+    pub fn give_item(&mut self, player_id: i32, item_id: i32, amount: i32, source: ItemHolder) {
         if let Some(character) = self.characters.get_mut(&player_id) {
             let amount = cmp::min(
                 SETTINGS.limits.max_item - character.get_item_amount(item_id),
@@ -30,6 +33,17 @@ impl Map {
                     },
                 );
             }
+
+            let name = character.name.clone();
+            let coords = character.coords;
+
+            self.log_item_transfer(ItemTransfer {
+                item_id,
+                amount,
+                source,
+                destination: ItemHolder::Character(name),
+                coords,
+            });
         }
     }
 }
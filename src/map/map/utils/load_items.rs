@@ -0,0 +1,108 @@
+use chrono::{Duration, NaiveDateTime, Utc};
+use eolib::protocol::Coords;
+
+use crate::{
+    db::{params, Queryable, Row},
+    map::{chest::ChestItem, Item},
+    SETTINGS,
+};
+
+use super::super::Map;
+
+impl Map {
+    /// Restores the ground and chest items written by the last save
+    pub async fn load_items(&mut self) {
+        let mut conn = match self.pool.get_conn().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                error!("Failed to get connection from pool: {}", e);
+                return;
+            }
+        };
+
+        let items = match conn
+            .exec_map(
+                include_str!("../../../sql/get_map_items.sql"),
+                params! { "map_id" => self.id },
+                |mut row: Row| {
+                    let uid: i32 = row.take(0).unwrap();
+                    let dropped_at: NaiveDateTime = row.take(7).unwrap();
+                    (
+                        uid,
+                        Item {
+                            id: row.take(1).unwrap(),
+                            amount: row.take(2).unwrap(),
+                            coords: Coords {
+                                x: row.take(3).unwrap(),
+                                y: row.take(4).unwrap(),
+                            },
+                            owner: row.take(5).unwrap(),
+                            protected_ticks: row.take(6).unwrap(),
+                            dropped_at: dropped_at.and_utc(),
+                        },
+                    )
+                },
+            )
+            .await
+        {
+            Ok(items) => items,
+            Err(e) => {
+                error!("Failed to load items for map {}: {}", self.id, e);
+                return;
+            }
+        };
+
+        let chest_items = match conn
+            .exec_map(
+                include_str!("../../../sql/get_chest_items.sql"),
+                params! { "map_id" => self.id },
+                |mut row: Row| {
+                    (
+                        Coords {
+                            x: row.take(0).unwrap(),
+                            y: row.take(1).unwrap(),
+                        },
+                        ChestItem {
+                            slot: row.take(2).unwrap(),
+                            item_id: row.take(3).unwrap(),
+                            amount: row.take(4).unwrap(),
+                        },
+                    )
+                },
+            )
+            .await
+        {
+            Ok(chest_items) => chest_items,
+            Err(e) => {
+                error!("Failed to load chest items for map {}: {}", self.id, e);
+                return;
+            }
+        };
+
+        self.has_saved_items = !items.is_empty() || !chest_items.is_empty();
+
+        let expires_at = match SETTINGS.map.item_expiry {
+            0 => None,
+            minutes => Some(Utc::now() - Duration::try_minutes(minutes.into()).unwrap()),
+        };
+
+        for (uid, item) in items {
+            if expires_at.is_some_and(|expires_at| item.dropped_at < expires_at) {
+                continue;
+            }
+
+            if !self.is_in_bounds(item.coords) {
+                continue;
+            }
+
+            self.items.insert(uid, item);
+        }
+
+        // Chests that were removed from the map file lose their items
+        for (coords, item) in chest_items {
+            if let Some(chest) = self.chests.iter_mut().find(|chest| chest.coords == coords) {
+                chest.items.push(item);
+            }
+        }
+    }
+}
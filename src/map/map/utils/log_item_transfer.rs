@@ -0,0 +1,24 @@
+use chrono::Utc;
+
+use crate::{map::ItemTransfer, save_queue::LedgerEntry};
+
+use super::super::Map;
+
+impl Map {
+    pub fn log_item_transfer(&self, transfer: ItemTransfer) {
+        if transfer.amount <= 0 {
+            return;
+        }
+
+        self.world.save_queue.log_item_transfer(LedgerEntry {
+            item_id: transfer.item_id,
+            amount: transfer.amount,
+            source: transfer.source.to_string(),
+            destination: transfer.destination.to_string(),
+            map: self.id,
+            x: transfer.coords.x,
+            y: transfer.coords.y,
+            created_at: Utc::now().naive_utc(),
+        });
+    }
+}
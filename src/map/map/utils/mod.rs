@@ -6,6 +6,7 @@ mod get_character;
 mod get_nearby_info;
 mod get_next_item_index;
 mod get_rid_and_size;
+mod get_stats;
 mod get_tile;
 mod get_warp;
 mod give_experience;
@@ -14,10 +15,13 @@ mod is_in_bounds;
 mod is_tile_occupied;
 mod is_tile_walkable;
 mod is_tile_walkable_npc;
+mod load_items;
+mod log_item_transfer;
 mod npc_chat;
 mod player_chat;
 mod player_in_range_of_tile;
 mod reload;
+mod reload_spawns;
 mod save;
 mod send_packet_all;
 mod send_packet_near;
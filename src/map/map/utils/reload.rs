@@ -13,7 +13,7 @@ use eolib::{
     },
 };
 
-use crate::map::Door;
+use crate::{map::Door, SPAWNS};
 
 use super::{super::Map, create_chests};
 
@@ -21,6 +21,7 @@ impl Map {
     pub fn reload(&mut self, file: Box<Emf>, file_size: i32) {
         self.npcs_initialized = false;
         self.npcs.clear();
+        self.npc_spawns = SPAWNS.load().get_npc_spawns(self.id, &file.npcs);
 
         self.has_timed_spikes = file.tile_spec_rows.iter().any(|row| {
             row.tiles
@@ -50,7 +51,18 @@ impl Map {
             }
         }
 
-        self.chests = create_chests(self.id, &file);
+        // Chests that are still in the same place keep their contents
+        let mut chests = create_chests(self.id, &file);
+        for chest in chests.iter_mut() {
+            if let Some(old) = self
+                .chests
+                .iter_mut()
+                .find(|old| old.coords == chest.coords)
+            {
+                chest.items = std::mem::take(&mut old.items);
+            }
+        }
+        self.chests = chests;
         self.arena_ticks = 0;
         self.arena_players.clear();
         self.quake_ticks = 0;
@@ -71,12 +83,14 @@ impl Map {
             return;
         }
 
+        let file_bytes = writer.to_byte_array();
+
         let packet = InitInitServerPacket {
             reply_code: InitReply::MapMutation,
             reply_code_data: Some(InitInitServerPacketReplyCodeData::MapMutation(
                 InitInitServerPacketReplyCodeDataMapMutation {
                     map_file: MapFile {
-                        content: writer.to_byte_array().to_vec(),
+                        content: file_bytes.to_vec(),
                     },
                 },
             )),
@@ -93,6 +107,7 @@ impl Map {
 
         self.file = file;
         self.file_size = file_size;
+        self.file_bytes = Some(file_bytes);
 
         for character in self.characters.values() {
             let player = match character.player {
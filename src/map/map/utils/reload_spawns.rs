@@ -0,0 +1,21 @@
+use crate::SPAWNS;
+
+use super::super::Map;
+
+impl Map {
+    pub fn reload_spawns(&mut self) {
+        let npc_spawns = SPAWNS.load().get_npc_spawns(self.id, &self.file.npcs);
+        if npc_spawns == self.npc_spawns {
+            return;
+        }
+
+        self.npc_spawns = npc_spawns;
+        self.npcs_initialized = false;
+        self.npcs.clear();
+
+        // Clients drop the old npcs when the refresh doesn't include them
+        for player_id in self.characters.keys() {
+            self.request_refresh(*player_id);
+        }
+    }
+}
@@ -1,9 +1,24 @@
 use tokio::sync::oneshot;
 
+use crate::db::{params, Conn, Queryable};
+
 use super::super::Map;
 
 impl Map {
     pub async fn save(&mut self, respond_to: oneshot::Sender<()>) {
+        let now = chrono::Utc::now();
+
+        for character in self.characters.values_mut() {
+            if let Some(logged_in_at) = character.logged_in_at {
+                character.usage += (now.timestamp() - logged_in_at.timestamp()) as i32 / 60;
+            }
+
+            // The queue keeps the saved state from here on
+            let mut snapshot = Box::new(character.clone());
+            snapshot.saved_state = character.saved_state.take();
+            self.world.save_queue.save(snapshot, false);
+        }
+
         let mut conn = match self.pool.get_conn().await {
             Ok(conn) => conn,
             Err(e) => {
@@ -13,19 +28,79 @@ impl Map {
             }
         };
 
-        let now = chrono::Utc::now();
+        if let Err(e) = self.save_items(&mut conn).await {
+            error!("Failed to save items for map {}: {}", self.id, e);
+        }
 
-        for character in self.characters.values_mut() {
-            if let Some(logged_in_at) = character.logged_in_at {
-                character.usage += (now.timestamp() - logged_in_at.timestamp()) as i32 / 60;
-            }
+        let _ = respond_to.send(());
+    }
+
+    async fn save_items(
+        &mut self,
+        conn: &mut Conn,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let has_items =
+            !self.items.is_empty() || self.chests.iter().any(|chest| !chest.items.is_empty());
+
+        // Most maps never have anything on them so skip the round trip
+        if !has_items && !self.has_saved_items {
+            return Ok(());
+        }
+
+        let mut tx = conn.start_transaction().await?;
 
-            if let Err(e) = character.save(&mut conn).await {
-                error!("Failed to update character: {}", e);
-                continue;
+        tx.exec_drop(
+            include_str!("../../../sql/delete_map_items.sql"),
+            params! { "map_id" => self.id },
+        )
+        .await?;
+
+        tx.exec_drop(
+            include_str!("../../../sql/delete_chest_items.sql"),
+            params! { "map_id" => self.id },
+        )
+        .await?;
+
+        for (uid, item) in &self.items {
+            tx.exec_drop(
+                include_str!("../../../sql/create_map_item.sql"),
+                params! {
+                    "map_id" => self.id,
+                    "uid" => uid,
+                    "item_id" => item.id,
+                    "amount" => item.amount,
+                    "x" => item.coords.x,
+                    "y" => item.coords.y,
+                    "owner" => item.owner,
+                    "protected_ticks" => item.protected_ticks,
+                    "dropped_at" => item.dropped_at.naive_utc(),
+                },
+            )
+            .await?;
+        }
+
+        for chest in &self.chests {
+            for (index, item) in chest.items.iter().enumerate() {
+                tx.exec_drop(
+                    include_str!("../../../sql/create_chest_item.sql"),
+                    params! {
+                        "map_id" => self.id,
+                        "x" => chest.coords.x,
+                        "y" => chest.coords.y,
+                        "index" => index as i32,
+                        "slot" => item.slot,
+                        "item_id" => item.item_id,
+                        "amount" => item.amount,
+                    },
+                )
+                .await?;
             }
         }
 
-        let _ = respond_to.send(());
+        tx.commit().await?;
+
+        self.has_saved_items = has_items;
+
+        Ok(())
     }
 }
@@ -5,7 +5,14 @@ use tokio::sync::oneshot;
 use super::super::Map;
 
 impl Map {
-    pub fn serialize(&self, respond_to: oneshot::Sender<Bytes>) {
+    /// The file is serialized on first request and kept until the map is
+    /// reloaded
+    pub fn serialize(&mut self, respond_to: oneshot::Sender<Bytes>) {
+        if let Some(ref file_bytes) = self.file_bytes {
+            let _ = respond_to.send(file_bytes.clone());
+            return;
+        }
+
         let mut writer = EoWriter::with_capacity(self.file_size as usize);
 
         if let Err(e) = self.file.serialize(&mut writer) {
@@ -13,6 +20,8 @@ impl Map {
             return;
         }
 
-        let _ = respond_to.send(writer.to_byte_array());
+        let file_bytes = writer.to_byte_array();
+        self.file_bytes = Some(file_bytes.clone());
+        let _ = respond_to.send(file_bytes);
     }
 }
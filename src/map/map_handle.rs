@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use chrono::{DateTime, Utc};
 use eolib::protocol::{
     map::Emf,
     net::{
@@ -8,7 +9,6 @@ use eolib::protocol::{
     },
     Coords, Direction, Emote,
 };
-use mysql_async::Pool;
 use tokio::sync::{
     mpsc::{self, UnboundedSender},
     oneshot,
@@ -16,11 +16,12 @@ use tokio::sync::{
 
 use crate::{
     character::{Character, SpellTarget},
+    db::Pool,
     player::PartyRequest,
     world::WorldHandle,
 };
 
-use super::{Command, Map};
+use super::{Command, ItemHolder, Map, MapStats};
 
 #[derive(Debug, Clone)]
 pub struct MapHandle {
@@ -298,6 +299,12 @@ impl MapHandle {
         rx.await.unwrap()
     }
 
+    pub async fn get_stats(&self) -> MapStats {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetStats { respond_to: tx });
+        rx.await.unwrap()
+    }
+
     pub async fn get_player_count(&self, filter: fn(&Character) -> bool) -> usize {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::GetPlayerCount {
@@ -311,11 +318,12 @@ impl MapHandle {
         let _ = self.tx.send(Command::AwardExperience { player_id, amount });
     }
 
-    pub fn give_item(&self, player_id: i32, item_id: i32, amount: i32) {
+    pub fn give_item(&self, player_id: i32, item_id: i32, amount: i32, source: ItemHolder) {
         let _ = self.tx.send(Command::GiveItem {
             player_id,
             item_id,
             amount,
+            source,
         });
     }
 
@@ -331,6 +339,10 @@ impl MapHandle {
         let _ = self.tx.send(Command::Reload { file, file_size });
     }
 
+    pub fn reload_spawns(&self) {
+        let _ = self.tx.send(Command::ReloadSpawns);
+    }
+
     pub fn lose_item(&self, player_id: i32, item_id: i32, amount: i32) {
         let _ = self.tx.send(Command::LoseItem {
             player_id,
@@ -407,6 +419,13 @@ impl MapHandle {
         });
     }
 
+    pub fn mute(&self, player_id: i32, muted_until: DateTime<Utc>) {
+        let _ = self.tx.send(Command::Mute {
+            player_id,
+            muted_until,
+        });
+    }
+
     pub fn open_bank(&self, player_id: i32, npc_index: i32, session_id: i32) {
         let _ = self.tx.send(Command::OpenBank {
             player_id,
@@ -681,6 +700,14 @@ impl MapHandle {
         });
     }
 
+    pub fn show_info_box(&self, player_id: i32, title: String, lines: Vec<String>) {
+        let _ = self.tx.send(Command::ShowInfoBox {
+            player_id,
+            title,
+            lines,
+        });
+    }
+
     pub fn sit(&self, player_id: i32) {
         let _ = self.tx.send(Command::Sit { player_id });
     }
@@ -952,6 +979,8 @@ impl MapHandle {
 }
 
 async fn run_map(mut map: Map) {
+    map.load_items().await;
+
     loop {
         if let Some(command) = map.rx.recv().await {
             map.handle_command(command).await;
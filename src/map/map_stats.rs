@@ -0,0 +1,8 @@
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct MapStats {
+    pub id: i32,
+    pub name: String,
+    pub characters: usize,
+    pub npcs: usize,
+    pub items: usize,
+}
@@ -6,6 +6,8 @@ mod door;
 pub use door::Door;
 mod item;
 pub use item::Item;
+mod item_transfer;
+pub use item_transfer::{ItemHolder, ItemTransfer};
 #[allow(clippy::module_inception)]
 mod map;
 pub use map::Map;
@@ -13,5 +15,7 @@ mod npc;
 pub use npc::{NPCBuilder, Npc};
 mod map_handle;
 pub use map_handle::MapHandle;
+mod map_stats;
+pub use map_stats::MapStats;
 mod wedding;
 pub use wedding::{Wedding, WeddingState};
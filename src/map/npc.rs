@@ -26,7 +26,6 @@ pub struct Npc {
     pub boss: bool,
     pub child: bool,
     pub polymorphed: bool,
-    
 }
 
 #[derive(Debug, Default, Clone)]
@@ -52,7 +51,8 @@ impl Npc {
     }
 
     pub fn damage(&mut self, player_id: i32, amount: i32, accuracy: i32, critical: bool) -> i32 {
-        let npc_data = match NPC_DB.npcs.get(self.id as usize - 1) {
+        let npc_db = NPC_DB.load();
+        let npc_data = match npc_db.npcs.get(self.id as usize - 1) {
             Some(npc_data) => npc_data,
             None => {
                 return 0;
@@ -74,7 +74,7 @@ impl Npc {
             }
         };
 
-        let hit_rate = match eval_float_with_context(&FORMULAS.hit_rate, &context) {
+        let hit_rate = match eval_float_with_context(&FORMULAS.load().hit_rate, &context) {
             Ok(hit_rate) => hit_rate,
             Err(e) => {
                 error!("Failed to calculate hit rate: {}", e);
@@ -88,7 +88,7 @@ impl Npc {
         let damage = if hit_rate < rand {
             0
         } else {
-            match eval_float_with_context(&FORMULAS.damage, &context) {
+            match eval_float_with_context(&FORMULAS.load().damage, &context) {
                 Ok(amount) => amount.floor() as i32,
                 Err(e) => {
                     error!("Failed to calculate damage: {}", e);
@@ -231,7 +231,7 @@ impl NPCBuilder {
             opponents: Vec::new(),
             boss: self.boss,
             child: self.child,
-            polymorphed: self.polymorphed
+            polymorphed: self.polymorphed,
         }
     }
 }
@@ -0,0 +1,175 @@
+use axum::{http::StatusCode, routing::get, Router};
+use eolib::protocol::net::{PacketAction, PacketFamily};
+use lazy_static::lazy_static;
+use prometheus::{
+    core::Collector, register_histogram, register_int_counter_vec, register_int_gauge,
+    register_int_gauge_vec, Histogram, IntCounterVec, IntGauge, IntGaugeVec, TextEncoder,
+};
+use std::collections::HashSet;
+use tokio::net::TcpListener;
+
+use crate::{map::MapStats, SETTINGS};
+
+lazy_static! {
+    pub static ref CONNECTIONS: IntGauge =
+        register_int_gauge!("reoserv_connections", "Number of open connections").unwrap();
+    pub static ref PLAYERS_IN_GAME: IntGauge =
+        register_int_gauge!("reoserv_players_in_game", "Number of characters in game").unwrap();
+    pub static ref MAP_CHARACTERS: IntGaugeVec = register_int_gauge_vec!(
+        "reoserv_map_characters",
+        "Number of characters on a map",
+        &["map"]
+    )
+    .unwrap();
+    pub static ref MAP_NPCS: IntGaugeVec = register_int_gauge_vec!(
+        "reoserv_map_npcs",
+        "Number of alive NPCs on a map",
+        &["map"]
+    )
+    .unwrap();
+    pub static ref MAP_ITEMS: IntGaugeVec = register_int_gauge_vec!(
+        "reoserv_map_items",
+        "Number of items on the ground of a map",
+        &["map"]
+    )
+    .unwrap();
+    static ref PACKETS_RECEIVED: IntCounterVec = register_int_counter_vec!(
+        "reoserv_packets_received_total",
+        "Number of packets received from clients",
+        &["family", "action"]
+    )
+    .unwrap();
+    static ref PACKETS_RATE_LIMITED: IntCounterVec = register_int_counter_vec!(
+        "reoserv_packets_rate_limited_total",
+        "Number of packets dropped for exceeding their rate limit",
+        &["family", "action"]
+    )
+    .unwrap();
+    pub static ref SAVE_DURATION: Histogram = register_histogram!(
+        "reoserv_save_duration_seconds",
+        "Time taken to save the world to the database"
+    )
+    .unwrap();
+    pub static ref SAVE_QUEUE_WRITE_DURATION: Histogram = register_histogram!(
+        "reoserv_save_queue_write_duration_seconds",
+        "Time taken to write pending character saves to the database"
+    )
+    .unwrap();
+    pub static ref SAVE_QUEUE_PENDING: IntGauge = register_int_gauge!(
+        "reoserv_save_queue_pending",
+        "Number of character saves waiting to be written to the database"
+    )
+    .unwrap();
+    pub static ref CHARACTER_SAVES: IntCounterVec = register_int_counter_vec!(
+        "reoserv_character_saves_total",
+        "Number of character saves, by whether anything had changed",
+        &["result"]
+    )
+    .unwrap();
+    pub static ref CHARACTER_SAVE_ROWS: IntCounterVec = register_int_counter_vec!(
+        "reoserv_character_save_rows_total",
+        "Number of rows written by character saves",
+        &["table", "operation"]
+    )
+    .unwrap();
+    pub static ref CHARACTER_SAVE_DURATION: Histogram = register_histogram!(
+        "reoserv_character_save_duration_seconds",
+        "Time taken to write a changed character to the database",
+        vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0]
+    )
+    .unwrap();
+    pub static ref TICK_DURATION: Histogram = register_histogram!(
+        "reoserv_tick_duration_seconds",
+        "Time taken to process a world tick",
+        vec![0.0001, 0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25]
+    )
+    .unwrap();
+}
+
+pub fn packet_received(buf: &[u8]) {
+    let (family, action) = packet_labels(buf);
+    PACKETS_RECEIVED
+        .with_label_values(&[&family, &action])
+        .inc();
+}
+
+pub fn packet_rate_limited(buf: &[u8]) {
+    let (family, action) = packet_labels(buf);
+    PACKETS_RATE_LIMITED
+        .with_label_values(&[&family, &action])
+        .inc();
+}
+
+fn packet_labels(buf: &[u8]) -> (String, String) {
+    (
+        format!("{:?}", PacketFamily::from(buf[1])),
+        format!("{:?}", PacketAction::from(buf[0])),
+    )
+}
+
+pub async fn serve() {
+    let listener = match TcpListener::bind(format!(
+        "{}:{}",
+        SETTINGS.metrics.host, SETTINGS.metrics.port
+    ))
+    .await
+    {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("Failed to bind metrics listener: {}", e);
+            return;
+        }
+    };
+
+    info!(
+        "serving metrics at {}:{}",
+        SETTINGS.metrics.host, SETTINGS.metrics.port
+    );
+
+    let app = Router::new().route("/metrics", get(metrics));
+
+    if let Err(e) = axum::serve(listener, app).await {
+        error!("Metrics listener stopped: {}", e);
+    }
+}
+
+/// Sets the per-map gauges, then removes the labels of maps that no longer exist
+pub fn set_map_stats(map_stats: &[MapStats]) {
+    let labels: HashSet<String> = map_stats.iter().map(|stats| stats.id.to_string()).collect();
+
+    for stats in map_stats {
+        let label = stats.id.to_string();
+        MAP_CHARACTERS
+            .with_label_values(&[&label])
+            .set(stats.characters as i64);
+        MAP_NPCS.with_label_values(&[&label]).set(stats.npcs as i64);
+        MAP_ITEMS
+            .with_label_values(&[&label])
+            .set(stats.items as i64);
+    }
+
+    for gauge in [&*MAP_CHARACTERS, &*MAP_NPCS, &*MAP_ITEMS] {
+        let stale: Vec<String> = gauge
+            .collect()
+            .iter()
+            .flat_map(|family| family.get_metric())
+            .flat_map(|metric| metric.get_label())
+            .map(|label| label.get_value().to_owned())
+            .filter(|label| !labels.contains(label))
+            .collect();
+
+        for label in stale {
+            let _ = gauge.remove_label_values(&[&label]);
+        }
+    }
+}
+
+async fn metrics() -> Result<String, StatusCode> {
+    match TextEncoder::new().encode_to_string(&prometheus::gather()) {
+        Ok(metrics) => Ok(metrics),
+        Err(e) => {
+            error!("Failed to encode metrics: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    db::{params, Conn, Pool, Query, Queryable},
+    settings::DatabaseDriver,
+};
+
+struct Migration {
+    version: i32,
+    name: &'static str,
+    sql: Query<'static>,
+}
+
+macro_rules! migration {
+    ($version:expr, $name:expr) => {
+        Migration {
+            version: $version,
+            name: $name,
+            sql: Query::new(
+                include_str!(concat!("sql/migrations/", $name, ".sql")),
+                include_str!(concat!("sql/migrations/sqlite/", $name, ".sql")),
+            ),
+        }
+    };
+}
+
+// Append new migrations to the end of this list, with an SQLite version of
+// each in `sql/migrations/sqlite`. Never edit or reorder a migration once it
+// has been released.
+const MIGRATIONS: &[Migration] = &[
+    migration!(1, "0001_init"),
+    migration!(2, "0002_nullable_ban_created_by"),
+    migration!(3, "0003_admin_log"),
+    migration!(4, "0004_character_muted_until"),
+    migration!(5, "0005_ban_hdid_ipv6"),
+    migration!(6, "0006_drop_get_guild_details"),
+    migration!(7, "0007_map_items"),
+    migration!(8, "0008_item_ledger"),
+    migration!(9, "0009_economy_snapshot"),
+    migration!(10, "0010_stats_experience_bigint"),
+    migration!(11, "0011_ban_scope"),
+];
+
+const LOCK_NAME: &str = "reoserv_migrations";
+const LOCK_TIMEOUT: i32 = 60;
+
+pub async fn run_migrations(pool: &Pool) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let mut conn = pool.get_conn().await?;
+
+    // SQLite databases are a single local file so only MySQL needs locking
+    if pool.driver() == DatabaseDriver::Sqlite {
+        return apply_migrations(&mut conn).await;
+    }
+
+    // Prevent two servers sharing a database from migrating at the same time
+    let locked: Option<i32> = conn
+        .exec_first(
+            "SELECT GET_LOCK(:name, :timeout)",
+            params! {
+                "name" => LOCK_NAME,
+                "timeout" => LOCK_TIMEOUT,
+            },
+        )
+        .await?
+        .flatten();
+
+    if locked != Some(1) {
+        return Err("Timed out waiting for the migration lock".into());
+    }
+
+    let result = apply_migrations(&mut conn).await;
+
+    conn.exec_drop(
+        "SELECT RELEASE_LOCK(:name)",
+        params! { "name" => LOCK_NAME },
+    )
+    .await?;
+
+    result
+}
+
+async fn apply_migrations(conn: &mut Conn) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    conn.query_drop(include_str!("sql/create_schema_version.sql"))
+        .await?;
+
+    let current_version: i32 = conn
+        .query_first("SELECT IFNULL(MAX(`version`), 0) FROM `SchemaVersion`")
+        .await?
+        .unwrap_or(0);
+
+    let latest_version = MIGRATIONS.last().map(|m| m.version).unwrap_or(0);
+
+    if current_version > latest_version {
+        return Err(format!(
+            "Database schema version {} is newer than this server supports ({}). Refusing to start.",
+            current_version, latest_version
+        )
+        .into());
+    }
+
+    if current_version == latest_version {
+        info!(
+            "Database schema is up to date (version {})",
+            current_version
+        );
+        return Ok(());
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current_version) {
+        info!("Applying migration {}", migration.name);
+
+        if let Err(e) = conn.query_drop(migration.sql).await {
+            return Err(format!("Migration {} failed: {}", migration.name, e).into());
+        }
+
+        conn.exec_drop(
+            include_str!("sql/create_schema_version_entry.sql"),
+            params! {
+                "version" => migration.version,
+                "name" => migration.name,
+            },
+        )
+        .await?;
+    }
+
+    info!("Database schema upgraded to version {}", latest_version);
+
+    Ok(())
+}
@@ -4,6 +4,8 @@ pub enum ClientState {
     Uninitialized,
     Initialized,
     Accepted,
+    /// Logged in but waiting in the login queue for a free slot
+    Queued,
     LoggedIn,
     EnteringGame,
     InGame,
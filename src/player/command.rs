@@ -19,6 +19,7 @@ pub enum Command {
         player_id: i32,
         name: String,
     },
+    AdmitLogin,
     CancelTrade,
     Close(String),
     ArenaDie {
@@ -28,6 +29,9 @@ pub enum Command {
     GenerateSessionId {
         respond_to: oneshot::Sender<i32>,
     },
+    GetAccountId {
+        respond_to: oneshot::Sender<i32>,
+    },
     GetCharacter {
         respond_to: oneshot::Sender<Result<Box<Character>, InvalidStateError>>,
     },
@@ -60,6 +64,7 @@ pub enum Command {
         animation: Option<WarpEffect>,
     },
     SendGuildReply(GuildReply),
+    SendPubFiles,
     SendServerMessage(String),
     Send(PacketAction, PacketFamily, Bytes),
     SetBoardId(i32),
@@ -11,13 +11,13 @@ use futures::{SinkExt, StreamExt};
 use tokio::net::TcpStream;
 use tokio_tungstenite::{tungstenite::Message, WebSocketStream};
 
-use crate::PACKET_RATE_LIMITS;
+use crate::{metrics, PACKET_RATE_LIMITS};
 
 use super::PacketLog;
 
 pub enum Socket {
     Standard(TcpStream),
-    Web(WebSocketStream<TcpStream>),
+    Web(Box<WebSocketStream<TcpStream>>),
 }
 
 pub struct PacketBus {
@@ -126,6 +126,8 @@ impl PacketBus {
 
                     let data_buf = Bytes::from(data_buf);
 
+                    metrics::packet_received(&data_buf);
+
                     if let Some(rate_limit) = PACKET_RATE_LIMITS.packets.iter().find(|l| {
                         l.action == PacketAction::from(data_buf[0])
                             && l.family == PacketFamily::from(data_buf[1])
@@ -136,6 +138,8 @@ impl PacketBus {
                                 .num_milliseconds()
                                 < rate_limit.limit
                             {
+                                metrics::packet_rate_limited(&data_buf);
+
                                 let mut buf = BytesMut::new();
                                 buf.put_u8(0xfe);
                                 buf.put_u8(0xfe);
@@ -174,6 +178,8 @@ impl PacketBus {
 
                                     let data_buf = Bytes::from(data_buf);
 
+                                    metrics::packet_received(&data_buf);
+
                                     if let Some(rate_limit) =
                                         PACKET_RATE_LIMITS.packets.iter().find(|l| {
                                             l.action == PacketAction::from(data_buf[0])
@@ -188,6 +194,8 @@ impl PacketBus {
                                                 .num_milliseconds()
                                                 < rate_limit.limit
                                             {
+                                                metrics::packet_rate_limited(&data_buf);
+
                                                 let mut buf = BytesMut::new();
                                                 buf.put_u8(0xfe);
                                                 buf.put_u8(0xfe);
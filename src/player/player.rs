@@ -3,10 +3,11 @@ use std::{cell::RefCell, collections::VecDeque};
 use bytes::Bytes;
 use chrono::{DateTime, Utc};
 use eolib::protocol::net::{server::GuildReplyServerPacket, PacketAction, PacketFamily, Version};
-use mysql_async::Pool;
 use tokio::sync::mpsc::UnboundedReceiver;
 
-use crate::{character::Character, errors::InvalidStateError, map::MapHandle, world::WorldHandle};
+use crate::{
+    character::Character, db::Pool, errors::InvalidStateError, map::MapHandle, world::WorldHandle,
+};
 
 use super::{
     packet_bus::PacketBus, Captcha, ClientState, Command, PartyRequest, Socket, WarpSession,
@@ -23,6 +24,7 @@ pub struct Player {
     pool: Pool,
     pub state: ClientState,
     ip: String,
+    hdid: Option<u32>,
     pub connected_at: DateTime<Utc>,
     pub closed: bool,
     login_attempts: i32,
@@ -46,7 +48,9 @@ pub struct Player {
     spell_id: Option<i32>,
 }
 
+mod accept_login;
 mod account;
+mod admit_login;
 mod arena_die;
 mod cancel_trade;
 mod close;
@@ -63,6 +67,7 @@ mod generate_email_pin;
 mod ping;
 mod quest_action;
 mod request_warp;
+mod send_pub_files;
 mod send_server_message;
 mod show_captcha;
 mod take_session_id;
@@ -94,6 +99,7 @@ impl Player {
             state: ClientState::Uninitialized,
             login_attempts: 0,
             ip,
+            hdid: None,
             character: None,
             warp_session: None,
             session_id: None,
@@ -120,6 +126,7 @@ impl Player {
             Command::AddGuildCreationPlayer { player_id, name } => {
                 self.add_guild_creation_player(player_id, name).await
             }
+            Command::AdmitLogin => self.admit_login().await,
             Command::ArenaDie { spawn_coords } => self.arena_die(spawn_coords).await,
             Command::CancelTrade => self.cancel_trade().await,
             Command::Close(reason) => self.close(reason).await,
@@ -147,6 +154,9 @@ impl Player {
                         .send(Err(InvalidStateError::new(ClientState::InGame, self.state)));
                 }
             }
+            Command::GetAccountId { respond_to } => {
+                let _ = respond_to.send(self.account_id);
+            }
             Command::GetPlayerId { respond_to } => {
                 let _ = respond_to.send(self.id);
             }
@@ -182,6 +192,7 @@ impl Player {
                     )
                     .await;
             }
+            Command::SendPubFiles => self.send_pub_files().await,
             Command::SendServerMessage(message) => self.send_server_message(&message).await,
             Command::Send(action, family, data) => {
                 let _ = self.bus.send_buf(action, family, data).await;
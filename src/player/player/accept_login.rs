@@ -0,0 +1,51 @@
+use eolib::protocol::net::{
+    server::{
+        CharacterSelectionListEntry, LoginReply, LoginReplyServerPacket,
+        LoginReplyServerPacketReplyCodeData, LoginReplyServerPacketReplyCodeDataOk,
+    },
+    PacketAction, PacketFamily,
+};
+
+use crate::{
+    deep::{LoginConfigServerPacket, ACTION_CONFIG},
+    player::ClientState,
+    utils::is_deep,
+    SETTINGS,
+};
+
+use super::Player;
+
+impl Player {
+    pub async fn accept_login(&mut self, characters: Vec<CharacterSelectionListEntry>) {
+        self.state = ClientState::LoggedIn;
+
+        if is_deep(&self.version) {
+            let _ = self
+                .bus
+                .send(
+                    PacketAction::Unrecognized(ACTION_CONFIG),
+                    PacketFamily::Login,
+                    LoginConfigServerPacket {
+                        max_skins: SETTINGS.character.max_skin + 1,
+                        max_hair_modals: SETTINGS.character.max_hair_style,
+                        max_character_name: SETTINGS.character.max_name_length as i32,
+                    },
+                )
+                .await;
+        }
+
+        let _ = self
+            .bus
+            .send(
+                PacketAction::Reply,
+                PacketFamily::Login,
+                LoginReplyServerPacket {
+                    reply_code: LoginReply::OK,
+                    reply_code_data: Some(LoginReplyServerPacketReplyCodeData::OK(
+                        LoginReplyServerPacketReplyCodeDataOk { characters },
+                    )),
+                },
+            )
+            .await;
+    }
+}
@@ -1,17 +1,22 @@
 use std::time::Duration;
 
+use crate::db::{params, Conn, Params, Queryable, Row};
+
 use chrono::{NaiveDateTime, Utc};
-use mysql_async::{params, prelude::Queryable, Conn, Params, Row};
 
 pub async fn account_banned(
     conn: &mut Conn,
     name: &str,
+    ip: &str,
+    hdid: Option<u32>,
 ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
     let mut row: Row = match conn
-        .exec_first::<Row, &str, Params>(
-            include_str!("../../../sql/get_account_ban_duration.sql"),
+        .exec_first::<Row, _, Params>(
+            query!("get_account_ban_duration"),
             params! {
                 "name" => name,
+                "ip" => ip,
+                "hdid" => hdid,
             },
         )
         .await?
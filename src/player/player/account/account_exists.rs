@@ -1,4 +1,4 @@
-use mysql_async::{params, prelude::Queryable, Conn, Params, Row};
+use crate::db::{params, Conn, Params, Queryable, Row};
 
 pub async fn account_exists(
     conn: &mut Conn,
@@ -2,9 +2,11 @@ use eolib::protocol::{
     net::server::{CharacterSelectionListEntry, EquipmentCharacterSelect},
     AdminLevel, Gender,
 };
-use mysql_async::{prelude::*, Conn, Row};
 
-use crate::ITEM_DB;
+use crate::{
+    db::{params, Conn, Queryable, Row},
+    ITEM_DB,
+};
 
 pub async fn get_character_list(
     conn: &mut Conn,
@@ -35,35 +37,35 @@ pub async fn get_character_list(
                     equipment: EquipmentCharacterSelect {
                         boots: match boots {
                             0 => 0,
-                            _ => match ITEM_DB.items.get(boots as usize - 1) {
+                            _ => match ITEM_DB.load().items.get(boots as usize - 1) {
                                 Some(item) => item.spec1,
                                 None => 0,
                             },
                         },
                         armor: match armor {
                             0 => 0,
-                            _ => match ITEM_DB.items.get(armor as usize - 1) {
+                            _ => match ITEM_DB.load().items.get(armor as usize - 1) {
                                 Some(item) => item.spec1,
                                 None => 0,
                             },
                         },
                         hat: match hat {
                             0 => 0,
-                            _ => match ITEM_DB.items.get(hat as usize - 1) {
+                            _ => match ITEM_DB.load().items.get(hat as usize - 1) {
                                 Some(item) => item.spec1,
                                 None => 0,
                             },
                         },
                         shield: match shield {
                             0 => 0,
-                            _ => match ITEM_DB.items.get(shield as usize - 1) {
+                            _ => match ITEM_DB.load().items.get(shield as usize - 1) {
                                 Some(item) => item.spec1,
                                 None => 0,
                             },
                         },
                         weapon: match weapon {
                             0 => 0,
-                            _ => match ITEM_DB.items.get(weapon as usize - 1) {
+                            _ => match ITEM_DB.load().items.get(weapon as usize - 1) {
                                 Some(item) => item.spec1,
                                 None => 0,
                             },
@@ -1,4 +1,4 @@
-use mysql_async::{prelude::*, Conn, Params, Row};
+use crate::db::{params, Conn, Params, Queryable, Row};
 
 pub async fn get_num_of_characters(
     conn: &mut Conn,
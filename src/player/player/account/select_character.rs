@@ -39,6 +39,17 @@ impl Player {
             return true;
         }
 
+        // The database is stale until any save left from the last session
+        // has been written
+        if !self.world.save_queue.flush_character(character_id).await {
+            self.close(format!(
+                "Character {} still has a pending save",
+                character_id
+            ))
+            .await;
+            return false;
+        }
+
         let mut conn = match self.pool.get_conn().await {
             Ok(conn) => conn,
             Err(e) => {
@@ -1,4 +1,4 @@
-use mysql_async::{prelude::*, Conn};
+use crate::db::{params, Conn, Queryable};
 
 pub async fn update_last_login_ip(
     conn: &mut Conn,
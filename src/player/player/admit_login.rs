@@ -0,0 +1,31 @@
+use crate::player::ClientState;
+
+use super::{account::get_character_list, Player};
+
+impl Player {
+    pub async fn admit_login(&mut self) {
+        if self.state != ClientState::Queued || self.account_id == 0 {
+            return;
+        }
+
+        let mut conn = match self.pool.get_conn().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                self.close(format!("Error getting connection from pool: {}", e))
+                    .await;
+                return;
+            }
+        };
+
+        let characters = match get_character_list(&mut conn, self.account_id).await {
+            Ok(characters) => characters,
+            Err(e) => {
+                self.close(format!("Error getting character list: {}", e))
+                    .await;
+                return;
+            }
+        };
+
+        self.accept_login(characters).await;
+    }
+}
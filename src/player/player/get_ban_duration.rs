@@ -1,8 +1,8 @@
 use std::time::Duration;
 
 use chrono::{NaiveDateTime, Utc};
-use mysql_async::prelude::*;
-use mysql_common::{params, Row};
+
+use crate::db::{params, Queryable, Row};
 
 use super::Player;
 
@@ -18,9 +18,10 @@ impl Player {
 
         let mut row: Row = match conn
             .exec_first(
-                include_str!("../../sql/get_ban_duration.sql"),
+                query!("get_ban_duration"),
                 params! {
                     "ip" => &self.ip,
+                    "hdid" => self.hdid,
                 },
             )
             .await
@@ -5,9 +5,7 @@ use eolib::protocol::{
     Coords,
 };
 
-use crate::{
-    character::Character, utils::pad_string, CLASS_DB, ITEM_DB, NPC_DB, SETTINGS, SPELL_DB,
-};
+use crate::{character::Character, utils::pad_string, PUB_FILES, SETTINGS};
 
 use super::Player;
 
@@ -24,13 +22,17 @@ impl Player {
             map.get_rid_and_size().await
         };
 
-        let (eif_rid, eif_length) = { (ITEM_DB.rid, ITEM_DB.items.len() as i32) };
+        let eif_rid = PUB_FILES.load().eif.rid;
+        let eif_length = PUB_FILES.load().eif.length;
 
-        let (ecf_rid, ecf_length) = { (CLASS_DB.rid, CLASS_DB.classes.len() as i32) };
+        let ecf_rid = PUB_FILES.load().ecf.rid;
+        let ecf_length = PUB_FILES.load().ecf.length;
 
-        let (enf_rid, enf_length) = { (NPC_DB.rid, NPC_DB.npcs.len() as i32) };
+        let enf_rid = PUB_FILES.load().enf.rid;
+        let enf_length = PUB_FILES.load().enf.length;
 
-        let (esf_rid, esf_length) = { (SPELL_DB.rid, SPELL_DB.skills.len() as i32) };
+        let esf_rid = PUB_FILES.load().esf.rid;
+        let esf_length = PUB_FILES.load().esf.length;
 
         let settings = ServerSettings {
             jail_map: SETTINGS.jail.map,
@@ -66,7 +68,7 @@ impl Player {
             guild_tag: pad_string(&character.guild_tag.clone().unwrap_or_default(), 3),
             admin: character.admin_level,
             level: character.level,
-            experience: character.experience,
+            experience: character.get_packet_experience(),
             usage: character.usage,
             stats: character.get_character_stats_welcome(),
             equipment: character.get_equipment_welcome(),
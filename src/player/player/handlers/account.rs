@@ -13,9 +13,9 @@ use eolib::{
         PacketAction, PacketFamily,
     },
 };
-use mysql_async::{params, prelude::Queryable, Params, Row};
 
 use crate::{
+    db::{params, Params, Queryable, Row},
     deep::{
         AccountAcceptClientPacket, AccountAcceptServerPacket, AccountConfigServerPacket,
         ACCOUNT_REPLY_WRONG_PIN, ACTION_CONFIG,
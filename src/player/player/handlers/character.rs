@@ -16,10 +16,10 @@ use eolib::{
         PacketAction, PacketFamily,
     },
 };
-use mysql_async::{params, prelude::Queryable, Conn, Params, Row};
 
 use crate::{
     character::Character,
+    db::{params, Conn, Params, Queryable, Row},
     errors::WrongSessionIdError,
     player::{
         player::account::{get_character_list, get_num_of_characters},
@@ -182,6 +182,21 @@ impl Player {
             return;
         }
 
+        // Deleting with a save still queued would leave it failing forever
+        if !self
+            .world
+            .save_queue
+            .flush_character(remove.character_id)
+            .await
+        {
+            self.close(format!(
+                "Character {} still has a pending save",
+                remove.character_id
+            ))
+            .await;
+            return;
+        }
+
         let character = match Character::load(&mut conn, remove.character_id).await {
             Ok(character) => character,
             Err(_) => {
@@ -21,10 +21,9 @@ use eolib::{
         r#pub::NpcType,
     },
 };
-use futures::StreamExt;
-use mysql_async::{params, prelude::Queryable, Conn, Params, Row};
 
 use crate::{
+    db::{params, Conn, Error, Params, Queryable, Row},
     player::{
         player::guild::{
             guild_exists, validate_guild_description, validate_guild_name, validate_guild_rank,
@@ -108,7 +107,8 @@ impl Player {
                 None => return,
             };
 
-            let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+            let npc_db = NPC_DB.load();
+            let npc_data = match npc_db.npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => npc_data,
                 None => return,
             };
@@ -506,7 +506,8 @@ impl Player {
                 None => return,
             };
 
-            let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+            let npc_db = NPC_DB.load();
+            let npc_data = match npc_db.npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => npc_data,
                 None => return,
             };
@@ -666,7 +667,7 @@ impl Player {
                 None => return,
             };
 
-            match NPC_DB.npcs.get(npc_id as usize - 1) {
+            match NPC_DB.load().npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => {
                     if npc_data.r#type != NpcType::Guild {
                         return;
@@ -763,7 +764,7 @@ impl Player {
                 None => return,
             };
 
-            match NPC_DB.npcs.get(npc_id as usize - 1) {
+            match NPC_DB.load().npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => {
                     if npc_data.r#type != NpcType::Guild {
                         return;
@@ -893,7 +894,8 @@ impl Player {
                 None => return,
             };
 
-            let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+            let npc_db = NPC_DB.load();
+            let npc_data = match npc_db.npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => npc_data,
                 None => return,
             };
@@ -910,58 +912,35 @@ impl Player {
                 }
             };
 
-            let mut result = match conn
-                .exec_iter(
-                    "CALL GetGuildDetails(:guild_identity);",
+            let mut row = match conn
+                .exec_first::<Row, &str, Params>(
+                    include_str!("../../../sql/get_guild_details.sql"),
                     params! {
                         "guild_identity" => &report.guild_identity,
                     },
                 )
                 .await
             {
-                Ok(result) => result,
+                Ok(Some(row)) => row,
+                Ok(None) => {
+                    player.send_guild_reply(GuildReply::NotFound);
+                    return;
+                }
                 Err(e) => {
                     error!("Error getting guild details: {}", e);
                     return;
                 }
             };
 
-            let mut packet = GuildReportServerPacket::default();
-
-            {
-                let mut stream = match result.stream::<Row>().await {
-                    Ok(Some(stream)) => stream,
-                    Ok(None) => {
-                        player.send_guild_reply(GuildReply::NotFound);
-                        return;
-                    }
-                    Err(e) => {
-                        error!("Error getting guild details: {}", e);
-                        return;
-                    }
-                };
-
-                let mut row = match stream.next().await {
-                    Some(Ok(row)) => row,
-                    Some(Err(e)) => {
-                        error!("Error getting guild details: {}", e);
-                        return;
-                    }
-                    None => {
-                        error!("Error getting guild details: no rows returned");
-                        return;
-                    }
-                };
-
-                packet.tag = row.take("tag").unwrap();
-                packet.name = row.take("name").unwrap();
-                packet.description = row.take("description").unwrap();
-
-                let created_at: NaiveDateTime = row.take("created_at").unwrap();
-                packet.create_date = created_at.format("%Y-%m-%d").to_string();
+            let created_at: NaiveDateTime = row.take("created_at").unwrap();
+            let bank: i32 = row.take("bank").unwrap();
 
-                let bank: i32 = row.take("bank").unwrap();
-                packet.wealth = if bank < 2000 {
+            let mut packet = GuildReportServerPacket {
+                tag: row.take("tag").unwrap(),
+                name: row.take("name").unwrap(),
+                description: row.take("description").unwrap(),
+                create_date: created_at.format("%Y-%m-%d").to_string(),
+                wealth: if bank < 2000 {
                     "bankrupt".to_string()
                 } else if bank < 10_000 {
                     "poor".to_string()
@@ -971,68 +950,51 @@ impl Player {
                     "wealthy".to_string()
                 } else {
                     "very wealthy".to_string()
-                };
-            }
+                },
+                ..Default::default()
+            };
 
+            let ranks = match conn
+                .exec_map(
+                    include_str!("../../../sql/get_guild_details_ranks.sql"),
+                    params! {
+                        "guild_identity" => &report.guild_identity,
+                    },
+                    |mut row: Row| row.take::<String, &str>("rank").unwrap(),
+                )
+                .await
             {
-                let mut stream = match result.stream::<Row>().await {
-                    Ok(Some(stream)) => stream,
-                    Ok(None) => {
-                        player.send_guild_reply(GuildReply::NotFound);
-                        return;
-                    }
-                    Err(e) => {
-                        error!("Error getting guild details: {}", e);
-                        return;
-                    }
-                };
-
-                let mut index = 0;
-                while let Some(row) = stream.next().await {
-                    let mut row = match row {
-                        Ok(row) => row,
-                        Err(e) => {
-                            error!("Error getting guild details: {}", e);
-                            return;
-                        }
-                    };
-
-                    // Client won't display ranks less than 4 characters long
-                    packet.ranks[index] =
-                        format!("{:<4}", row.take::<String, &str>("rank").unwrap());
-
-                    index += 1;
+                Ok(ranks) => ranks,
+                Err(e) => {
+                    error!("Error getting guild details: {}", e);
+                    return;
                 }
-            }
-
-            {
-                let mut stream = match result.stream::<Row>().await {
-                    Ok(Some(stream)) => stream,
-                    Ok(None) => {
-                        player.send_guild_reply(GuildReply::NotFound);
-                        return;
-                    }
-                    Err(e) => {
-                        error!("Error getting guild details: {}", e);
-                        return;
-                    }
-                };
+            };
 
-                while let Some(row) = stream.next().await {
-                    let mut row = match row {
-                        Ok(row) => row,
-                        Err(e) => {
-                            error!("Error getting guild details: {}", e);
-                            return;
-                        }
-                    };
+            for (index, rank) in ranks.iter().enumerate() {
+                // Client won't display ranks less than 4 characters long
+                packet.ranks[index] = format!("{:<4}", rank);
+            }
 
-                    packet.staff.push(GuildStaff {
+            packet.staff = match conn
+                .exec_map(
+                    include_str!("../../../sql/get_guild_details_staff.sql"),
+                    params! {
+                        "guild_identity" => &report.guild_identity,
+                    },
+                    |mut row: Row| GuildStaff {
                         rank: row.take("guild_rank").unwrap(),
                         name: row.take("name").unwrap(),
-                    });
+                    },
+                )
+                .await
+            {
+                Ok(staff) => staff,
+                Err(e) => {
+                    error!("Error getting guild details: {}", e);
+                    return;
                 }
-            }
+            };
 
             player.send(PacketAction::Report, PacketFamily::Guild, &packet);
         });
@@ -1084,7 +1046,8 @@ impl Player {
                 None => return,
             };
 
-            let npc_data = match NPC_DB.npcs.get(npc_id as usize - 1) {
+            let npc_db = NPC_DB.load();
+            let npc_data = match npc_db.npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => npc_data,
                 None => return,
             };
@@ -1273,7 +1236,7 @@ impl Player {
                 None => return,
             };
 
-            match NPC_DB.npcs.get(npc_id as usize - 1) {
+            match NPC_DB.load().npcs.get(npc_id as usize - 1) {
                 Some(npc_data) => {
                     if npc_data.r#type != NpcType::Guild {
                         return;
@@ -1429,7 +1392,7 @@ async fn get_guild_bank(conn: &mut Conn, tag: &str) -> i32 {
     }
 }
 
-async fn set_guild_bank(conn: &mut Conn, tag: &str, bank: i32) -> Result<(), mysql_async::Error> {
+async fn set_guild_bank(conn: &mut Conn, tag: &str, bank: i32) -> Result<(), Error> {
     conn.exec_drop(
         "UPDATE Guild SET `bank` = :bank WHERE `tag` = :tag",
         params! {
@@ -31,6 +31,8 @@ impl Player {
             }
         };
 
+        self.hdid = request.hdid.parse().ok();
+
         if let Some(duration) = self.get_ban_duration().await {
             let _ = self
                 .bus
@@ -62,7 +64,7 @@ impl Player {
                 )
                 .await;
 
-            self.close("IP Banned".to_string()).await;
+            self.close("Banned".to_string()).await;
             return;
         }
 
@@ -5,21 +5,21 @@ use eolib::{
         server::{
             LoginReply, LoginReplyServerPacket, LoginReplyServerPacketReplyCodeData,
             LoginReplyServerPacketReplyCodeDataBanned, LoginReplyServerPacketReplyCodeDataBusy,
-            LoginReplyServerPacketReplyCodeDataLoggedIn, LoginReplyServerPacketReplyCodeDataOk,
+            LoginReplyServerPacketReplyCodeDataLoggedIn,
             LoginReplyServerPacketReplyCodeDataWrongUser,
-            LoginReplyServerPacketReplyCodeDataWrongUserPassword,
+            LoginReplyServerPacketReplyCodeDataWrongUserPassword, MessageAcceptServerPacket,
         },
         PacketAction, PacketFamily,
     },
 };
-use mysql_async::{params, prelude::Queryable, Params, Row};
 
 use crate::{
+    db::{params, Params, Queryable, Row},
     deep::{
         AccountRecoverPinReply, AccountRecoverReply, AccountRecoverUpdateReply,
         LoginAcceptClientPacket, LoginAcceptServerPacket, LoginAgreeClientPacket,
-        LoginAgreeServerPacket, LoginConfigServerPacket, LoginCreateClientPacket,
-        LoginCreateServerPacket, LoginTakeClientPacket, LoginTakeServerPacket, ACTION_CONFIG,
+        LoginAgreeServerPacket, LoginCreateClientPacket, LoginCreateServerPacket,
+        LoginTakeClientPacket, LoginTakeServerPacket,
     },
     player::{
         player::account::{
@@ -28,8 +28,8 @@ use crate::{
         },
         ClientState,
     },
-    utils::{is_deep, mask_email, send_email},
-    EMAILS, SETTINGS,
+    utils::{mask_email, send_email},
+    EMAILS, LANG, SETTINGS,
 };
 
 use super::super::Player;
@@ -44,6 +44,11 @@ impl Player {
             }
         };
 
+        // already waiting on a queued login for this connection
+        if self.state == ClientState::Queued {
+            return;
+        }
+
         if self.state != ClientState::Accepted {
             self.close("Logging in before connection accepted".to_string())
                 .await;
@@ -51,7 +56,7 @@ impl Player {
         }
 
         let player_count = self.world.get_player_count().await;
-        if player_count >= SETTINGS.server.max_players {
+        if player_count >= SETTINGS.server.max_players || self.world.is_shutting_down().await {
             let _ = self
                 .bus
                 .send(
@@ -114,7 +119,7 @@ impl Player {
             return;
         }
 
-        let banned = match account_banned(&mut conn, &request.username).await {
+        let banned = match account_banned(&mut conn, &request.username, &self.ip, self.hdid).await {
             Ok(banned) => banned,
             Err(e) => {
                 self.close(format!("Error checking if account is banned: {}", e))
@@ -213,13 +218,6 @@ impl Player {
             return;
         }
 
-        if let Err(e) = update_last_login_ip(&mut conn, account_id, &self.ip).await {
-            self.world.remove_pending_login(account_id);
-            self.close(format!("Error updating last login IP: {}", e))
-                .await;
-            return;
-        }
-
         let characters = match get_character_list(&mut conn, account_id).await {
             Ok(characters) => characters,
             Err(e) => {
@@ -229,38 +227,76 @@ impl Player {
             }
         };
 
-        self.account_id = account_id;
-        self.world.add_logged_in_account(account_id);
-        self.state = ClientState::LoggedIn;
+        if let Some(message) = self.world.get_maintenance_message().await {
+            if !characters.iter().any(|character| {
+                i32::from(character.admin) >= i32::from(SETTINGS.maintenance.admin_level)
+            }) {
+                self.world.remove_pending_login(account_id);
 
-        if is_deep(&self.version) {
-            let _ = self
-                .bus
-                .send(
-                    PacketAction::Unrecognized(ACTION_CONFIG),
-                    PacketFamily::Login,
-                    LoginConfigServerPacket {
-                        max_skins: SETTINGS.character.max_skin + 1,
-                        max_hair_modals: SETTINGS.character.max_hair_style,
-                        max_character_name: SETTINGS.character.max_name_length as i32,
-                    },
-                )
+                let _ = self
+                    .bus
+                    .send(
+                        PacketAction::Accept,
+                        PacketFamily::Message,
+                        MessageAcceptServerPacket {
+                            messages: [
+                                LANG.load().maintenance_title.to_owned(),
+                                message,
+                                String::new(),
+                                String::new(),
+                            ],
+                        },
+                    )
+                    .await;
+
+                let _ = self
+                    .bus
+                    .send(
+                        PacketAction::Reply,
+                        PacketFamily::Login,
+                        LoginReplyServerPacket {
+                            reply_code: LoginReply::Busy,
+                            reply_code_data: Some(LoginReplyServerPacketReplyCodeData::Busy(
+                                LoginReplyServerPacketReplyCodeDataBusy::new(),
+                            )),
+                        },
+                    )
+                    .await;
+
+                self.close("Server in maintenance".to_string()).await;
+                return;
+            }
+        }
+
+        if let Err(e) = update_last_login_ip(&mut conn, account_id, &self.ip).await {
+            self.world.remove_pending_login(account_id);
+            self.close(format!("Error updating last login IP: {}", e))
                 .await;
+            return;
         }
 
-        let _ = self
-            .bus
-            .send(
-                PacketAction::Reply,
-                PacketFamily::Login,
-                LoginReplyServerPacket {
-                    reply_code: LoginReply::OK,
-                    reply_code_data: Some(LoginReplyServerPacketReplyCodeData::OK(
-                        LoginReplyServerPacketReplyCodeDataOk { characters },
-                    )),
-                },
-            )
-            .await;
+        self.account_id = account_id;
+
+        let skip_queue = SETTINGS.login_queue.staff_skip
+            && characters.iter().any(|character| {
+                i32::from(character.admin) >= i32::from(SETTINGS.login_queue.admin_level)
+            });
+
+        match self
+            .world
+            .queue_login(self.id, account_id, skip_queue)
+            .await
+        {
+            Some(position) => {
+                self.state = ClientState::Queued;
+                self.send_server_message(&get_lang_string!(
+                    &LANG.load().login_queue_position,
+                    position = position
+                ))
+                .await;
+            }
+            None => self.accept_login(characters).await,
+        }
     }
 
     async fn login_take(&mut self, reader: EoReader) {
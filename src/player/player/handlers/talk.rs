@@ -94,6 +94,11 @@ impl Player {
 
         tokio::spawn(async move {
             if let Some(character) = map.get_character(player_id).await {
+                if character.is_muted() {
+                    character.send_muted_message();
+                    return;
+                }
+
                 world.broadcast_global_message(
                     character.player_id.unwrap(),
                     character.name,
@@ -183,7 +188,28 @@ impl Player {
             }
         };
 
-        self.world.broadcast_party_message(self.id, open.message);
+        let map = match &self.map {
+            Some(map) => map.to_owned(),
+            None => return,
+        };
+
+        let world = self.world.to_owned();
+
+        let player_id = self.id;
+
+        tokio::spawn(async move {
+            let character = match map.get_character(player_id).await {
+                Some(character) => character,
+                None => return,
+            };
+
+            if character.is_muted() {
+                character.send_muted_message();
+                return;
+            }
+
+            world.broadcast_party_message(player_id, open.message);
+        });
     }
 
     fn talk_request(&mut self, reader: EoReader) {
@@ -210,6 +236,11 @@ impl Player {
                 None => return,
             };
 
+            if character.is_muted() {
+                character.send_muted_message();
+                return;
+            }
+
             let guild_tag = match character.guild_tag {
                 Some(guild_tag) => guild_tag,
                 None => return,
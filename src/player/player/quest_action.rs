@@ -7,6 +7,8 @@ use eolib::protocol::{
 };
 use eoplus::Arg;
 
+use crate::map::ItemHolder;
+
 use super::Player;
 
 impl Player {
@@ -47,7 +49,7 @@ impl Player {
                     _ => 1,
                 };
 
-                map.give_item(self.id, item_id, amount);
+                map.give_item(self.id, item_id, amount, ItemHolder::Quest);
             }
             "RemoveItem" => {
                 let item_id = match args.first() {
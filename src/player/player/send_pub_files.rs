@@ -0,0 +1,24 @@
+use eolib::protocol::net::{client::FileType, PacketAction, PacketFamily};
+
+use crate::{player::ClientState, PUB_FILES};
+
+use super::Player;
+
+impl Player {
+    pub async fn send_pub_files(&mut self) {
+        if self.state != ClientState::InGame {
+            return;
+        }
+
+        for file_type in [FileType::Eif, FileType::Enf, FileType::Esf, FileType::Ecf] {
+            let mut file_id = 1;
+            while let Some(buf) = PUB_FILES.load().get_file(file_type, file_id) {
+                let _ = self
+                    .bus
+                    .send_buf(PacketAction::Init, PacketFamily::Init, buf)
+                    .await;
+                file_id += 1;
+            }
+        }
+    }
+}
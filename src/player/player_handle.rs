@@ -11,10 +11,9 @@ use eolib::{
     },
 };
 use eoplus::Arg;
-use mysql_async::Pool;
 use tokio::sync::{mpsc, oneshot};
 
-use crate::{character::Character, map::MapHandle, world::WorldHandle};
+use crate::{character::Character, db::Pool, map::MapHandle, world::WorldHandle};
 
 use super::{player::Player, ClientState, Command, PartyRequest, Socket};
 
@@ -45,6 +44,10 @@ impl PlayerHandle {
             .send(Command::AddGuildCreationPlayer { player_id, name });
     }
 
+    pub fn admit_login(&self) {
+        let _ = self.tx.send(Command::AdmitLogin);
+    }
+
     pub fn arena_die(&self, spawn_coords: Coords) {
         let _ = self.tx.send(Command::ArenaDie { spawn_coords });
     }
@@ -72,6 +75,15 @@ impl PlayerHandle {
         }
     }
 
+    pub async fn get_account_id(&self) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetAccountId { respond_to: tx });
+        match rx.await {
+            Ok(account_id) => Ok(account_id),
+            Err(_) => Err("Player disconnected".into()),
+        }
+    }
+
     pub async fn get_character(
         &self,
     ) -> Result<Box<Character>, Box<dyn std::error::Error + Send + Sync>> {
@@ -158,6 +170,10 @@ impl PlayerHandle {
         let _ = self.tx.send(Command::SendGuildReply(guild_reply));
     }
 
+    pub fn send_pub_files(&self) {
+        let _ = self.tx.send(Command::SendPubFiles);
+    }
+
     pub fn send_server_message(&self, message: &str) {
         let _ = self.tx.send(Command::SendServerMessage(message.to_owned()));
     }
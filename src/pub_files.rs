@@ -0,0 +1,150 @@
+use bytes::Bytes;
+use eolib::{
+    data::{EoSerialize, EoWriter},
+    protocol::net::{
+        client::FileType,
+        server::{
+            InitInitServerPacket, InitInitServerPacketReplyCodeData,
+            InitInitServerPacketReplyCodeDataFileEcf, InitInitServerPacketReplyCodeDataFileEif,
+            InitInitServerPacketReplyCodeDataFileEnf, InitInitServerPacketReplyCodeDataFileEsf,
+            InitReply, PubFile,
+        },
+    },
+};
+
+use crate::{utils::SplitPubFile, CLASS_DB, ITEM_DB, NPC_DB, SETTINGS, SPELL_DB};
+
+/// Pub file replies serialized once when the pubs are loaded and shared by
+/// every player that requests them. Rebuilt by `$reload pubs`.
+#[derive(Debug)]
+pub struct PubFiles {
+    pub eif: CachedPub,
+    pub enf: CachedPub,
+    pub esf: CachedPub,
+    pub ecf: CachedPub,
+}
+
+#[derive(Debug)]
+pub struct CachedPub {
+    /// rid of the whole pub, carried by every file and advertised in the
+    /// welcome reply
+    pub rid: [i32; 2],
+    /// Record count across every file
+    pub length: i32,
+    /// Serialized `InitInitServerPacket` for each file, indexed by file id - 1
+    pub files: Vec<Bytes>,
+}
+
+impl PubFiles {
+    pub fn new() -> Self {
+        let max_size = SETTINGS.server.pub_split_size;
+
+        let eif = ITEM_DB.load().split(max_size);
+        let enf = NPC_DB.load().split(max_size);
+        let esf = SPELL_DB.load().split(max_size);
+        let ecf = CLASS_DB.load().split(max_size);
+
+        Self {
+            eif: CachedPub::new(
+                eif[0].rid,
+                ITEM_DB.load().items.len() as i32,
+                &eif,
+                InitReply::FileEif,
+                |pub_file| {
+                    InitInitServerPacketReplyCodeData::FileEif(
+                        InitInitServerPacketReplyCodeDataFileEif { pub_file },
+                    )
+                },
+            ),
+            enf: CachedPub::new(
+                enf[0].rid,
+                NPC_DB.load().npcs.len() as i32,
+                &enf,
+                InitReply::FileEnf,
+                |pub_file| {
+                    InitInitServerPacketReplyCodeData::FileEnf(
+                        InitInitServerPacketReplyCodeDataFileEnf { pub_file },
+                    )
+                },
+            ),
+            esf: CachedPub::new(
+                esf[0].rid,
+                SPELL_DB.load().skills.len() as i32,
+                &esf,
+                InitReply::FileEsf,
+                |pub_file| {
+                    InitInitServerPacketReplyCodeData::FileEsf(
+                        InitInitServerPacketReplyCodeDataFileEsf { pub_file },
+                    )
+                },
+            ),
+            ecf: CachedPub::new(
+                ecf[0].rid,
+                CLASS_DB.load().classes.len() as i32,
+                &ecf,
+                InitReply::FileEcf,
+                |pub_file| {
+                    InitInitServerPacketReplyCodeData::FileEcf(
+                        InitInitServerPacketReplyCodeDataFileEcf { pub_file },
+                    )
+                },
+            ),
+        }
+    }
+
+    /// The reply for one numbered file of a pub, `None` if there's no such
+    /// file
+    pub fn get_file(&self, file_type: FileType, file_id: i32) -> Option<Bytes> {
+        let index = usize::try_from(file_id).ok()?.checked_sub(1)?;
+
+        let cached = match file_type {
+            FileType::Eif => &self.eif,
+            FileType::Enf => &self.enf,
+            FileType::Esf => &self.esf,
+            FileType::Ecf => &self.ecf,
+            _ => return None,
+        };
+
+        cached.files.get(index).cloned()
+    }
+}
+
+impl CachedPub {
+    /// Serializes a reply for each file of a split pub, numbering them from 1
+    fn new<T: EoSerialize>(
+        rid: [i32; 2],
+        length: i32,
+        files: &[T],
+        reply_code: InitReply,
+        reply_code_data: impl Fn(PubFile) -> InitInitServerPacketReplyCodeData,
+    ) -> Self {
+        Self {
+            rid,
+            length,
+            files: (1..)
+                .zip(files)
+                .map(|(file_id, file)| {
+                    serialize_reply(InitInitServerPacket {
+                        reply_code,
+                        reply_code_data: Some(reply_code_data(PubFile {
+                            file_id,
+                            content: serialize_file(file),
+                        })),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+fn serialize_file<T: EoSerialize>(file: &T) -> Vec<u8> {
+    let mut writer = EoWriter::new();
+    file.serialize(&mut writer).unwrap();
+    writer.to_byte_array().to_vec()
+}
+
+fn serialize_reply(reply: InitInitServerPacket) -> Bytes {
+    let mut writer = EoWriter::new();
+    reply.serialize(&mut writer).unwrap();
+    writer.to_byte_array()
+}
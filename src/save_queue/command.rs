@@ -0,0 +1,21 @@
+use tokio::sync::oneshot;
+
+use crate::character::Character;
+
+use super::LedgerEntry;
+
+#[derive(Debug)]
+pub enum Command {
+    Flush {
+        respond_to: oneshot::Sender<()>,
+    },
+    FlushCharacter {
+        character_id: i32,
+        respond_to: oneshot::Sender<bool>,
+    },
+    LogItemTransfer(LedgerEntry),
+    Save {
+        character: Box<Character>,
+        logged_out: bool,
+    },
+}
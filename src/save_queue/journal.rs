@@ -0,0 +1,92 @@
+use std::io::ErrorKind;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Writes that haven't reached the database, one JSON object per line.
+/// Replaced as a whole so a crash mid-write leaves the old journal.
+pub async fn read_journal<T: DeserializeOwned>(
+    path: &str,
+) -> Result<Vec<T>, Box<dyn std::error::Error + Send + Sync>> {
+    let contents = match tokio::fs::read_to_string(path).await {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut entries = Vec::new();
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        entries.push(serde_json::from_str(line)?);
+    }
+
+    Ok(entries)
+}
+
+pub async fn write_journal<'a, T: Serialize + 'a>(
+    path: &str,
+    entries: impl Iterator<Item = &'a T>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let mut contents = String::new();
+    for entry in entries {
+        contents.push_str(&serde_json::to_string(entry)?);
+        contents.push('\n');
+    }
+
+    let temp_path = format!("{}.tmp", path);
+    tokio::fs::write(&temp_path, contents).await?;
+    tokio::fs::rename(&temp_path, path).await?;
+
+    Ok(())
+}
+
+pub async fn remove_journal(path: &str) -> Result<(), std::io::Error> {
+    match tokio::fs::remove_file(path).await {
+        Err(e) if e.kind() != ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::character::Character;
+
+    use super::*;
+
+    fn journal_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("reoserv-{}-{}.jsonl", name, std::process::id()))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[tokio::test]
+    async fn replays_what_was_written() {
+        let path = journal_path("journal-round-trip");
+        let characters: Vec<Character> = (1..=2)
+            .map(|id| Character {
+                id,
+                name: format!("character{}", id),
+                ..Default::default()
+            })
+            .collect();
+
+        write_journal(&path, characters.iter()).await.unwrap();
+        let replayed: Vec<Character> = read_journal(&path).await.unwrap();
+        remove_journal(&path).await.unwrap();
+
+        let ids: Vec<(i32, &str)> = replayed
+            .iter()
+            .map(|character| (character.id, character.name.as_str()))
+            .collect();
+        assert_eq!(ids, [(1, "character1"), (2, "character2")]);
+        assert!(!std::path::Path::new(&format!("{}.tmp", path)).exists());
+    }
+
+    #[tokio::test]
+    async fn missing_journal_is_empty() {
+        let path = journal_path("journal-missing");
+        remove_journal(&path).await.unwrap();
+
+        let replayed: Vec<Character> = read_journal(&path).await.unwrap();
+        assert!(replayed.is_empty());
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+
+use crate::db::{params, Conn, Error, Queryable};
+
+/// A row for the item ledger, queued with character saves so it isn't lost
+/// while the database is down
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub item_id: i32,
+    pub amount: i32,
+    pub source: String,
+    pub destination: String,
+    pub map: i32,
+    pub x: i32,
+    pub y: i32,
+    pub created_at: NaiveDateTime,
+}
+
+impl LedgerEntry {
+    pub async fn save(&self, conn: &mut Conn) -> Result<(), Error> {
+        conn.exec_drop(
+            include_str!("../sql/create_item_ledger_entry.sql"),
+            params! {
+                "item_id" => self.item_id,
+                "amount" => self.amount,
+                "source" => &self.source,
+                "destination" => &self.destination,
+                "map" => self.map,
+                "x" => self.x,
+                "y" => self.y,
+                "created_at" => self.created_at,
+            },
+        )
+        .await
+    }
+}
@@ -0,0 +1,9 @@
+mod command;
+use command::Command;
+mod journal;
+mod ledger_entry;
+pub use ledger_entry::LedgerEntry;
+#[allow(clippy::module_inception)]
+mod save_queue;
+mod save_queue_handle;
+pub use save_queue_handle::SaveQueueHandle;
@@ -0,0 +1,342 @@
+use std::{collections::HashMap, time::Duration};
+
+use tokio::{sync::mpsc::UnboundedReceiver, time::Instant};
+
+use crate::{
+    character::{Character, SavedState},
+    db::Pool,
+    metrics::{SAVE_QUEUE_PENDING, SAVE_QUEUE_WRITE_DURATION},
+    SETTINGS,
+};
+
+use super::{
+    journal::{read_journal, remove_journal, write_journal},
+    Command, LedgerEntry,
+};
+
+#[derive(Debug)]
+struct PendingSave {
+    character: Box<Character>,
+    logged_out: bool,
+}
+
+impl PendingSave {
+    /// Swaps in a newer state of the character. Neither has been written, so
+    /// the saved state the older one carried still matches the database.
+    fn replace(&mut self, mut character: Box<Character>, logged_out: bool) {
+        if character.saved_state.is_none() {
+            character.saved_state = self.character.saved_state.take();
+        }
+
+        self.character = character;
+        self.logged_out = logged_out;
+    }
+}
+
+#[derive(Debug)]
+pub struct SaveQueue {
+    pub rx: UnboundedReceiver<Command>,
+    pub retry_at: Option<Instant>,
+    pool: Pool,
+    pending: Vec<PendingSave>,
+    pending_ledger: Vec<LedgerEntry>,
+    /// Last written state of online characters so saves only write changes
+    saved: HashMap<i32, Box<SavedState>>,
+    failures: u32,
+    failing_since: Option<Instant>,
+    journaled: bool,
+}
+
+impl SaveQueue {
+    pub fn new(rx: UnboundedReceiver<Command>, pool: Pool) -> Self {
+        Self {
+            rx,
+            retry_at: None,
+            pool,
+            pending: Vec::new(),
+            pending_ledger: Vec::new(),
+            saved: HashMap::new(),
+            failures: 0,
+            failing_since: None,
+            journaled: false,
+        }
+    }
+
+    pub async fn handle_command(&mut self, command: Command) {
+        match command {
+            Command::Flush { respond_to } => {
+                self.process().await;
+                if self.has_pending() {
+                    self.write_journal().await;
+                }
+                let _ = respond_to.send(());
+            }
+            Command::FlushCharacter {
+                character_id,
+                respond_to,
+            } => {
+                if self.is_pending(character_id) {
+                    self.process().await;
+                }
+                let _ = respond_to.send(!self.is_pending(character_id));
+            }
+            Command::LogItemTransfer(entry) => {
+                self.pending_ledger.push(entry);
+                self.process_or_wait().await;
+            }
+            Command::Save {
+                character,
+                logged_out,
+            } => {
+                self.push(character, logged_out);
+                self.process_or_wait().await;
+            }
+        }
+    }
+
+    async fn process_or_wait(&mut self) {
+        // Writes made while backing off wait for the next retry
+        if self.retry_at.is_none() {
+            self.process().await;
+        } else if self.journaled {
+            self.write_journal().await;
+        }
+    }
+
+    pub async fn replay_journal(&mut self) {
+        let characters: Vec<Character> =
+            match read_journal(&SETTINGS.save_queue.journal_path).await {
+                Ok(characters) => characters,
+                Err(e) => {
+                    error!("Failed to read save journal: {}", e);
+                    return;
+                }
+            };
+
+        let ledger: Vec<LedgerEntry> =
+            match read_journal(&SETTINGS.save_queue.ledger_journal_path).await {
+                Ok(ledger) => ledger,
+                Err(e) => {
+                    error!("Failed to read ledger journal: {}", e);
+                    return;
+                }
+            };
+
+        if characters.is_empty() && ledger.is_empty() {
+            return;
+        }
+
+        info!(
+            "Replaying {} saves and {} ledger entries from journal",
+            characters.len(),
+            ledger.len()
+        );
+
+        self.journaled = true;
+        for character in characters {
+            self.push(Box::new(character), true);
+        }
+        self.pending_ledger.extend(ledger);
+
+        self.process().await;
+    }
+
+    /// Attempts every pending save, scheduling a retry if any fail
+    pub async fn process(&mut self) {
+        self.retry_at = None;
+
+        if !self.has_pending() {
+            return;
+        }
+
+        let _timer = SAVE_QUEUE_WRITE_DURATION.start_timer();
+
+        let mut conn = match self.pool.get_conn().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                error!("Failed to get connection from pool: {}", e);
+                self.schedule_retry().await;
+                return;
+            }
+        };
+
+        let mut failed = Vec::new();
+        for mut pending in self.pending.drain(..) {
+            let character_id = pending.character.id;
+            if let Some(saved) = self.saved.remove(&character_id) {
+                pending.character.saved_state = Some(saved);
+            }
+
+            match pending.character.save(&mut conn).await {
+                Ok(()) => {
+                    if !pending.logged_out {
+                        if let Some(saved) = pending.character.saved_state.take() {
+                            self.saved.insert(character_id, saved);
+                        }
+                    }
+                }
+                Err(e) => {
+                    error!("Failed to save character {}: {}", pending.character.name, e);
+                    failed.push(pending);
+                }
+            }
+        }
+
+        self.pending = failed;
+        SAVE_QUEUE_PENDING.set(self.pending.len() as i64);
+
+        let mut failed = Vec::new();
+        for entry in self.pending_ledger.drain(..) {
+            if let Err(e) = entry.save(&mut conn).await {
+                error!("Failed to create item ledger entry: {}", e);
+                failed.push(entry);
+            }
+        }
+
+        self.pending_ledger = failed;
+
+        if self.has_pending() {
+            self.schedule_retry().await;
+            return;
+        }
+
+        if self.failures > 0 {
+            info!("Database recovered, all pending saves written");
+        }
+
+        self.failures = 0;
+        self.failing_since = None;
+
+        if self.journaled {
+            if let Err(e) = remove_journal(&SETTINGS.save_queue.journal_path).await {
+                error!("Failed to remove save journal: {}", e);
+                return;
+            }
+            if let Err(e) = remove_journal(&SETTINGS.save_queue.ledger_journal_path).await {
+                error!("Failed to remove ledger journal: {}", e);
+                return;
+            }
+            self.journaled = false;
+        }
+    }
+
+    fn push(&mut self, character: Box<Character>, logged_out: bool) {
+        // Only the newest state of a character is worth writing
+        match self
+            .pending
+            .iter_mut()
+            .find(|pending| pending.character.id == character.id)
+        {
+            Some(pending) => pending.replace(character, logged_out),
+            None => self.pending.push(PendingSave {
+                character,
+                logged_out,
+            }),
+        }
+
+        SAVE_QUEUE_PENDING.set(self.pending.len() as i64);
+    }
+
+    fn has_pending(&self) -> bool {
+        !self.pending.is_empty() || !self.pending_ledger.is_empty()
+    }
+
+    fn is_pending(&self, character_id: i32) -> bool {
+        self.pending
+            .iter()
+            .any(|pending| pending.character.id == character_id)
+    }
+
+    async fn schedule_retry(&mut self) {
+        let now = Instant::now();
+        let failing_since = *self.failing_since.get_or_insert(now);
+
+        let delay = retry_delay(
+            SETTINGS.save_queue.retry_delay,
+            SETTINGS.save_queue.max_retry_delay,
+            self.failures,
+        );
+        self.failures += 1;
+        self.retry_at = Some(now + Duration::from_secs(delay));
+
+        warn!(
+            "{} character saves and {} ledger entries pending, retrying in {}s",
+            self.pending.len(),
+            self.pending_ledger.len(),
+            delay
+        );
+
+        if now - failing_since >= Duration::from_secs(SETTINGS.save_queue.journal_after) {
+            self.write_journal().await;
+        }
+    }
+
+    async fn write_journal(&mut self) {
+        let characters = self
+            .pending
+            .iter()
+            .map(|pending| pending.character.as_ref());
+        if let Err(e) = write_journal(&SETTINGS.save_queue.journal_path, characters).await {
+            error!("Failed to write save journal: {}", e);
+            return;
+        }
+
+        let ledger = self.pending_ledger.iter();
+        match write_journal(&SETTINGS.save_queue.ledger_journal_path, ledger).await {
+            Ok(()) => self.journaled = true,
+            Err(e) => error!("Failed to write ledger journal: {}", e),
+        }
+    }
+}
+
+/// Doubles the delay for each failure in a row, up to the max
+fn retry_delay(retry_delay: u64, max_retry_delay: u64, failures: u32) -> u64 {
+    retry_delay
+        .saturating_mul(1 << failures.min(16))
+        .min(max_retry_delay)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending(character: Character) -> PendingSave {
+        PendingSave {
+            character: Box::new(character),
+            logged_out: false,
+        }
+    }
+
+    #[test]
+    fn replacing_keeps_the_unwritten_saved_state() {
+        let mut old = Character {
+            level: 1,
+            ..Default::default()
+        };
+        let saved = SavedState::new(&old);
+        old.saved_state = Some(Box::new(saved.clone()));
+        let mut pending = pending(old);
+
+        let newer = Character {
+            level: 2,
+            ..Default::default()
+        };
+        pending.replace(Box::new(newer), true);
+
+        assert_eq!(pending.character.level, 2);
+        assert!(pending.logged_out);
+        assert_eq!(pending.character.saved_state, Some(Box::new(saved)));
+    }
+
+    #[test]
+    fn retry_delay_doubles_up_to_the_max() {
+        let delays: Vec<u64> = (0..5).map(|failures| retry_delay(5, 60, failures)).collect();
+        assert_eq!(delays, [5, 10, 20, 40, 60]);
+    }
+
+    #[test]
+    fn retry_delay_does_not_overflow_after_many_failures() {
+        assert_eq!(retry_delay(u64::MAX / 2, u64::MAX, 100), u64::MAX);
+        assert_eq!(retry_delay(5, 300, u32::MAX), 300);
+    }
+}
@@ -0,0 +1,69 @@
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{character::Character, db::Pool};
+
+use super::{save_queue::SaveQueue, Command, LedgerEntry};
+
+/// Every in-game character save goes through here so saves for the same
+/// character are always written in order, even while the database is down.
+/// Item ledger entries share the same retries and journal.
+#[derive(Debug, Clone)]
+pub struct SaveQueueHandle {
+    tx: mpsc::UnboundedSender<Command>,
+}
+
+impl SaveQueueHandle {
+    pub fn new(pool: Pool) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let save_queue = SaveQueue::new(rx, pool);
+        tokio::spawn(run_save_queue(save_queue));
+
+        Self { tx }
+    }
+
+    /// Writes every pending save, journaling whatever still fails
+    pub async fn flush(&self) {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::Flush { respond_to: tx });
+        let _ = rx.await;
+    }
+
+    /// Writes any pending save for a character. Returns false if one is
+    /// still pending, in which case its rows in the database are stale.
+    pub async fn flush_character(&self, character_id: i32) -> bool {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::FlushCharacter {
+            character_id,
+            respond_to: tx,
+        });
+        rx.await.unwrap_or(false)
+    }
+
+    pub fn log_item_transfer(&self, entry: LedgerEntry) {
+        let _ = self.tx.send(Command::LogItemTransfer(entry));
+    }
+
+    pub fn save(&self, character: Box<Character>, logged_out: bool) {
+        let _ = self.tx.send(Command::Save {
+            character,
+            logged_out,
+        });
+    }
+}
+
+async fn run_save_queue(mut save_queue: SaveQueue) {
+    save_queue.replay_journal().await;
+
+    loop {
+        let retry_at = save_queue.retry_at;
+        tokio::select! {
+            command = save_queue.rx.recv() => match command {
+                Some(command) => save_queue.handle_command(command).await,
+                None => break,
+            },
+            _ = tokio::time::sleep_until(retry_at.unwrap_or_else(tokio::time::Instant::now)), if retry_at.is_some() => {
+                save_queue.process().await;
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use config::{Config, ConfigError, File};
+use eolib::protocol::AdminLevel;
 
 #[derive(Debug, Deserialize)]
 pub struct Server {
@@ -17,16 +18,36 @@ pub struct Server {
     pub max_version: String,
     pub save_rate: i32,
     pub generate_pub: bool,
+    pub pub_split_size: usize,
     pub lang: String,
+    pub console: bool,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct Database {
+    pub driver: DatabaseDriver,
     pub host: String,
     pub port: String,
     pub name: String,
     pub username: String,
     pub password: String,
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseDriver {
+    Mysql,
+    Sqlite,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SaveQueue {
+    pub retry_delay: u64,
+    pub max_retry_delay: u64,
+    pub journal_after: u64,
+    pub journal_path: String,
+    pub ledger_journal_path: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -86,6 +107,7 @@ pub struct World {
     pub ghost_rate: i32,
     pub polymorph_rate: i32,
     pub info_reveals_drops: bool,
+    pub default_mute_duration: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -111,6 +133,12 @@ pub struct NPCs {
     pub talk_rate: i32,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct Economy {
+    pub snapshot_rate: i32,
+    pub top_holders: i32,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Sln {
     pub enabled: bool,
@@ -184,6 +212,7 @@ pub struct Quake {
 pub struct Map {
     pub quakes: Vec<Quake>,
     pub door_close_rate: i32,
+    pub item_expiry: i32,
 }
 
 #[derive(Debug, Deserialize)]
@@ -268,10 +297,52 @@ pub struct AutoPickup {
     pub rate: i32,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct Shutdown {
+    pub warnings: Vec<i64>,
+    pub default_countdown: i32,
+    pub restart_exit_code: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoginQueue {
+    pub enabled: bool,
+    pub max_accounts: i32,
+    pub update_interval: i32,
+    pub staff_skip: bool,
+    pub admin_level: AdminLevel,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Maintenance {
+    pub enabled: bool,
+    pub admin_level: AdminLevel,
+    pub message: String,
+    pub kick_online: bool,
+    pub kick_delay: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AdminApi {
+    pub enabled: bool,
+    pub host: String,
+    pub port: String,
+    pub token: String,
+    pub admin_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Metrics {
+    pub enabled: bool,
+    pub host: String,
+    pub port: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Settings {
     pub server: Server,
     pub database: Database,
+    pub save_queue: SaveQueue,
     pub account: Account,
     pub new_character: NewCharacter,
     pub jail: Jail,
@@ -280,6 +351,7 @@ pub struct Settings {
     pub character: Character,
     pub npcs: NPCs,
     pub sln: Sln,
+    pub economy: Economy,
     pub bank: Bank,
     pub limits: Limits,
     pub board: Board,
@@ -295,6 +367,11 @@ pub struct Settings {
     pub bard: Bard,
     pub smtp: Smtp,
     pub auto_pickup: AutoPickup,
+    pub shutdown: Shutdown,
+    pub maintenance: Maintenance,
+    pub login_queue: LoginQueue,
+    pub admin_api: AdminApi,
+    pub metrics: Metrics,
 }
 
 impl Settings {
@@ -0,0 +1,242 @@
+use config::{Config, File};
+use eolib::protocol::{map::MapNpc, Coords};
+use glob::glob;
+
+/// NPC spawn changes layered over the spawns baked into each map's EMF
+#[derive(Debug, Default)]
+pub struct Spawns {
+    pub files: Vec<SpawnFile>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpawnFile {
+    #[serde(skip)]
+    pub path: String,
+    pub map: i32,
+    #[serde(default)]
+    pub remove: Vec<SpawnMatch>,
+    #[serde(default)]
+    pub replace: Vec<SpawnReplacement>,
+    #[serde(default)]
+    pub add: Vec<Spawn>,
+}
+
+/// Picks out EMF spawns by their coordinates, and optionally by npc id when
+/// more than one spawn shares a tile
+#[derive(Debug, Deserialize)]
+pub struct SpawnMatch {
+    pub x: i32,
+    pub y: i32,
+    #[serde(default)]
+    pub npc_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SpawnReplacement {
+    pub at: SpawnMatch,
+    pub with: Spawn,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Spawn {
+    pub npc_id: i32,
+    pub x: i32,
+    pub y: i32,
+    pub spawn_type: i32,
+    pub spawn_time: i32,
+    pub amount: i32,
+}
+
+impl Spawns {
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        let mut paths = glob("data/spawns/*.ron")?.collect::<Result<Vec<_>, _>>()?;
+        paths.sort();
+
+        let mut files = Vec::with_capacity(paths.len());
+        for path in paths {
+            let mut file: SpawnFile = Config::builder()
+                .add_source(File::from(path.as_path()))
+                .build()?
+                .try_deserialize()?;
+
+            file.path = path.display().to_string();
+
+            let spawns = file
+                .replace
+                .iter()
+                .map(|replacement| &replacement.with)
+                .chain(file.add.iter());
+
+            for spawn in spawns {
+                if spawn.npc_id < 1 {
+                    return Err(format!("{}: invalid npc id {}", file.path, spawn.npc_id).into());
+                }
+            }
+
+            files.push(file);
+        }
+
+        Ok(Self { files })
+    }
+
+    /// The spawns for a map after applying every spawn file for it, in file
+    /// name order. Each file removes, then replaces, then adds.
+    pub fn get_npc_spawns(&self, map_id: i32, npcs: &[MapNpc]) -> Vec<MapNpc> {
+        let mut spawns = npcs.to_vec();
+
+        for file in self.files.iter().filter(|file| file.map == map_id) {
+            for remove in &file.remove {
+                let len = spawns.len();
+                spawns.retain(|spawn| !remove.matches(spawn));
+                if spawns.len() == len {
+                    warn!(
+                        "{}: no spawn to remove at {},{} on map {}",
+                        file.path, remove.x, remove.y, map_id
+                    );
+                }
+            }
+
+            for replacement in &file.replace {
+                let mut replaced = false;
+                for spawn in spawns
+                    .iter_mut()
+                    .filter(|spawn| replacement.at.matches(spawn))
+                {
+                    *spawn = replacement.with.to_map_npc();
+                    replaced = true;
+                }
+
+                if !replaced {
+                    warn!(
+                        "{}: no spawn to replace at {},{} on map {}",
+                        file.path, replacement.at.x, replacement.at.y, map_id
+                    );
+                }
+            }
+
+            spawns.extend(file.add.iter().map(Spawn::to_map_npc));
+        }
+
+        spawns
+    }
+}
+
+impl SpawnMatch {
+    pub fn matches(&self, spawn: &MapNpc) -> bool {
+        spawn.coords.x == self.x
+            && spawn.coords.y == self.y
+            && self.npc_id.is_none_or(|npc_id| npc_id == spawn.id)
+    }
+}
+
+impl Spawn {
+    pub fn to_map_npc(&self) -> MapNpc {
+        MapNpc {
+            coords: Coords {
+                x: self.x,
+                y: self.y,
+            },
+            id: self.npc_id,
+            spawn_type: self.spawn_type,
+            spawn_time: self.spawn_time,
+            amount: self.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eolib::protocol::{map::MapNpc, Coords};
+
+    use super::{Spawn, SpawnFile, SpawnMatch, SpawnReplacement, Spawns};
+
+    fn spawn(npc_id: i32, x: i32, y: i32) -> Spawn {
+        Spawn {
+            npc_id,
+            x,
+            y,
+            spawn_type: 0,
+            spawn_time: 10,
+            amount: 1,
+        }
+    }
+
+    fn at(x: i32, y: i32, npc_id: Option<i32>) -> SpawnMatch {
+        SpawnMatch { x, y, npc_id }
+    }
+
+    fn file(map: i32) -> SpawnFile {
+        SpawnFile {
+            path: format!("data/spawns/{}.ron", map),
+            map,
+            remove: Vec::new(),
+            replace: Vec::new(),
+            add: Vec::new(),
+        }
+    }
+
+    fn ids(spawns: &[MapNpc]) -> Vec<(i32, i32, i32)> {
+        spawns
+            .iter()
+            .map(|spawn| (spawn.id, spawn.coords.x, spawn.coords.y))
+            .collect()
+    }
+
+    fn emf_spawns() -> Vec<MapNpc> {
+        [(1, 1, 1), (2, 1, 1), (3, 5, 5)]
+            .iter()
+            .map(|(id, x, y)| MapNpc {
+                coords: Coords { x: *x, y: *y },
+                id: *id,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn removes_then_replaces_then_adds() {
+        let mut file = file(1);
+        // the add on the same tile isn't removed or replaced
+        file.add = vec![spawn(9, 5, 5)];
+        file.remove = vec![at(5, 5, None)];
+        file.replace = vec![
+            SpawnReplacement {
+                at: at(1, 1, Some(2)),
+                with: spawn(20, 2, 2),
+            },
+            SpawnReplacement {
+                at: at(5, 5, None),
+                with: spawn(30, 5, 5),
+            },
+        ];
+
+        let spawns = Spawns { files: vec![file] };
+
+        assert_eq!(
+            ids(&spawns.get_npc_spawns(1, &emf_spawns())),
+            [(1, 1, 1), (20, 2, 2), (9, 5, 5)]
+        );
+    }
+
+    #[test]
+    fn later_files_see_earlier_changes() {
+        let mut first = file(1);
+        first.add = vec![spawn(9, 7, 7)];
+
+        let mut second = file(1);
+        second.remove = vec![at(1, 1, None)];
+        second.replace = vec![SpawnReplacement {
+            at: at(7, 7, Some(9)),
+            with: spawn(10, 7, 7),
+        }];
+
+        let spawns = Spawns {
+            files: vec![first, second],
+        };
+
+        assert_eq!(
+            ids(&spawns.get_npc_spawns(1, &emf_spawns())),
+            [(3, 5, 5), (10, 7, 7)]
+        );
+    }
+}
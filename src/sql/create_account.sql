@@ -20,5 +20,5 @@ VALUES
         :computer,
         :hdid,
         :register_ip,
-        NOW()
+        CURRENT_TIMESTAMP
     );
\ No newline at end of file
@@ -0,0 +1,2 @@
+INSERT INTO `AdminLog` (`admin_name`, `command`, `args`, `target`, `map`, `x`, `y`)
+VALUES (:admin_name, :command, :args, :target, :map, :x, :y);
@@ -0,0 +1,2 @@
+INSERT INTO `ChestItem` (`map`, `x`, `y`, `index`, `slot`, `item_id`, `amount`)
+VALUES (:map_id, :x, :y, :index, :slot, :item_id, :amount);
@@ -0,0 +1,2 @@
+INSERT INTO `EconomySnapshot` (`inventory_gold`, `bank_gold`, `guild_gold`, `created_at`)
+VALUES (:inventory_gold, :bank_gold, :guild_gold, :created_at);
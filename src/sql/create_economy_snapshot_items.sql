@@ -0,0 +1,8 @@
+INSERT INTO `EconomySnapshotItem` (`snapshot_id`, `item_id`, `amount`)
+SELECT :snapshot_id, `item_id`, SUM(`quantity`)
+FROM (
+  SELECT `item_id`, `quantity` FROM `Inventory`
+  UNION ALL
+  SELECT `item_id`, `quantity` FROM `Bank`
+) AS `Items`
+GROUP BY `item_id`;
@@ -0,0 +1,2 @@
+INSERT INTO `ItemLedger` (`item_id`, `amount`, `source`, `destination`, `map`, `x`, `y`, `created_at`)
+VALUES (:item_id, :amount, :source, :destination, :map, :x, :y, :created_at);
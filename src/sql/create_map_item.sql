@@ -0,0 +1,2 @@
+INSERT INTO `MapItem` (`map`, `uid`, `item_id`, `amount`, `x`, `y`, `owner`, `protected_ticks`, `dropped_at`)
+VALUES (:map_id, :uid, :item_id, :amount, :x, :y, :owner, :protected_ticks, :dropped_at);
@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS `SchemaVersion` (
+  `version` int NOT NULL,
+  `name` varchar(64) NOT NULL,
+  `applied_at` datetime NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (`version`)
+);
@@ -0,0 +1,2 @@
+INSERT INTO `SchemaVersion` (`version`, `name`)
+VALUES (:version, :name);
@@ -0,0 +1 @@
+DELETE FROM `ChestItem` WHERE `map` = :map_id;
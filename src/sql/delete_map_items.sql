@@ -0,0 +1 @@
+DELETE FROM `MapItem` WHERE `map` = :map_id;
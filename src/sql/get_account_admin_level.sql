@@ -0,0 +1,3 @@
+SELECT IFNULL(MAX(`admin_level`), 0) 'admin_level'
+FROM `Character`
+WHERE `account_id` = :account_id;
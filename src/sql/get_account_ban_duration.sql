@@ -1,6 +1,8 @@
 SELECT IFNULL(b.`duration`, 0) 'duration', b.`created_at`
 FROM `Ban` b
-INNER JOIN `Account` a
+LEFT JOIN `Account` a
   ON a.id = b.account_id
-  AND a.name = :name
-ORDER BY b.`created_at` DESC
+WHERE a.name = :name
+   OR (b.ip = :ip AND b.scope IN ('ip', 'all'))
+   OR (b.hdid = :hdid AND b.scope IN ('hdid', 'all'))
+ORDER BY b.`duration` IS NOT NULL, DATE_ADD(b.`created_at`, INTERVAL b.`duration` MINUTE) DESC
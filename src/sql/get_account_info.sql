@@ -0,0 +1,4 @@
+SELECT `name` 'account_name',
+       `last_login_ip`
+FROM `Account`
+WHERE `id` = :account_id;
@@ -0,0 +1,12 @@
+SELECT `admin_name`,
+       `command`,
+       `args`,
+       `map`,
+       `x`,
+       `y`,
+       `created_at`
+FROM `AdminLog`
+WHERE :name IS NULL
+   OR `admin_name` = :name
+   OR `target` = :name
+ORDER BY `id` DESC LIMIT :limit;
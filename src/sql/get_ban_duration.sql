@@ -1,4 +1,5 @@
 SELECT IFNULL(`duration`, 0) 'duration', `created_at`
 FROM `Ban`
-WHERE `ip` = :ip
-ORDER BY `created_at` DESC
+WHERE (`ip` = :ip AND `scope` IN ('ip', 'all'))
+   OR (`hdid` = :hdid AND `scope` IN ('hdid', 'all'))
+ORDER BY `duration` IS NOT NULL, DATE_ADD(`created_at`, INTERVAL `duration` MINUTE) DESC
@@ -0,0 +1,16 @@
+SELECT b.`id`,
+       a.`name` 'account_name',
+       b.`ip`,
+       b.`hdid`,
+       b.`scope`,
+       b.`duration`,
+       c.`name` 'created_by',
+       b.`created_at`
+FROM `Ban` b
+LEFT JOIN `Account` a
+  ON a.id = b.account_id
+LEFT JOIN `Character` c
+  ON c.id = b.created_by
+WHERE (b.`duration` IS NULL OR DATE_ADD(b.`created_at`, INTERVAL b.`duration` MINUTE) > NOW())
+  AND (:filter IS NULL OR a.`name` = :filter OR b.`ip` LIKE CONCAT(:filter, '%'))
+ORDER BY b.`id` DESC LIMIT 50;
@@ -14,6 +14,7 @@ SELECT c.account_id,
        c.gold_bank,
        c.guild_rank,
        c.guild_rank_string,
+       c.muted_until,
        p.boots,
        p.accessory,
        p.gloves,
@@ -1,4 +1,4 @@
-SELECT a.id, a.last_login_ip
+SELECT a.id, a.last_login_ip, a.hdid
 FROM `Character` c
 INNER JOIN `Account` a
 	ON a.id = c.account_id
@@ -0,0 +1,4 @@
+SELECT `x`, `y`, `slot`, `item_id`, `amount`
+FROM `ChestItem`
+WHERE `map` = :map_id
+ORDER BY `x`, `y`, `index`;
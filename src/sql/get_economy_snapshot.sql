@@ -0,0 +1,4 @@
+SELECT `id`, `inventory_gold`, `bank_gold`, `guild_gold`, `created_at`
+FROM `EconomySnapshot`
+WHERE `created_at` <= :before
+ORDER BY `created_at` DESC LIMIT 1;
@@ -0,0 +1,3 @@
+SELECT `item_id`, `amount`
+FROM `EconomySnapshotItem`
+WHERE `snapshot_id` = :snapshot_id;
@@ -0,0 +1,3 @@
+SELECT (SELECT COALESCE(SUM(`quantity`), 0) FROM `Inventory` WHERE `item_id` = 1) AS `inventory_gold`,
+       (SELECT COALESCE(SUM(`gold_bank`), 0) FROM `Character`) AS `bank_gold`,
+       (SELECT COALESCE(SUM(`bank`), 0) FROM `Guild`) AS `guild_gold`;
@@ -0,0 +1,13 @@
+SELECT `amount`,
+       `source`,
+       `destination`,
+       `map`,
+       `x`,
+       `y`,
+       `created_at`
+FROM `ItemLedger`
+WHERE `item_id` = :item_id
+  AND (:name IS NULL
+   OR `source` = :name
+   OR `destination` = :name)
+ORDER BY `id` DESC LIMIT :limit;
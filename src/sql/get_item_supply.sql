@@ -0,0 +1,7 @@
+SELECT `item_id`, SUM(`quantity`) AS `amount`
+FROM (
+  SELECT `item_id`, `quantity` FROM `Inventory`
+  UNION ALL
+  SELECT `item_id`, `quantity` FROM `Bank`
+) AS `Items`
+GROUP BY `item_id`;
@@ -0,0 +1,3 @@
+SELECT `uid`, `item_id`, `amount`, `x`, `y`, `owner`, `protected_ticks`, `dropped_at`
+FROM `MapItem`
+WHERE `map` = :map_id;
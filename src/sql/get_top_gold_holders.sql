@@ -0,0 +1,7 @@
+SELECT `Character`.`name`,
+       COALESCE(`Inventory`.`quantity`, 0) + `Character`.`gold_bank` AS `gold`
+FROM `Character`
+LEFT JOIN `Inventory`
+  ON `Inventory`.`character_id` = `Character`.`id`
+ AND `Inventory`.`item_id` = 1
+ORDER BY `gold` DESC LIMIT :limit;
@@ -0,0 +1,4 @@
+SELECT `scope`
+FROM `Ban`
+WHERE (`ip` = :target AND `scope` IN ('ip', 'all'))
+   OR `account_id` IN (SELECT `id` FROM `Account` WHERE `name` = :target);
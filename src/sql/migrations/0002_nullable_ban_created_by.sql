@@ -0,0 +1,2 @@
+-- Bans issued from the console or admin API have no creating character
+ALTER TABLE `Ban` MODIFY `created_by` int DEFAULT NULL;
@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS `AdminLog` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `admin_name` varchar(32) NOT NULL,
+  `command` varchar(32) NOT NULL,
+  `args` varchar(255) NOT NULL DEFAULT '',
+  `target` varchar(32) DEFAULT NULL,
+  `map` int DEFAULT NULL,
+  `x` int DEFAULT NULL,
+  `y` int DEFAULT NULL,
+  `created_at` datetime NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  KEY `admin_name_idx` (`admin_name`),
+  KEY `target_idx` (`target`)
+);
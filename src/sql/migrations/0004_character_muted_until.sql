@@ -0,0 +1,10 @@
+-- Databases created from an older db-init script already have this column
+SET @add_muted_until = IF(
+  (SELECT COUNT(*) FROM information_schema.COLUMNS
+    WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'Character' AND COLUMN_NAME = 'muted_until') = 0,
+  'ALTER TABLE `Character` ADD `muted_until` datetime DEFAULT NULL AFTER `guild_rank_string`',
+  'DO 0'
+);
+PREPARE add_muted_until FROM @add_muted_until;
+EXECUTE add_muted_until;
+DEALLOCATE PREPARE add_muted_until;
@@ -0,0 +1,37 @@
+ALTER TABLE `Account`
+  MODIFY `register_ip` varchar(45) NOT NULL,
+  MODIFY `last_login_ip` varchar(45) DEFAULT NULL;
+
+ALTER TABLE `Ban` MODIFY `ip` varchar(45) NOT NULL;
+
+-- Databases created from an older db-init script already have the hdid
+-- column and both keys
+SET @add_hdid = IF(
+  (SELECT COUNT(*) FROM information_schema.COLUMNS
+    WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'Ban' AND COLUMN_NAME = 'hdid') = 0,
+  'ALTER TABLE `Ban` ADD `hdid` int unsigned DEFAULT NULL AFTER `ip`',
+  'DO 0'
+);
+PREPARE add_hdid FROM @add_hdid;
+EXECUTE add_hdid;
+DEALLOCATE PREPARE add_hdid;
+
+SET @add_ip_idx = IF(
+  (SELECT COUNT(*) FROM information_schema.STATISTICS
+    WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'Ban' AND INDEX_NAME = 'ip_idx') = 0,
+  'ALTER TABLE `Ban` ADD KEY `ip_idx` (`ip`)',
+  'DO 0'
+);
+PREPARE add_ip_idx FROM @add_ip_idx;
+EXECUTE add_ip_idx;
+DEALLOCATE PREPARE add_ip_idx;
+
+SET @add_hdid_idx = IF(
+  (SELECT COUNT(*) FROM information_schema.STATISTICS
+    WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = 'Ban' AND INDEX_NAME = 'hdid_idx') = 0,
+  'ALTER TABLE `Ban` ADD KEY `hdid_idx` (`hdid`)',
+  'DO 0'
+);
+PREPARE add_hdid_idx FROM @add_hdid_idx;
+EXECUTE add_hdid_idx;
+DEALLOCATE PREPARE add_hdid_idx;
@@ -0,0 +1,2 @@
+-- Guild details are now loaded with plain queries that work on every driver
+DROP PROCEDURE IF EXISTS `GetGuildDetails`;
@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS `MapItem` (
+  `map` int NOT NULL,
+  `uid` int NOT NULL,
+  `item_id` int NOT NULL,
+  `amount` int NOT NULL,
+  `x` int NOT NULL,
+  `y` int NOT NULL,
+  `owner` int NOT NULL DEFAULT 0,
+  `protected_ticks` int NOT NULL DEFAULT 0,
+  `dropped_at` datetime NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (`map`, `uid`)
+);
+
+CREATE TABLE IF NOT EXISTS `ChestItem` (
+  `map` int NOT NULL,
+  `x` int NOT NULL,
+  `y` int NOT NULL,
+  `index` int NOT NULL,
+  `slot` int NOT NULL,
+  `item_id` int NOT NULL,
+  `amount` int NOT NULL,
+  PRIMARY KEY (`map`, `x`, `y`, `index`)
+);
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS `ItemLedger` (
+  `id` bigint NOT NULL AUTO_INCREMENT,
+  `item_id` int NOT NULL,
+  `amount` int NOT NULL,
+  `source` varchar(32) NOT NULL,
+  `destination` varchar(32) NOT NULL,
+  `map` int NOT NULL,
+  `x` int NOT NULL,
+  `y` int NOT NULL,
+  `created_at` datetime NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  KEY `item_id_idx` (`item_id`),
+  KEY `source_idx` (`source`),
+  KEY `destination_idx` (`destination`)
+);
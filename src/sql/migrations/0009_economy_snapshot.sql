@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS `EconomySnapshot` (
+  `id` int NOT NULL AUTO_INCREMENT,
+  `inventory_gold` bigint NOT NULL,
+  `bank_gold` bigint NOT NULL,
+  `guild_gold` bigint NOT NULL,
+  `created_at` datetime NOT NULL,
+  PRIMARY KEY (`id`),
+  KEY `created_at_idx` (`created_at`)
+);
+
+CREATE TABLE IF NOT EXISTS `EconomySnapshotItem` (
+  `snapshot_id` int NOT NULL,
+  `item_id` int NOT NULL,
+  `amount` bigint NOT NULL,
+  PRIMARY KEY (`snapshot_id`, `item_id`),
+  CONSTRAINT `economy_snapshot_item_snapshot_id` FOREIGN KEY (`snapshot_id`) REFERENCES `EconomySnapshot` (`id`) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+-- Experience is stored as a 64-bit number so high level servers don't overflow
+ALTER TABLE `Stats` MODIFY `experience` bigint NOT NULL DEFAULT '0';
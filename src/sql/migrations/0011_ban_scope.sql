@@ -0,0 +1,4 @@
+-- Bans only match the account unless an IP or hardware ban is asked for.
+-- Existing bans keep matching the IP and hardware ID they always did.
+ALTER TABLE `Ban` ADD `scope` varchar(8) NOT NULL DEFAULT 'account' AFTER `hdid`;
+UPDATE `Ban` SET `scope` = 'all';
@@ -0,0 +1 @@
+-- Ban.created_by is already nullable in the SQLite baseline
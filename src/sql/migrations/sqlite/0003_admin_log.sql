@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS `AdminLog` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `admin_name` TEXT NOT NULL,
+  `command` TEXT NOT NULL,
+  `args` TEXT NOT NULL DEFAULT '',
+  `target` TEXT DEFAULT NULL,
+  `map` INTEGER DEFAULT NULL,
+  `x` INTEGER DEFAULT NULL,
+  `y` INTEGER DEFAULT NULL,
+  `created_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS `admin_log_admin_name_idx` ON `AdminLog` (`admin_name`);
+CREATE INDEX IF NOT EXISTS `admin_log_target_idx` ON `AdminLog` (`target`);
@@ -0,0 +1 @@
+ALTER TABLE `Character` ADD `muted_until` TEXT DEFAULT NULL;
@@ -0,0 +1,5 @@
+-- SQLite doesn't enforce varchar lengths so IPv6 addresses already fit
+ALTER TABLE `Ban` ADD `hdid` INTEGER DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS `ban_ip_idx` ON `Ban` (`ip`);
+CREATE INDEX IF NOT EXISTS `ban_hdid_idx` ON `Ban` (`hdid`);
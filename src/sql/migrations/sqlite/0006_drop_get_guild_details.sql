@@ -0,0 +1 @@
+-- SQLite has no stored procedures, nothing to drop
@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS `MapItem` (
+  `map` INTEGER NOT NULL,
+  `uid` INTEGER NOT NULL,
+  `item_id` INTEGER NOT NULL,
+  `amount` INTEGER NOT NULL,
+  `x` INTEGER NOT NULL,
+  `y` INTEGER NOT NULL,
+  `owner` INTEGER NOT NULL DEFAULT 0,
+  `protected_ticks` INTEGER NOT NULL DEFAULT 0,
+  `dropped_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (`map`, `uid`)
+);
+
+CREATE TABLE IF NOT EXISTS `ChestItem` (
+  `map` INTEGER NOT NULL,
+  `x` INTEGER NOT NULL,
+  `y` INTEGER NOT NULL,
+  `index` INTEGER NOT NULL,
+  `slot` INTEGER NOT NULL,
+  `item_id` INTEGER NOT NULL,
+  `amount` INTEGER NOT NULL,
+  PRIMARY KEY (`map`, `x`, `y`, `index`)
+);
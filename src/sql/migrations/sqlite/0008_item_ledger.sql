@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS `ItemLedger` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `item_id` INTEGER NOT NULL,
+  `amount` INTEGER NOT NULL,
+  `source` TEXT NOT NULL,
+  `destination` TEXT NOT NULL,
+  `map` INTEGER NOT NULL,
+  `x` INTEGER NOT NULL,
+  `y` INTEGER NOT NULL,
+  `created_at` TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS `item_ledger_item_id_idx` ON `ItemLedger` (`item_id`);
+CREATE INDEX IF NOT EXISTS `item_ledger_source_idx` ON `ItemLedger` (`source`);
+CREATE INDEX IF NOT EXISTS `item_ledger_destination_idx` ON `ItemLedger` (`destination`);
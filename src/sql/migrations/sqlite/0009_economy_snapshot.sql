@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS `EconomySnapshot` (
+  `id` INTEGER PRIMARY KEY AUTOINCREMENT,
+  `inventory_gold` INTEGER NOT NULL,
+  `bank_gold` INTEGER NOT NULL,
+  `guild_gold` INTEGER NOT NULL,
+  `created_at` TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS `economy_snapshot_created_at_idx` ON `EconomySnapshot` (`created_at`);
+
+CREATE TABLE IF NOT EXISTS `EconomySnapshotItem` (
+  `snapshot_id` INTEGER NOT NULL REFERENCES `EconomySnapshot` (`id`) ON DELETE CASCADE,
+  `item_id` INTEGER NOT NULL,
+  `amount` INTEGER NOT NULL,
+  PRIMARY KEY (`snapshot_id`, `item_id`)
+);
@@ -0,0 +1 @@
+-- SQLite integers are already 64-bit, nothing to change
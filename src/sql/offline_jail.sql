@@ -1,5 +1,3 @@
-UPDATE `Position` p
-INNER JOIN `Character` c
-	ON c.`id` = p.`character_id`
-SET p.`map` = :map, p.`x` = :x, p.`y` = :y
-WHERE c.`name` = :name;
+UPDATE `Position`
+SET `map` = :map, `x` = :x, `y` = :y
+WHERE `character_id` = (SELECT `id` FROM `Character` WHERE `name` = :name);
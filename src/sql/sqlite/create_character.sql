@@ -0,0 +1,19 @@
+INSERT INTO `Character` (
+	`account_id`,
+    `name`,
+    `home`,
+    `gender`,
+    `race`,
+    `hair_style`,
+    `hair_color`,
+    `created_at`
+) VALUES (
+	:account_id,
+    :name,
+    :home,
+    :gender,
+    :race,
+    :hair_style,
+    :hair_color,
+    CURRENT_TIMESTAMP
+);
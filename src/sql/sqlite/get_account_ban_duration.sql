@@ -0,0 +1,8 @@
+SELECT IFNULL(b.`duration`, 0) 'duration', b.`created_at`
+FROM `Ban` b
+LEFT JOIN `Account` a
+  ON a.id = b.account_id
+WHERE a.name = :name
+   OR (b.ip = :ip AND b.scope IN ('ip', 'all'))
+   OR (b.hdid = :hdid AND b.scope IN ('hdid', 'all'))
+ORDER BY b.`duration` IS NOT NULL, datetime(b.`created_at`, '+' || b.`duration` || ' minutes') DESC
@@ -0,0 +1,5 @@
+SELECT IFNULL(`duration`, 0) 'duration', `created_at`
+FROM `Ban`
+WHERE (`ip` = :ip AND `scope` IN ('ip', 'all'))
+   OR (`hdid` = :hdid AND `scope` IN ('hdid', 'all'))
+ORDER BY `duration` IS NOT NULL, datetime(`created_at`, '+' || `duration` || ' minutes') DESC
@@ -14,5 +14,6 @@ SET `title` = :title,
     `guild_id` = (SELECT `id` FROM `Guild` WHERE `tag` = :guild_tag),
     `guild_rank` = :guild_rank,
     `guild_rank_string` = :guild_rank_string,
-    `updated_at` = CURRENT_TIMESTAMP()
+    `muted_until` = :muted_until,
+    `updated_at` = CURRENT_TIMESTAMP
 WHERE `id` = :character_id;
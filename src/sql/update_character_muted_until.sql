@@ -0,0 +1,3 @@
+UPDATE `Character`
+SET `muted_until` = :muted_until
+WHERE `name` = :name;
@@ -1,6 +1,4 @@
 UPDATE `GuildRank`
-INNER JOIN `Guild`
-  ON `Guild`.id = `GuildRank`.guild_id
 SET `rank` = :rank
-WHERE `tag` = :tag AND `index` = :index;
-
+WHERE `guild_id` = (SELECT `id` FROM `Guild` WHERE `tag` = :tag)
+  AND `index` = :index;
@@ -1,4 +1,4 @@
 UPDATE `Account`
 SET `last_login_ip` = :ip,
-	  `updated_at` = NOW()
+	  `updated_at` = CURRENT_TIMESTAMP
 WHERE `id` = :account_id;
@@ -0,0 +1,25 @@
+use crc::{Crc, CRC_32_CKSUM};
+use eolib::data::{decode_number, encode_number, EoSerialize, EoWriter};
+
+const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_CKSUM);
+
+/// Checksum of a pub or map file's content (everything after the type and
+/// rid) in the two-short form clients compare against their cached copy
+pub fn get_rid<T: EoSerialize>(file: &T) -> Result<[i32; 2], String> {
+    let mut writer = EoWriter::new();
+    file.serialize(&mut writer).map_err(|e| e.to_string())?;
+    let buf = writer.to_byte_array();
+
+    let mut digest = CRC32.digest();
+    digest.update(&buf[7..]);
+
+    // Kept within what encode_number accepts
+    let checksum = digest.finalize() % i32::MAX as u32;
+
+    let encoded = encode_number(checksum as i32).map_err(|e| e.to_string())?;
+
+    Ok([
+        decode_number(&encoded[0..=1]) as i32,
+        decode_number(&encoded[2..=3]) as i32,
+    ])
+}
@@ -1,21 +1,27 @@
-use crc::{Crc, CRC_32_CKSUM};
 use eolib::{
-    data::{decode_number, encode_number, EoReader, EoSerialize, EoWriter},
+    data::{EoReader, EoSerialize},
     protocol::r#pub::{Ecf, EcfRecord},
 };
-use glob::glob;
-use serde_json::Value;
 use std::{fs::File, io::Read};
 
 use bytes::Bytes;
 
 use crate::SETTINGS;
 
-use super::save_pub_file;
-
-pub const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_CKSUM);
+use super::{get_rid, load_json_records, save_pub_file};
 
 pub fn load_class_file() -> Result<Ecf, Box<dyn std::error::Error>> {
+    let ecf_file = build_class_file()?;
+
+    if SETTINGS.server.generate_pub {
+        save_pub_file(&ecf_file, "data/pub/dat001.ecf")?;
+    }
+
+    Ok(ecf_file)
+}
+
+/// Reads the pub without writing the generated file back to data/pub
+pub fn build_class_file() -> Result<Ecf, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -26,13 +32,15 @@ pub fn load_class_file() -> Result<Ecf, Box<dyn std::error::Error>> {
 fn load_json() -> Result<Ecf, Box<dyn std::error::Error>> {
     let mut ecf_file = Ecf::default();
 
-    for entry in glob("data/pub/classes/*.json")? {
-        let path = entry?;
-        let mut file = File::open(path)?;
-        let mut json = String::new();
-        file.read_to_string(&mut json)?;
+    for v in load_json_records("data/pub/classes")? {
+        let v = match v {
+            Some(v) => v,
+            None => {
+                ecf_file.classes.push(EcfRecord::default());
+                continue;
+            }
+        };
 
-        let v: Value = serde_json::from_str(&json)?;
         let record = EcfRecord {
             name: v["name"].as_str().unwrap_or_default().to_string(),
             parent_type: v["parent"].as_u64().unwrap_or(0) as i32,
@@ -54,23 +62,7 @@ fn load_json() -> Result<Ecf, Box<dyn std::error::Error>> {
 
     ecf_file.total_classes_count = ecf_file.classes.len() as i32;
 
-    let mut writer = EoWriter::new();
-    ecf_file.serialize(&mut writer).unwrap();
-    let buf = writer.to_byte_array();
-
-    let mut digest = CRC32.digest();
-    digest.update(&buf[7..]);
-
-    let checksum = digest.finalize();
-
-    let encoded = encode_number(checksum as i32).unwrap();
-
-    ecf_file.rid = [
-        decode_number(&encoded[0..=1]) as i32,
-        decode_number(&encoded[2..=3]) as i32,
-    ];
-
-    save_pub_file(&ecf_file, "data/pub/dat001.ecf")?;
+    ecf_file.rid = get_rid(&ecf_file)?;
 
     Ok(ecf_file)
 }
@@ -5,14 +5,23 @@ use eolib::{
     data::{EoReader, EoSerialize},
     protocol::r#pub::server::{DropFile, DropNpcRecord, DropRecord},
 };
-use glob::glob;
-use serde_json::Value;
 
 use crate::SETTINGS;
 
-use super::save_pub_file;
+use super::{get_json_array, load_json_records, save_pub_file};
 
 pub fn load_drop_file() -> Result<DropFile, Box<dyn std::error::Error>> {
+    let drop_file = build_drop_file()?;
+
+    if SETTINGS.server.generate_pub {
+        save_pub_file(&drop_file, "data/pub/dtd001.edf")?;
+    }
+
+    Ok(drop_file)
+}
+
+/// Reads the pub without writing the generated file back to data/pub
+pub fn build_drop_file() -> Result<DropFile, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -23,16 +32,13 @@ pub fn load_drop_file() -> Result<DropFile, Box<dyn std::error::Error>> {
 fn load_json() -> Result<DropFile, Box<dyn std::error::Error>> {
     let mut drop_file = DropFile::default();
 
-    let mut npc_id = 1;
-    for entry in glob("data/pub/npcs/*.json")? {
-        let path = entry?;
-        let mut file = File::open(path)?;
-        let mut json = String::new();
-        file.read_to_string(&mut json)?;
+    for (npc_id, v) in (1..).zip(load_json_records("data/pub/npcs")?) {
+        let v = match v {
+            Some(v) => v,
+            None => continue,
+        };
 
-        let v: Value = serde_json::from_str(&json)?;
-
-        let drops = v["drops"].as_array().unwrap();
+        let drops = get_json_array(&v, "drops", format!("data/pub/npcs {}", npc_id))?;
         if !drops.is_empty() {
             drop_file.npcs.push(DropNpcRecord {
                 npc_id,
@@ -47,11 +53,8 @@ fn load_json() -> Result<DropFile, Box<dyn std::error::Error>> {
                     .collect(),
             });
         }
-        npc_id += 1;
     }
 
-    save_pub_file(&drop_file, "data/pub/dtd001.edf")?;
-
     Ok(drop_file)
 }
 
@@ -0,0 +1,133 @@
+use std::fs;
+
+use evalexpr::{context_map, eval_float_with_context, DefaultNumericTypes, HashMapContext};
+
+use crate::formulas::Formulas;
+
+// Levels are sent to clients as a single byte
+const LEVEL_LIMIT: i32 = 252;
+
+/// Builds the total experience needed for each level from 0 to
+/// `max_level`, either from the exp formula or the table file
+pub fn load_exp_table(formulas: &Formulas) -> Result<Vec<i64>, Box<dyn std::error::Error>> {
+    if formulas.max_level < 1 || formulas.max_level > LEVEL_LIMIT {
+        return Err(format!("max_level must be between 1 and {}", LEVEL_LIMIT).into());
+    }
+
+    let len = formulas.max_level as usize + 1;
+
+    let exp_table = match formulas.exp_table {
+        Some(ref path) => {
+            let contents = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+            parse_exp_table(&contents, formulas.max_level)
+                .map_err(|e| format!("{}: {}", path, e))?
+        }
+        None => {
+            let mut exp_table = Vec::with_capacity(len);
+            for level in 0..len {
+                let context: HashMapContext<DefaultNumericTypes> = context_map! {
+                    "level" => float level as f64,
+                }?;
+
+                let exp = eval_float_with_context(&formulas.exp, &context)?;
+                exp_table.push(exp.round() as i64);
+            }
+            exp_table
+        }
+    };
+
+    if let Some(level) = exp_table.windows(2).position(|pair| pair[1] < pair[0]) {
+        return Err(format!(
+            "experience for level {} is less than level {}",
+            level + 1,
+            level
+        )
+        .into());
+    }
+
+    Ok(exp_table)
+}
+
+/// Reads one total per line, skipping blank lines and ignoring anything past
+/// `max_level`. Underscores can be used to group digits.
+fn parse_exp_table(contents: &str, max_level: i32) -> Result<Vec<i64>, String> {
+    let exp_table = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .take(max_level as usize + 1)
+        .map(|line| {
+            line.replace('_', "")
+                .parse::<i64>()
+                .map_err(|e| format!("invalid experience {}: {}", line, e))
+        })
+        .collect::<Result<Vec<i64>, String>>()?;
+
+    if exp_table.len() <= max_level as usize {
+        return Err(format!(
+            "has {} levels but max_level is {}",
+            exp_table.len(),
+            max_level
+        ));
+    }
+
+    Ok(exp_table)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formulas::Formulas;
+
+    use super::{load_exp_table, parse_exp_table, LEVEL_LIMIT};
+
+    fn formulas(exp: &str, max_level: i32) -> Formulas {
+        Formulas {
+            hp: String::new(),
+            tp: String::new(),
+            sp: String::new(),
+            max_weight: String::new(),
+            hit_rate: String::new(),
+            damage: String::new(),
+            party_exp_share: String::new(),
+            exp: exp.to_string(),
+            exp_table: None,
+            max_level,
+            classes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn max_level_must_fit_in_a_byte() {
+        assert!(load_exp_table(&formulas("level", 0)).is_err());
+        assert!(load_exp_table(&formulas("level", LEVEL_LIMIT + 1)).is_err());
+        assert_eq!(
+            load_exp_table(&formulas("level", LEVEL_LIMIT))
+                .unwrap()
+                .len(),
+            LEVEL_LIMIT as usize + 1
+        );
+    }
+
+    #[test]
+    fn decreasing_experience_is_rejected() {
+        let error = load_exp_table(&formulas("if(level == 3.0, 0.0, level * 10)", 4))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(error, "experience for level 3 is less than level 2");
+    }
+
+    #[test]
+    fn table_skips_blank_lines_and_levels_past_the_max() {
+        let exp_table = parse_exp_table("0\n10\n\n1_000\n2000\n9999\n", 3).unwrap();
+        assert_eq!(exp_table, [0, 10, 1000, 2000]);
+    }
+
+    #[test]
+    fn short_or_invalid_table_is_rejected() {
+        assert_eq!(
+            parse_exp_table("0\n10\n", 2),
+            Err("has 2 levels but max_level is 2".to_string())
+        );
+        assert!(parse_exp_table("0\nten\n20\n", 2).is_err());
+    }
+}
@@ -13,6 +13,17 @@ use crate::SETTINGS;
 use super::save_pub_file;
 
 pub fn load_inn_file() -> Result<InnFile, Box<dyn std::error::Error>> {
+    let inn_file = build_inn_file()?;
+
+    if SETTINGS.server.generate_pub {
+        save_pub_file(&inn_file, "data/pub/din001.eid")?;
+    }
+
+    Ok(inn_file)
+}
+
+/// Reads the pub without writing the generated file back to data/pub
+pub fn build_inn_file() -> Result<InnFile, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -75,8 +86,6 @@ fn load_json() -> Result<InnFile, Box<dyn std::error::Error>> {
         });
     }
 
-    save_pub_file(&inn_file, "data/pub/din001.eid")?;
-
     Ok(inn_file)
 }
 
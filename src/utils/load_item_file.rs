@@ -1,22 +1,28 @@
 use std::{fs::File, io::Read};
 
 use bytes::Bytes;
-use crc::{Crc, CRC_32_CKSUM};
 
 use eolib::{
-    data::{decode_number, encode_number, EoReader, EoSerialize, EoWriter},
+    data::{EoReader, EoSerialize},
     protocol::r#pub::{Eif, EifRecord, Element, ItemSize, ItemSpecial, ItemSubtype, ItemType},
 };
-use glob::glob;
-use serde_json::Value;
 
 use crate::SETTINGS;
 
-use super::save_pub_file;
-
-pub const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_CKSUM);
+use super::{get_rid, load_json_records, save_pub_file};
 
 pub fn load_item_file() -> Result<Eif, Box<dyn std::error::Error>> {
+    let eif_file = build_item_file()?;
+
+    if SETTINGS.server.generate_pub {
+        save_pub_file(&eif_file, "data/pub/dat001.eif")?;
+    }
+
+    Ok(eif_file)
+}
+
+/// Reads the pub without writing the generated file back to data/pub
+pub fn build_item_file() -> Result<Eif, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -27,13 +33,15 @@ pub fn load_item_file() -> Result<Eif, Box<dyn std::error::Error>> {
 fn load_json() -> Result<Eif, Box<dyn std::error::Error>> {
     let mut eif_file = Eif::default();
 
-    for entry in glob("data/pub/items/*.json")? {
-        let path = entry?;
-        let mut file = File::open(path)?;
-        let mut json = String::new();
-        file.read_to_string(&mut json)?;
+    for v in load_json_records("data/pub/items")? {
+        let v = match v {
+            Some(v) => v,
+            None => {
+                eif_file.items.push(EifRecord::default());
+                continue;
+            }
+        };
 
-        let v: Value = serde_json::from_str(&json)?;
         let record = EifRecord {
             name: v["name"].as_str().unwrap_or_default().to_string(),
             graphic_id: v["graphicId"].as_u64().unwrap_or(0) as i32,
@@ -86,23 +94,7 @@ fn load_json() -> Result<Eif, Box<dyn std::error::Error>> {
 
     eif_file.total_items_count = eif_file.items.len() as i32;
 
-    let mut writer = EoWriter::new();
-    eif_file.serialize(&mut writer).unwrap();
-    let buf = writer.to_byte_array();
-
-    let mut digest = CRC32.digest();
-    digest.update(&buf[7..]);
-
-    let checksum = digest.finalize();
-
-    let encoded = encode_number(checksum as i32).unwrap();
-
-    eif_file.rid = [
-        decode_number(&encoded[0..=1]) as i32,
-        decode_number(&encoded[2..=3]) as i32,
-    ];
-
-    save_pub_file(&eif_file, "data/pub/dat001.eif")?;
+    eif_file.rid = get_rid(&eif_file)?;
 
     Ok(eif_file)
 }
@@ -0,0 +1,181 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use glob::glob;
+use serde_json::Value;
+
+/// Loads every JSON record in a pub directory, indexed by id - 1.
+///
+/// A record's id is its `id` field, or the number its filename starts with
+/// (e.g. `00012.json` or `12_goblin.json`) when that's missing. Gaps are
+/// returned as `None` so callers can pad them with placeholder records.
+pub fn load_json_records(dir: &str) -> Result<Vec<Option<Value>>, Box<dyn std::error::Error>> {
+    let mut records: BTreeMap<usize, (PathBuf, Value)> = BTreeMap::new();
+
+    for entry in glob(&format!("{}/*.json", dir))? {
+        let path = entry?;
+        let mut file = File::open(&path)?;
+        let mut json = String::new();
+        file.read_to_string(&mut json)?;
+
+        let v: Value =
+            serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let id = match get_record_id(&path, &v) {
+            Some(id) if id > 0 => id,
+            Some(_) => return Err(format!("{}: ids start at 1", path.display()).into()),
+            None => {
+                return Err(format!(
+                    "{}: no id, add an \"id\" field or start the filename with it",
+                    path.display()
+                )
+                .into())
+            }
+        };
+
+        if let Some((other, _)) = records.get(&id) {
+            return Err(format!(
+                "{}: id {} is already used by {}",
+                path.display(),
+                id,
+                other.display()
+            )
+            .into());
+        }
+
+        records.insert(id, (path, v));
+    }
+
+    let count = match records.keys().next_back() {
+        Some(id) => *id,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut values = vec![None; count];
+    for (id, (_, v)) in records {
+        values[id - 1] = Some(v);
+    }
+
+    let missing: Vec<String> = values
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| v.is_none())
+        .map(|(index, _)| (index + 1).to_string())
+        .collect();
+
+    if !missing.is_empty() {
+        warn!(
+            "{} is missing ids {}, using placeholders",
+            dir,
+            missing.join(", ")
+        );
+    }
+
+    Ok(values)
+}
+
+/// Gets a list field from a record, treating a missing field as empty
+pub fn get_json_array<'a>(
+    v: &'a Value,
+    field: &str,
+    source: impl Display,
+) -> Result<&'a [Value], String> {
+    match &v[field] {
+        Value::Null => Ok(&[]),
+        Value::Array(values) => Ok(values),
+        _ => Err(format!("{}: \"{}\" must be a list", source, field)),
+    }
+}
+
+fn get_record_id(path: &Path, v: &Value) -> Option<usize> {
+    if let Some(id) = v.get("id") {
+        return id.as_u64().map(|id| id as usize);
+    }
+
+    let stem = path.file_stem()?.to_str()?;
+    let digits: String = stem.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use super::load_json_records;
+
+    fn write_records(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("reoserv-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for (file_name, json) in files {
+            fs::write(dir.join(file_name), json).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn gaps_are_none() {
+        let dir = write_records(
+            "json-records-gaps",
+            &[
+                ("00001.json", r#"{"name": "one"}"#),
+                ("4_four.json", r#"{"name": "four"}"#),
+                ("whatever.json", r#"{"id": 2, "name": "two"}"#),
+            ],
+        );
+
+        let records = load_json_records(dir.to_str().unwrap()).unwrap();
+        let names: Vec<Option<&str>> = records
+            .iter()
+            .map(|v| v.as_ref().map(|v| v["name"].as_str().unwrap()))
+            .collect();
+
+        assert_eq!(names, [Some("one"), Some("two"), None, Some("four")]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn id_field_beats_filename() {
+        let dir = write_records("json-records-id-field", &[("00001.json", r#"{"id": 2}"#)]);
+
+        let records = load_json_records(dir.to_str().unwrap()).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert!(records[0].is_none());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn duplicate_ids_are_rejected() {
+        let dir = write_records(
+            "json-records-duplicates",
+            &[
+                ("00003.json", r#"{"name": "a"}"#),
+                ("other.json", r#"{"id": 3, "name": "b"}"#),
+            ],
+        );
+
+        let error = load_json_records(dir.to_str().unwrap())
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("id 3 is already used"), "{}", error);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_and_zero_ids_are_rejected() {
+        let dir = write_records("json-records-no-id", &[("goblin.json", "{}")]);
+        assert!(load_json_records(dir.to_str().unwrap()).is_err());
+        fs::remove_dir_all(dir).unwrap();
+
+        let dir = write_records("json-records-zero-id", &[("00000.json", "{}")]);
+        assert!(load_json_records(dir.to_str().unwrap()).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
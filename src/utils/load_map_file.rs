@@ -0,0 +1,48 @@
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use bytes::Bytes;
+use eolib::{
+    data::{EoReader, EoSerialize, EoWriter},
+    protocol::map::Emf,
+};
+
+use super::MapJson;
+
+/// Reads a map from either an EMF or a JSON map file, returning it with the
+/// size of the EMF clients will download
+pub fn load_map_file(path: &Path) -> Result<(Emf, i32), Box<dyn Error + Send + Sync>> {
+    let buf = fs::read(path)?;
+
+    if path
+        .extension()
+        .is_some_and(|extension| extension == "json")
+    {
+        let map: MapJson = serde_json::from_slice(&buf)?;
+        let emf = map.compile()?;
+
+        let mut writer = EoWriter::new();
+        emf.serialize(&mut writer)?;
+
+        let file_size = writer.to_byte_array().len() as i32;
+        return Ok((emf, file_size));
+    }
+
+    let file_size = buf.len() as i32;
+    let reader = EoReader::new(Bytes::from(buf));
+    let emf = Emf::deserialize(&reader)?;
+
+    Ok((emf, file_size))
+}
+
+/// The file a map is loaded from. A JSON map takes precedence over an EMF with
+/// the same id.
+pub fn get_map_file_path(map_id: i32) -> Option<PathBuf> {
+    ["json", "emf"]
+        .iter()
+        .map(|extension| PathBuf::from(format!("data/maps/{:0>5}.{}", map_id, extension)))
+        .find(|path| path.exists())
+}
@@ -1,21 +1,27 @@
 use eolib::{
-    data::{decode_number, encode_number, EoReader, EoSerialize, EoWriter},
+    data::{EoReader, EoSerialize},
     protocol::r#pub::{Element, Enf, EnfRecord, NpcType},
 };
-use glob::glob;
-use serde_json::Value;
 use std::{fs::File, io::Read};
 
 use bytes::Bytes;
-use crc::{Crc, CRC_32_CKSUM};
 
 use crate::SETTINGS;
 
-use super::save_pub_file;
-
-pub const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_CKSUM);
+use super::{get_rid, load_json_records, save_pub_file};
 
 pub fn load_npc_file() -> Result<Enf, Box<dyn std::error::Error>> {
+    let enf_file = build_npc_file()?;
+
+    if SETTINGS.server.generate_pub {
+        save_pub_file(&enf_file, "data/pub/dtn001.enf")?;
+    }
+
+    Ok(enf_file)
+}
+
+/// Reads the pub without writing the generated file back to data/pub
+pub fn build_npc_file() -> Result<Enf, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -26,13 +32,15 @@ pub fn load_npc_file() -> Result<Enf, Box<dyn std::error::Error>> {
 fn load_json() -> Result<Enf, Box<dyn std::error::Error>> {
     let mut enf_file = Enf::default();
 
-    for entry in glob("data/pub/npcs/*.json")? {
-        let path = entry?;
-        let mut file = File::open(path)?;
-        let mut json = String::new();
-        file.read_to_string(&mut json)?;
+    for v in load_json_records("data/pub/npcs")? {
+        let v = match v {
+            Some(v) => v,
+            None => {
+                enf_file.npcs.push(EnfRecord::default());
+                continue;
+            }
+        };
 
-        let v: Value = serde_json::from_str(&json)?;
         let record = EnfRecord {
             name: v["name"].as_str().unwrap_or_default().to_string(),
             graphic_id: v["graphicId"].as_u64().unwrap_or(0) as i32,
@@ -66,23 +74,7 @@ fn load_json() -> Result<Enf, Box<dyn std::error::Error>> {
 
     enf_file.total_npcs_count = enf_file.npcs.len() as i32;
 
-    let mut writer = EoWriter::new();
-    enf_file.serialize(&mut writer).unwrap();
-    let buf = writer.to_byte_array();
-
-    let mut digest = CRC32.digest();
-    digest.update(&buf[7..]);
-
-    let checksum = digest.finalize();
-
-    let encoded = encode_number(checksum as i32).unwrap();
-
-    enf_file.rid = [
-        decode_number(&encoded[0..=1]) as i32,
-        decode_number(&encoded[2..=3]) as i32,
-    ];
-
-    save_pub_file(&enf_file, "data/pub/dtn001.enf")?;
+    enf_file.rid = get_rid(&enf_file)?;
 
     Ok(enf_file)
 }
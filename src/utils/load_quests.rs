@@ -9,10 +9,13 @@ pub fn load_quests() -> HashMap<i32, Quest> {
     for entry in glob("data/quests/*.eqf").unwrap() {
         match entry {
             Ok(path) => match load_quest(path) {
-                Some((id, quest)) => {
+                Ok((id, quest)) => {
                     let _ = quests.insert(id, quest);
                 }
-                None => continue,
+                Err(e) => {
+                    error!("{}", e);
+                    continue;
+                }
             },
             Err(e) => {
                 error!("Failed to parse entry: {}", e);
@@ -24,51 +27,48 @@ pub fn load_quests() -> HashMap<i32, Quest> {
     quests
 }
 
-fn load_quest(path: PathBuf) -> Option<(i32, Quest)> {
+pub fn load_quest(path: PathBuf) -> Result<(i32, Quest), String> {
     let id = match path.file_name() {
         Some(name) => match name.to_str().to_owned() {
             Some(name) => match name.split('.').collect::<Vec<&str>>()[0].parse::<i32>() {
                 Ok(id) => id,
                 Err(e) => {
-                    error!("Failed to parse id: {}", e);
-                    return None;
+                    return Err(format!("Failed to parse id of {}: {}", path.display(), e));
                 }
             },
-            None => return None,
+            None => return Err(format!("Invalid file name: {}", path.display())),
         },
-        None => return None,
+        None => return Err(format!("Invalid file name: {}", path.display())),
     };
 
-    let mut file = match File::open(path) {
+    let mut file = match File::open(&path) {
         Ok(file) => file,
         Err(e) => {
-            error!("Failed to load file: {}", e);
-            return None;
+            return Err(format!("Failed to load file {}: {}", path.display(), e));
         }
     };
 
     let length = match file.metadata() {
         Ok(metadata) => metadata.len() as usize,
         Err(e) => {
-            error!("Failed to get file metadata: {}", e);
-            return None;
+            return Err(format!(
+                "Failed to get file metadata of {}: {}",
+                path.display(),
+                e
+            ));
         }
     };
 
     let mut buf: Vec<u8> = Vec::with_capacity(length);
 
     if let Err(e) = file.read_to_end(&mut buf) {
-        error!("Failed to read file: {}", e);
-        return None;
+        return Err(format!("Failed to read file {}: {}", path.display(), e));
     }
 
     let input = String::from_utf8_lossy(&buf);
 
     match parse_quest(&input) {
-        Ok(quest) => Some((id, quest)),
-        Err(e) => {
-            error!("Failed to parse quest: {}", e);
-            None
-        }
+        Ok(quest) => Ok((id, quest)),
+        Err(e) => Err(format!("Failed to parse quest {}: {}", path.display(), e)),
     }
 }
@@ -12,9 +12,20 @@ use serde_json::Value;
 
 use crate::SETTINGS;
 
-use super::save_pub_file;
+use super::{get_json_array, save_pub_file};
 
 pub fn load_shop_file() -> Result<ShopFile, Box<dyn std::error::Error>> {
+    let shop_file = build_shop_file()?;
+
+    if SETTINGS.server.generate_pub {
+        save_pub_file(&shop_file, "data/pub/dts001.esf")?;
+    }
+
+    Ok(shop_file)
+}
+
+/// Reads the pub without writing the generated file back to data/pub
+pub fn build_shop_file() -> Result<ShopFile, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -27,14 +38,15 @@ fn load_json() -> Result<ShopFile, Box<dyn std::error::Error>> {
 
     for entry in glob("data/pub/shops/*.json")? {
         let path = entry?;
-        let mut file = File::open(path)?;
+        let mut file = File::open(&path)?;
         let mut json = String::new();
         file.read_to_string(&mut json)?;
 
-        let v: Value = serde_json::from_str(&json)?;
+        let v: Value =
+            serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))?;
 
-        let trades = v["trades"].as_array().unwrap();
-        let crafts = v["crafts"].as_array().unwrap();
+        let trades = get_json_array(&v, "trades", path.display())?;
+        let crafts = get_json_array(&v, "crafts", path.display())?;
 
         shop_file.shops.push(ShopRecord {
             behavior_id: v["behaviorId"].as_u64().unwrap_or(0) as i32,
@@ -79,8 +91,6 @@ fn load_json() -> Result<ShopFile, Box<dyn std::error::Error>> {
         });
     }
 
-    save_pub_file(&shop_file, "data/pub/dts001.esf")?;
-
     Ok(shop_file)
 }
 
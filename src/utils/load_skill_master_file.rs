@@ -10,9 +10,20 @@ use serde_json::Value;
 
 use crate::SETTINGS;
 
-use super::save_pub_file;
+use super::{get_json_array, save_pub_file};
 
 pub fn load_skill_master_file() -> Result<SkillMasterFile, Box<dyn std::error::Error>> {
+    let skill_master_file = build_skill_master_file()?;
+
+    if SETTINGS.server.generate_pub {
+        save_pub_file(&skill_master_file, "data/pub/dsm001.emf")?;
+    }
+
+    Ok(skill_master_file)
+}
+
+/// Reads the pub without writing the generated file back to data/pub
+pub fn build_skill_master_file() -> Result<SkillMasterFile, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -25,13 +36,14 @@ fn load_json() -> Result<SkillMasterFile, Box<dyn std::error::Error>> {
 
     for entry in glob("data/pub/skill_masters/*.json")? {
         let path = entry?;
-        let mut file = File::open(path)?;
+        let mut file = File::open(&path)?;
         let mut json = String::new();
         file.read_to_string(&mut json)?;
 
-        let v: Value = serde_json::from_str(&json)?;
+        let v: Value =
+            serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))?;
 
-        let skills = v["skills"].as_array().unwrap();
+        let skills = get_json_array(&v, "skills", path.display())?;
 
         skill_master_file.skill_masters.push(SkillMasterRecord {
             behavior_id: v["behaviorId"].as_u64().unwrap_or(0) as i32,
@@ -71,8 +83,6 @@ fn load_json() -> Result<SkillMasterFile, Box<dyn std::error::Error>> {
         });
     }
 
-    save_pub_file(&skill_master_file, "data/pub/dsm001.emf")?;
-
     Ok(skill_master_file)
 }
 
@@ -1,12 +1,9 @@
-use crc::{Crc, CRC_32_CKSUM};
 use eolib::{
-    data::{decode_number, encode_number, EoReader, EoSerialize, EoWriter},
+    data::{EoReader, EoSerialize},
     protocol::r#pub::{
         Element, Esf, EsfRecord, SkillNature, SkillTargetRestrict, SkillTargetType, SkillType,
     },
 };
-use glob::glob;
-use serde_json::Value;
 
 use std::{fs::File, io::Read};
 
@@ -14,11 +11,20 @@ use bytes::Bytes;
 
 use crate::SETTINGS;
 
-use super::save_pub_file;
-
-pub const CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_CKSUM);
+use super::{get_rid, load_json_records, save_pub_file};
 
 pub fn load_spell_file() -> Result<Esf, Box<dyn std::error::Error>> {
+    let esf_file = build_spell_file()?;
+
+    if SETTINGS.server.generate_pub {
+        save_pub_file(&esf_file, "data/pub/dsl001.esf")?;
+    }
+
+    Ok(esf_file)
+}
+
+/// Reads the pub without writing the generated file back to data/pub
+pub fn build_spell_file() -> Result<Esf, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -29,13 +35,15 @@ pub fn load_spell_file() -> Result<Esf, Box<dyn std::error::Error>> {
 fn load_json() -> Result<Esf, Box<dyn std::error::Error>> {
     let mut esf_file = Esf::default();
 
-    for entry in glob("data/pub/spells/*.json")? {
-        let path = entry?;
-        let mut file = File::open(path)?;
-        let mut json = String::new();
-        file.read_to_string(&mut json)?;
+    for v in load_json_records("data/pub/spells")? {
+        let v = match v {
+            Some(v) => v,
+            None => {
+                esf_file.skills.push(EsfRecord::default());
+                continue;
+            }
+        };
 
-        let v: Value = serde_json::from_str(&json)?;
         let record = EsfRecord {
             name: v["name"].as_str().unwrap_or_default().to_string(),
             chant: v["shout"].as_str().unwrap_or_default().to_string(),
@@ -80,23 +88,7 @@ fn load_json() -> Result<Esf, Box<dyn std::error::Error>> {
 
     esf_file.total_skills_count = esf_file.skills.len() as i32;
 
-    let mut writer = EoWriter::new();
-    esf_file.serialize(&mut writer).unwrap();
-    let buf = writer.to_byte_array();
-
-    let mut digest = CRC32.digest();
-    digest.update(&buf[7..]);
-
-    let checksum = digest.finalize();
-
-    let encoded = encode_number(checksum as i32).unwrap();
-
-    esf_file.rid = [
-        decode_number(&encoded[0..=1]) as i32,
-        decode_number(&encoded[2..=3]) as i32,
-    ];
-
-    save_pub_file(&esf_file, "data/pub/dsl001.esf")?;
+    esf_file.rid = get_rid(&esf_file)?;
 
     Ok(esf_file)
 }
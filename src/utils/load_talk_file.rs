@@ -5,14 +5,23 @@ use eolib::{
     data::{EoReader, EoSerialize},
     protocol::r#pub::server::{TalkFile, TalkMessageRecord, TalkRecord},
 };
-use glob::glob;
-use serde_json::Value;
 
 use crate::SETTINGS;
 
-use super::save_pub_file;
+use super::{get_json_array, load_json_records, save_pub_file};
 
 pub fn load_talk_file() -> Result<TalkFile, Box<dyn std::error::Error>> {
+    let talk_file = build_talk_file()?;
+
+    if SETTINGS.server.generate_pub {
+        save_pub_file(&talk_file, "data/pub/ttd001.etf")?;
+    }
+
+    Ok(talk_file)
+}
+
+/// Reads the pub without writing the generated file back to data/pub
+pub fn build_talk_file() -> Result<TalkFile, Box<dyn std::error::Error>> {
     if SETTINGS.server.generate_pub {
         load_json()
     } else {
@@ -23,16 +32,13 @@ pub fn load_talk_file() -> Result<TalkFile, Box<dyn std::error::Error>> {
 fn load_json() -> Result<TalkFile, Box<dyn std::error::Error>> {
     let mut talk_file = TalkFile::default();
 
-    let mut npc_id = 1;
-    for entry in glob("data/pub/npcs/*.json")? {
-        let path = entry?;
-        let mut file = File::open(path)?;
-        let mut json = String::new();
-        file.read_to_string(&mut json)?;
-
-        let v: Value = serde_json::from_str(&json)?;
+    for (npc_id, v) in (1..).zip(load_json_records("data/pub/npcs")?) {
+        let v = match v {
+            Some(v) => v,
+            None => continue,
+        };
 
-        let messages = v["talkMessages"].as_array().unwrap();
+        let messages = get_json_array(&v, "talkMessages", format!("data/pub/npcs {}", npc_id))?;
         if !messages.is_empty() {
             talk_file.npcs.push(TalkRecord {
                 npc_id,
@@ -45,12 +51,8 @@ fn load_json() -> Result<TalkFile, Box<dyn std::error::Error>> {
                     .collect(),
             });
         }
-
-        npc_id += 1;
     }
 
-    save_pub_file(&talk_file, "data/pub/ttd001.etf")?;
-
     Ok(talk_file)
 }
 
@@ -0,0 +1,553 @@
+use std::collections::BTreeMap;
+
+use eolib::protocol::{
+    map::{
+        Emf, MapGraphicLayer, MapGraphicRow, MapGraphicRowTile, MapItem, MapLegacyDoorKey,
+        MapMusicControl, MapNpc, MapSign, MapTileSpec, MapTileSpecRow, MapTileSpecRowTile,
+        MapTimedEffect, MapType, MapWarp, MapWarpRow, MapWarpRowTile,
+    },
+    Coords,
+};
+
+use super::get_rid;
+
+/// A map in a format that can be edited by hand and diffed in git. Tiles are
+/// listed flat with their own coordinates instead of the row groups used by
+/// EMF files.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapJson {
+    pub name: String,
+    pub r#type: MapType,
+    pub timed_effect: MapTimedEffect,
+    pub music_id: i32,
+    pub music_control: MapMusicControl,
+    pub ambient_sound_id: i32,
+    pub width: i32,
+    pub height: i32,
+    pub fill_tile: i32,
+    pub map_available: bool,
+    pub can_scroll: bool,
+    pub relog_x: i32,
+    pub relog_y: i32,
+    #[serde(default)]
+    pub npcs: Vec<MapJsonNpc>,
+    #[serde(default)]
+    pub door_keys: Vec<MapJsonDoorKey>,
+    #[serde(default)]
+    pub chests: Vec<MapJsonChest>,
+    #[serde(default)]
+    pub tile_specs: Vec<MapJsonTileSpec>,
+    #[serde(default)]
+    pub warps: Vec<MapJsonWarp>,
+    #[serde(default)]
+    pub graphics: MapJsonGraphics,
+    #[serde(default)]
+    pub signs: Vec<MapJsonSign>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapJsonNpc {
+    pub x: i32,
+    pub y: i32,
+    pub id: i32,
+    pub spawn_type: i32,
+    pub spawn_time: i32,
+    pub amount: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapJsonDoorKey {
+    pub x: i32,
+    pub y: i32,
+    pub key: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapJsonChest {
+    pub x: i32,
+    pub y: i32,
+    pub key: i32,
+    pub slot: i32,
+    pub item_id: i32,
+    pub spawn_time: i32,
+    pub amount: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapJsonTileSpec {
+    pub x: i32,
+    pub y: i32,
+    pub spec: MapTileSpec,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapJsonWarp {
+    pub x: i32,
+    pub y: i32,
+    pub map: i32,
+    pub dest_x: i32,
+    pub dest_y: i32,
+    pub level_required: i32,
+    pub door: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapJsonGraphic {
+    pub x: i32,
+    pub y: i32,
+    pub graphic: i32,
+}
+
+/// The nine graphic layers of an EMF, in file order
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MapJsonGraphics {
+    pub ground: Vec<MapJsonGraphic>,
+    pub objects: Vec<MapJsonGraphic>,
+    pub overlay: Vec<MapJsonGraphic>,
+    pub down_wall: Vec<MapJsonGraphic>,
+    pub right_wall: Vec<MapJsonGraphic>,
+    pub roof: Vec<MapJsonGraphic>,
+    pub top: Vec<MapJsonGraphic>,
+    pub shadow: Vec<MapJsonGraphic>,
+    pub overlay2: Vec<MapJsonGraphic>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MapJsonSign {
+    pub x: i32,
+    pub y: i32,
+    pub title: String,
+    pub message: String,
+}
+
+impl From<&Emf> for MapJson {
+    fn from(emf: &Emf) -> Self {
+        let [ground, objects, overlay, down_wall, right_wall, roof, top, shadow, overlay2] =
+            emf.graphic_layers.each_ref().map(|layer| {
+                layer
+                    .graphic_rows
+                    .iter()
+                    .flat_map(|row| {
+                        row.tiles.iter().map(|tile| MapJsonGraphic {
+                            x: tile.x,
+                            y: row.y,
+                            graphic: tile.graphic,
+                        })
+                    })
+                    .collect()
+            });
+
+        Self {
+            name: emf.name.clone(),
+            r#type: emf.r#type,
+            timed_effect: emf.timed_effect,
+            music_id: emf.music_id,
+            music_control: emf.music_control,
+            ambient_sound_id: emf.ambient_sound_id,
+            width: emf.width,
+            height: emf.height,
+            fill_tile: emf.fill_tile,
+            map_available: emf.map_available,
+            can_scroll: emf.can_scroll,
+            relog_x: emf.relog_x,
+            relog_y: emf.relog_y,
+            npcs: emf
+                .npcs
+                .iter()
+                .map(|npc| MapJsonNpc {
+                    x: npc.coords.x,
+                    y: npc.coords.y,
+                    id: npc.id,
+                    spawn_type: npc.spawn_type,
+                    spawn_time: npc.spawn_time,
+                    amount: npc.amount,
+                })
+                .collect(),
+            door_keys: emf
+                .legacy_door_keys
+                .iter()
+                .map(|door_key| MapJsonDoorKey {
+                    x: door_key.coords.x,
+                    y: door_key.coords.y,
+                    key: door_key.key,
+                })
+                .collect(),
+            chests: emf
+                .items
+                .iter()
+                .map(|item| MapJsonChest {
+                    x: item.coords.x,
+                    y: item.coords.y,
+                    key: item.key,
+                    slot: item.chest_slot,
+                    item_id: item.item_id,
+                    spawn_time: item.spawn_time,
+                    amount: item.amount,
+                })
+                .collect(),
+            tile_specs: emf
+                .tile_spec_rows
+                .iter()
+                .flat_map(|row| {
+                    row.tiles.iter().map(|tile| MapJsonTileSpec {
+                        x: tile.x,
+                        y: row.y,
+                        spec: tile.tile_spec,
+                    })
+                })
+                .collect(),
+            warps: emf
+                .warp_rows
+                .iter()
+                .flat_map(|row| {
+                    row.tiles.iter().map(|tile| MapJsonWarp {
+                        x: tile.x,
+                        y: row.y,
+                        map: tile.warp.destination_map,
+                        dest_x: tile.warp.destination_coords.x,
+                        dest_y: tile.warp.destination_coords.y,
+                        level_required: tile.warp.level_required,
+                        door: tile.warp.door,
+                    })
+                })
+                .collect(),
+            graphics: MapJsonGraphics {
+                ground,
+                objects,
+                overlay,
+                down_wall,
+                right_wall,
+                roof,
+                top,
+                shadow,
+                overlay2,
+            },
+            signs: emf
+                .signs
+                .iter()
+                .map(|sign| {
+                    let title_length = sign.title_length.max(0) as usize;
+                    MapJsonSign {
+                        x: sign.coords.x,
+                        y: sign.coords.y,
+                        title: sign.string_data.chars().take(title_length).collect(),
+                        message: sign.string_data.chars().skip(title_length).collect(),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl MapJson {
+    /// Builds the EMF sent to clients. Tiles are grouped back into rows sorted
+    /// by y then x, and the rid is a checksum of the content so clients pick
+    /// up any edit.
+    pub fn compile(self) -> Result<Emf, String> {
+        if self.name.chars().count() > 24 {
+            return Err(format!(
+                "name \"{}\" is longer than 24 characters",
+                self.name
+            ));
+        }
+
+        let layers = [
+            self.graphics.ground,
+            self.graphics.objects,
+            self.graphics.overlay,
+            self.graphics.down_wall,
+            self.graphics.right_wall,
+            self.graphics.roof,
+            self.graphics.top,
+            self.graphics.shadow,
+            self.graphics.overlay2,
+        ];
+
+        let mut emf = Emf {
+            rid: [0, 0],
+            name: self.name,
+            r#type: self.r#type,
+            timed_effect: self.timed_effect,
+            music_id: self.music_id,
+            music_control: self.music_control,
+            ambient_sound_id: self.ambient_sound_id,
+            width: self.width,
+            height: self.height,
+            fill_tile: self.fill_tile,
+            map_available: self.map_available,
+            can_scroll: self.can_scroll,
+            relog_x: self.relog_x,
+            relog_y: self.relog_y,
+            npcs: self
+                .npcs
+                .into_iter()
+                .map(|npc| MapNpc {
+                    coords: Coords { x: npc.x, y: npc.y },
+                    id: npc.id,
+                    spawn_type: npc.spawn_type,
+                    spawn_time: npc.spawn_time,
+                    amount: npc.amount,
+                })
+                .collect(),
+            legacy_door_keys: self
+                .door_keys
+                .into_iter()
+                .map(|door_key| MapLegacyDoorKey {
+                    coords: Coords {
+                        x: door_key.x,
+                        y: door_key.y,
+                    },
+                    key: door_key.key,
+                })
+                .collect(),
+            items: self
+                .chests
+                .into_iter()
+                .map(|chest| MapItem {
+                    coords: Coords {
+                        x: chest.x,
+                        y: chest.y,
+                    },
+                    key: chest.key,
+                    chest_slot: chest.slot,
+                    item_id: chest.item_id,
+                    spawn_time: chest.spawn_time,
+                    amount: chest.amount,
+                })
+                .collect(),
+            tile_spec_rows: group_rows(
+                self.tile_specs
+                    .into_iter()
+                    .map(|tile| (tile.x, tile.y, tile.spec)),
+            )
+            .into_iter()
+            .map(|(y, tiles)| MapTileSpecRow {
+                y,
+                tiles: tiles
+                    .into_iter()
+                    .map(|(x, tile_spec)| MapTileSpecRowTile { x, tile_spec })
+                    .collect(),
+            })
+            .collect(),
+            warp_rows: group_rows(self.warps.into_iter().map(|warp| {
+                (
+                    warp.x,
+                    warp.y,
+                    MapWarp {
+                        destination_map: warp.map,
+                        destination_coords: Coords {
+                            x: warp.dest_x,
+                            y: warp.dest_y,
+                        },
+                        level_required: warp.level_required,
+                        door: warp.door,
+                    },
+                )
+            }))
+            .into_iter()
+            .map(|(y, tiles)| MapWarpRow {
+                y,
+                tiles: tiles
+                    .into_iter()
+                    .map(|(x, warp)| MapWarpRowTile { x, warp })
+                    .collect(),
+            })
+            .collect(),
+            graphic_layers: layers.map(|layer| MapGraphicLayer {
+                graphic_rows: group_rows(
+                    layer.into_iter().map(|tile| (tile.x, tile.y, tile.graphic)),
+                )
+                .into_iter()
+                .map(|(y, tiles)| MapGraphicRow {
+                    y,
+                    tiles: tiles
+                        .into_iter()
+                        .map(|(x, graphic)| MapGraphicRowTile { x, graphic })
+                        .collect(),
+                })
+                .collect(),
+            }),
+            signs: self
+                .signs
+                .into_iter()
+                .map(|sign| MapSign {
+                    coords: Coords {
+                        x: sign.x,
+                        y: sign.y,
+                    },
+                    title_length: sign.title.chars().count() as i32,
+                    string_data: format!("{}{}", sign.title, sign.message),
+                })
+                .collect(),
+        };
+
+        emf.rid = get_rid(&emf)?;
+
+        Ok(emf)
+    }
+}
+
+fn group_rows<T>(tiles: impl Iterator<Item = (i32, i32, T)>) -> BTreeMap<i32, Vec<(i32, T)>> {
+    let mut rows: BTreeMap<i32, Vec<(i32, T)>> = BTreeMap::new();
+    for (x, y, tile) in tiles {
+        rows.entry(y).or_default().push((x, tile));
+    }
+
+    for tiles in rows.values_mut() {
+        tiles.sort_by_key(|(x, _)| *x);
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use eolib::{
+        data::{EoSerialize, EoWriter},
+        protocol::{
+            map::{
+                Emf, MapGraphicLayer, MapGraphicRow, MapGraphicRowTile, MapItem, MapLegacyDoorKey,
+                MapNpc, MapSign, MapTileSpec, MapTileSpecRow, MapTileSpecRowTile, MapWarp,
+                MapWarpRow, MapWarpRowTile,
+            },
+            Coords,
+        },
+    };
+
+    use super::MapJson;
+
+    fn serialize(emf: &Emf) -> Vec<u8> {
+        let mut writer = EoWriter::new();
+        emf.serialize(&mut writer).unwrap();
+        writer.to_byte_array().to_vec()
+    }
+
+    fn graphic_layer(rows: &[(i32, &[(i32, i32)])]) -> MapGraphicLayer {
+        MapGraphicLayer {
+            graphic_rows: rows
+                .iter()
+                .map(|(y, tiles)| MapGraphicRow {
+                    y: *y,
+                    tiles: tiles
+                        .iter()
+                        .map(|(x, graphic)| MapGraphicRowTile {
+                            x: *x,
+                            graphic: *graphic,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    fn emf() -> Emf {
+        let mut graphic_layers: [MapGraphicLayer; 9] = Default::default();
+        graphic_layers[0] = graphic_layer(&[(0, &[(0, 101), (3, 102)]), (2, &[(1, 103)])]);
+        graphic_layers[8] = graphic_layer(&[(4, &[(4, 900)])]);
+
+        Emf {
+            rid: [1, 2],
+            name: "Test Map".to_string(),
+            width: 10,
+            height: 8,
+            fill_tile: 7,
+            map_available: true,
+            relog_x: 2,
+            relog_y: 3,
+            npcs: vec![MapNpc {
+                coords: Coords { x: 4, y: 5 },
+                id: 12,
+                spawn_type: 7,
+                spawn_time: 30,
+                amount: 2,
+            }],
+            legacy_door_keys: vec![MapLegacyDoorKey {
+                coords: Coords { x: 1, y: 1 },
+                key: 3,
+            }],
+            items: vec![MapItem {
+                coords: Coords { x: 6, y: 6 },
+                key: 0,
+                chest_slot: 1,
+                item_id: 40,
+                spawn_time: 60,
+                amount: 5,
+            }],
+            tile_spec_rows: vec![
+                MapTileSpecRow {
+                    y: 1,
+                    tiles: vec![
+                        MapTileSpecRowTile {
+                            x: 0,
+                            tile_spec: MapTileSpec::Wall,
+                        },
+                        MapTileSpecRowTile {
+                            x: 6,
+                            tile_spec: MapTileSpec::Chest,
+                        },
+                    ],
+                },
+                MapTileSpecRow {
+                    y: 7,
+                    tiles: vec![MapTileSpecRowTile {
+                        x: 2,
+                        tile_spec: MapTileSpec::Jukebox,
+                    }],
+                },
+            ],
+            warp_rows: vec![MapWarpRow {
+                y: 3,
+                tiles: vec![MapWarpRowTile {
+                    x: 9,
+                    warp: MapWarp {
+                        destination_map: 5,
+                        destination_coords: Coords { x: 10, y: 11 },
+                        level_required: 3,
+                        door: 1,
+                    },
+                }],
+            }],
+            graphic_layers,
+            signs: vec![MapSign {
+                coords: Coords { x: 2, y: 2 },
+                string_data: "WelcomeHello there".to_string(),
+                title_length: 7,
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn emf_survives_a_json_round_trip() {
+        let emf = emf();
+
+        let json = serde_json::to_string_pretty(&MapJson::from(&emf)).unwrap();
+        let compiled = serde_json::from_str::<MapJson>(&json)
+            .unwrap()
+            .compile()
+            .unwrap();
+
+        // Only the rid, a checksum of the content, is allowed to change
+        assert_eq!(serialize(&emf)[7..], serialize(&compiled)[7..]);
+        assert_ne!(compiled.rid, [0, 0]);
+    }
+
+    #[test]
+    fn unsorted_tiles_compile_into_sorted_rows() {
+        let mut map = MapJson::from(&emf());
+        map.tile_specs.reverse();
+        map.graphics.ground.reverse();
+
+        let compiled = map.compile().unwrap();
+        assert_eq!(serialize(&emf())[7..], serialize(&compiled)[7..]);
+    }
+}
@@ -12,32 +12,44 @@ mod format_duration;
 pub use format_duration::format_duration;
 mod get_board_tile_spec;
 pub use get_board_tile_spec::get_board_tile_spec;
+mod get_rid;
+pub use get_rid::get_rid;
 mod get_next_coords;
 pub use get_next_coords::get_next_coords;
 mod is_deep;
 pub use is_deep::is_deep;
 mod load_class_file;
-pub use load_class_file::load_class_file;
+pub use load_class_file::{build_class_file, load_class_file};
 mod load_drop_file;
-pub use load_drop_file::load_drop_file;
+pub use load_drop_file::{build_drop_file, load_drop_file};
+mod load_exp_table;
+pub use load_exp_table::load_exp_table;
 mod load_inn_file;
-pub use load_inn_file::load_inn_file;
+pub use load_inn_file::{build_inn_file, load_inn_file};
+mod load_json_records;
+pub use load_json_records::{get_json_array, load_json_records};
 mod load_item_file;
-pub use load_item_file::load_item_file;
+pub use load_item_file::{build_item_file, load_item_file};
+mod load_map_file;
+pub use load_map_file::{get_map_file_path, load_map_file};
 mod load_npc_file;
-pub use load_npc_file::load_npc_file;
+pub use load_npc_file::{build_npc_file, load_npc_file};
 mod load_shop_file;
-pub use load_shop_file::load_shop_file;
+pub use load_shop_file::{build_shop_file, load_shop_file};
 mod load_skill_master_file;
-pub use load_skill_master_file::load_skill_master_file;
+pub use load_skill_master_file::{build_skill_master_file, load_skill_master_file};
 mod load_spell_file;
-pub use load_spell_file::load_spell_file;
+pub use load_spell_file::{build_spell_file, load_spell_file};
 mod load_talk_file;
-pub use load_talk_file::load_talk_file;
+pub use load_talk_file::{build_talk_file, load_talk_file};
 mod save_pub_file;
 pub use save_pub_file::save_pub_file;
+mod split_pub_file;
+pub use split_pub_file::SplitPubFile;
+mod map_json;
+pub use map_json::MapJson;
 mod load_quests;
-pub use load_quests::load_quests;
+pub use load_quests::{load_quest, load_quests};
 mod pad_string;
 pub use pad_string::pad_string;
 mod validate_character_name;
@@ -48,3 +60,5 @@ mod mask_email;
 pub use mask_email::mask_email;
 mod timestamp_diff;
 pub use timestamp_diff::timestamp_diff;
+mod reloadable;
+pub use reloadable::Reloadable;
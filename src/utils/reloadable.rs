@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use arc_swap::{ArcSwap, Guard};
+
+/// Global data that can be swapped out at runtime with `$reload`.
+///
+/// Readers take a snapshot with `load`, which stays valid (and unchanged)
+/// for as long as it is held even if a reload happens in the meantime.
+/// The old value is freed once the last snapshot of it is dropped.
+pub struct Reloadable<T> {
+    inner: ArcSwap<T>,
+}
+
+impl<T> Reloadable<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: ArcSwap::from_pointee(value),
+        }
+    }
+
+    pub fn load(&self) -> Guard<Arc<T>> {
+        self.inner.load()
+    }
+
+    pub fn store(&self, value: T) {
+        self.inner.store(Arc::new(value));
+    }
+}
@@ -0,0 +1,155 @@
+use eolib::{
+    data::{EoSerialize, EoWriter},
+    protocol::r#pub::{Ecf, Eif, Enf, Esf},
+};
+
+// "EIF" + rid + total count + version
+const HEADER_SIZE: usize = 10;
+
+/// Pub files that can be sent to clients in numbered parts
+pub trait SplitPubFile: Sized {
+    /// Splits the pub into files of at most `max_size` bytes. Every part keeps
+    /// the total record count so clients know to ask for the next file.
+    ///
+    /// The welcome reply only has room for one rid per pub, which clients
+    /// compare against their first cached file, so every part carries the
+    /// whole pub's rid. A change to any record then makes clients download
+    /// every part again. A pub that fits in one file is returned unchanged.
+    fn split(&self, max_size: usize) -> Vec<Self>;
+}
+
+macro_rules! impl_split_pub_file {
+    ($pub:ident, $records:ident, $total:ident) => {
+        impl SplitPubFile for $pub {
+            fn split(&self, max_size: usize) -> Vec<Self> {
+                let chunks = chunk_records(&self.$records, max_size);
+                if chunks.len() <= 1 {
+                    return vec![self.clone()];
+                }
+
+                chunks
+                    .into_iter()
+                    .map(|records| $pub {
+                        rid: self.rid,
+                        $total: self.$total,
+                        version: self.version,
+                        $records: records,
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+impl_split_pub_file!(Eif, items, total_items_count);
+impl_split_pub_file!(Enf, npcs, total_npcs_count);
+impl_split_pub_file!(Esf, skills, total_skills_count);
+impl_split_pub_file!(Ecf, classes, total_classes_count);
+
+fn chunk_records<T: EoSerialize + Clone>(records: &[T], max_size: usize) -> Vec<Vec<T>> {
+    if max_size == 0 {
+        return vec![records.to_vec()];
+    }
+
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    let mut size = HEADER_SIZE;
+
+    for record in records {
+        let mut writer = EoWriter::new();
+        record.serialize(&mut writer).unwrap();
+        let record_size = writer.to_byte_array().len();
+
+        // A record bigger than the limit still gets a file to itself
+        if !chunk.is_empty() && size + record_size > max_size {
+            chunks.push(std::mem::take(&mut chunk));
+            size = HEADER_SIZE;
+        }
+
+        chunk.push(record.clone());
+        size += record_size;
+    }
+
+    chunks.push(chunk);
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use eolib::{
+        data::{EoSerialize, EoWriter},
+        protocol::r#pub::{Eif, EifRecord},
+    };
+
+    use super::{chunk_records, SplitPubFile, HEADER_SIZE};
+
+    fn record(name: &str) -> EifRecord {
+        EifRecord {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn record_size(record: &EifRecord) -> usize {
+        let mut writer = EoWriter::new();
+        record.serialize(&mut writer).unwrap();
+        writer.to_byte_array().len()
+    }
+
+    #[test]
+    fn records_that_fill_a_file_exactly_stay_together() {
+        let records = vec![record("a"), record("b"), record("c")];
+        let size = record_size(&records[0]);
+
+        let chunks = chunk_records(&records, HEADER_SIZE + size * 2);
+        assert_eq!(chunks, [records[..2].to_vec(), records[2..].to_vec()]);
+
+        let chunks = chunk_records(&records, HEADER_SIZE + size * 2 - 1);
+        assert_eq!(
+            chunks,
+            [
+                records[..1].to_vec(),
+                records[1..2].to_vec(),
+                records[2..].to_vec()
+            ]
+        );
+    }
+
+    #[test]
+    fn oversized_record_gets_its_own_file() {
+        let records = vec![record("a"), record(&"b".repeat(50)), record("c")];
+        let size = record_size(&records[0]);
+
+        let chunks = chunk_records(&records, HEADER_SIZE + size * 2);
+        assert_eq!(
+            chunks,
+            [
+                records[..1].to_vec(),
+                records[1..2].to_vec(),
+                records[2..].to_vec()
+            ]
+        );
+    }
+
+    #[test]
+    fn split_parts_share_rid_and_total() {
+        let eif = Eif {
+            rid: [123, 456],
+            total_items_count: 4,
+            version: 1,
+            items: vec![record("a"), record("b"), record("c"), record("d")],
+        };
+        let size = record_size(&eif.items[0]);
+
+        let parts = eif.split(HEADER_SIZE + size * 3);
+        assert_eq!(parts.len(), 2);
+        for part in &parts {
+            assert_eq!(part.rid, eif.rid);
+            assert_eq!(part.total_items_count, eif.total_items_count);
+            assert_eq!(part.version, eif.version);
+        }
+
+        let items: Vec<EifRecord> = parts.into_iter().flat_map(|part| part.items).collect();
+        assert_eq!(items, eif.items);
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::BTreeMap;
+
+use eolib::protocol::map::Emf;
+use glob::glob;
+
+use crate::utils::load_map_file;
+
+use super::Report;
+
+/// Reads every EMF and JSON map the server would load, without starting the
+/// map actors
+pub fn load_maps(report: &mut Report) -> BTreeMap<i32, Emf> {
+    let mut maps = BTreeMap::new();
+
+    for pattern in ["data/maps/*.emf", "data/maps/*.json"] {
+        let entries = match glob(pattern) {
+            Ok(entries) => entries,
+            Err(e) => {
+                report.error("maps", e.to_string());
+                return maps;
+            }
+        };
+
+        for entry in entries {
+            let path = match entry {
+                Ok(path) => path,
+                Err(e) => {
+                    report.error("maps", e.to_string());
+                    continue;
+                }
+            };
+
+            let id = match path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<i32>().ok())
+            {
+                Some(id) => id,
+                None => {
+                    report.warn(
+                        "maps",
+                        format!("{} is not named after a map id", path.display()),
+                    );
+                    continue;
+                }
+            };
+
+            // JSON maps come second and replace an EMF with the same id
+            match load_map_file(&path) {
+                Ok((emf, _)) => {
+                    maps.insert(id, emf);
+                }
+                Err(e) => report.error("maps", format!("{}: {}", path.display(), e)),
+            }
+        }
+    }
+
+    maps
+}
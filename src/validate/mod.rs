@@ -0,0 +1,11 @@
+mod load_maps;
+mod report;
+use report::Report;
+#[allow(clippy::module_inception)]
+mod validate;
+pub use validate::validate;
+mod validate_config;
+mod validate_maps;
+mod validate_pubs;
+mod validate_quests;
+mod validate_spawns;
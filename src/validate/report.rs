@@ -0,0 +1,36 @@
+/// Problems found while validating, printed once every check has run.
+#[derive(Debug, Default)]
+pub struct Report {
+    errors: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl Report {
+    pub fn error(&mut self, source: &str, message: String) {
+        self.errors.push(format!("{}: {}", source, message));
+    }
+
+    pub fn warn(&mut self, source: &str, message: String) {
+        self.warnings.push(format!("{}: {}", source, message));
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    pub fn print(&self) {
+        for warning in &self.warnings {
+            println!("warning: {}", warning);
+        }
+
+        for error in &self.errors {
+            println!("error: {}", error);
+        }
+
+        println!(
+            "\n{} error(s), {} warning(s)",
+            self.errors.len(),
+            self.warnings.len()
+        );
+    }
+}
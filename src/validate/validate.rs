@@ -0,0 +1,209 @@
+use std::collections::BTreeMap;
+
+use eolib::protocol::{
+    map::Emf,
+    r#pub::{
+        server::{DropFile, InnFile, ShopFile, SkillMasterFile, TalkFile},
+        Ecf, Eif, Enf, Esf,
+    },
+};
+use eoplus::Quest;
+use glob::glob;
+
+use crate::{
+    arenas::Arenas,
+    commands::Commands,
+    emails::Emails,
+    formulas::Formulas,
+    lang::Lang,
+    packet_rate_limits::PacketRateLimits,
+    player_commands::PlayerCommands,
+    settings::Settings,
+    spawns::Spawns,
+    utils::{
+        build_class_file, build_drop_file, build_inn_file, build_item_file, build_npc_file,
+        build_shop_file, build_skill_master_file, build_spell_file, build_talk_file,
+        load_exp_table, load_quest,
+    },
+};
+
+use super::{
+    load_maps::load_maps, validate_config::validate_config, validate_maps::validate_maps,
+    validate_pubs::validate_pubs, validate_quests::validate_quests,
+    validate_spawns::validate_spawns, Report,
+};
+
+/// Everything loaded from disk that the checks cross-reference
+pub struct Data {
+    pub settings: Settings,
+    pub arenas: Option<Arenas>,
+    pub classes: Ecf,
+    pub drops: DropFile,
+    pub inns: InnFile,
+    pub items: Eif,
+    pub npcs: Enf,
+    pub shops: ShopFile,
+    pub skill_masters: SkillMasterFile,
+    pub spells: Esf,
+    pub talks: TalkFile,
+    pub quests: BTreeMap<i32, Quest>,
+    pub maps: BTreeMap<i32, Emf>,
+    pub spawns: Option<Spawns>,
+}
+
+impl Data {
+    pub fn item_exists(&self, item_id: i32) -> bool {
+        item_id > 0 && (item_id as usize) <= self.items.items.len()
+    }
+
+    pub fn npc_exists(&self, npc_id: i32) -> bool {
+        npc_id > 0 && (npc_id as usize) <= self.npcs.npcs.len()
+    }
+
+    pub fn spell_exists(&self, spell_id: i32) -> bool {
+        spell_id > 0 && (spell_id as usize) <= self.spells.skills.len()
+    }
+
+    pub fn class_exists(&self, class_id: i32) -> bool {
+        class_id > 0 && (class_id as usize) <= self.classes.classes.len()
+    }
+
+    pub fn map_exists(&self, map_id: i32) -> bool {
+        self.maps.contains_key(&map_id)
+    }
+
+    /// Whether the coords are on a loaded map, so unknown maps aren't
+    /// reported twice
+    pub fn in_bounds(&self, map_id: i32, x: i32, y: i32) -> bool {
+        match self.maps.get(&map_id) {
+            Some(map) => x >= 0 && y >= 0 && x <= map.width && y <= map.height,
+            None => true,
+        }
+    }
+}
+
+/// Loads every pub, quest, map and config file and cross-checks the ids they
+/// reference. Returns the process exit code.
+pub fn validate() -> i32 {
+    let mut report = Report::default();
+
+    let settings = match Settings::new() {
+        Ok(settings) => settings,
+        Err(e) => {
+            report.error("config/Config.toml", e.to_string());
+            report.print();
+            return 1;
+        }
+    };
+
+    let arenas = match Arenas::new() {
+        Ok(arenas) => Some(arenas),
+        Err(e) => {
+            report.error("config/Arenas.ron", e.to_string());
+            None
+        }
+    };
+
+    let spawns = match Spawns::new() {
+        Ok(spawns) => Some(spawns),
+        Err(e) => {
+            report.error("spawns", e.to_string());
+            None
+        }
+    };
+
+    if let Err(e) = Commands::new() {
+        report.error("config/Commands.ron", e.to_string());
+    }
+
+    if let Err(e) = PlayerCommands::new() {
+        report.error("config/PlayerCommands.ron", e.to_string());
+    }
+
+    match Formulas::new() {
+        Ok(formulas) => {
+            if let Err(e) = load_exp_table(&formulas) {
+                report.error("config/Formulas.ron", e.to_string());
+            }
+        }
+        Err(e) => report.error("config/Formulas.ron", e.to_string()),
+    }
+
+    if let Err(e) = Lang::new() {
+        report.error("config/lang", e.to_string());
+    }
+
+    if let Err(e) = Emails::new() {
+        report.error("config/Emails.ron", e.to_string());
+    }
+
+    if let Err(e) = PacketRateLimits::new() {
+        report.error("config/PacketRateLimits.ron", e.to_string());
+    }
+
+    let mut quests = BTreeMap::new();
+    for entry in glob("data/quests/*.eqf").unwrap() {
+        match entry.map_err(|e| e.to_string()).and_then(load_quest) {
+            Ok((id, quest)) => {
+                quests.insert(id, quest);
+            }
+            Err(e) => report.error("quests", e),
+        }
+    }
+
+    let data = Data {
+        settings,
+        arenas,
+        classes: load_pub("classes", build_class_file, &mut report),
+        drops: load_pub("drops", build_drop_file, &mut report),
+        inns: load_pub("inns", build_inn_file, &mut report),
+        items: load_pub("items", build_item_file, &mut report),
+        npcs: load_pub("npcs", build_npc_file, &mut report),
+        shops: load_pub("shops", build_shop_file, &mut report),
+        skill_masters: load_pub("skill masters", build_skill_master_file, &mut report),
+        spells: load_pub("spells", build_spell_file, &mut report),
+        talks: load_pub("talks", build_talk_file, &mut report),
+        quests,
+        maps: load_maps(&mut report),
+        spawns,
+    };
+
+    println!(
+        "Checking {} items, {} npcs, {} spells, {} classes, {} quests and {} maps",
+        data.items.items.len(),
+        data.npcs.npcs.len(),
+        data.spells.skills.len(),
+        data.classes.classes.len(),
+        data.quests.len(),
+        data.maps.len()
+    );
+
+    validate_pubs(&data, &mut report);
+    validate_quests(&data, &mut report);
+    validate_maps(&data, &mut report);
+    validate_config(&data, &mut report);
+    validate_spawns(&data, &mut report);
+
+    report.print();
+
+    if report.has_errors() {
+        1
+    } else {
+        0
+    }
+}
+
+// Only builds the pubs, validating never writes to data/pub
+fn load_pub<T: Default>(
+    name: &str,
+    load: fn() -> Result<T, Box<dyn std::error::Error>>,
+    report: &mut Report,
+) -> T {
+    match load() {
+        Ok(file) => file,
+        Err(e) => {
+            report.error(name, e.to_string());
+            T::default()
+        }
+    }
+}
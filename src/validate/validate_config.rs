@@ -0,0 +1,105 @@
+use super::{validate::Data, Report};
+
+pub fn validate_config(data: &Data, report: &mut Report) {
+    let settings = &data.settings;
+
+    let locations = [
+        (
+            "new_character.spawn_map",
+            settings.new_character.spawn_map,
+            settings.new_character.spawn_x,
+            settings.new_character.spawn_y,
+        ),
+        (
+            "jail.map",
+            settings.jail.map,
+            settings.jail.x,
+            settings.jail.y,
+        ),
+        (
+            "jail.free_map",
+            settings.jail.free_map,
+            settings.jail.free_x,
+            settings.jail.free_y,
+        ),
+        (
+            "rescue.map",
+            settings.rescue.map,
+            settings.rescue.x,
+            settings.rescue.y,
+        ),
+    ];
+
+    for (name, map, x, y) in locations {
+        if !data.map_exists(map) {
+            report.error(
+                "config/Config.toml",
+                format!("{} {} is not loaded", name, map),
+            );
+        } else if !data.in_bounds(map, x, y) {
+            report.error(
+                "config/Config.toml",
+                format!("{} {},{} is outside map {}", name, x, y, map),
+            );
+        }
+    }
+
+    let mut items = vec![
+        ("marriage.ring_item_id", settings.marriage.ring_item_id),
+        (
+            "marriage.female_armor_id",
+            settings.marriage.female_armor_id,
+        ),
+        ("marriage.male_armor_id", settings.marriage.male_armor_id),
+    ];
+
+    for item_id in &settings.bard.instrument_items {
+        items.push(("bard.instrument_items", *item_id));
+    }
+
+    for item_id in &settings.items.infinite_use_items {
+        items.push(("items.infinite_use_items", *item_id));
+    }
+
+    for item_id in &settings.items.protected_items {
+        items.push(("items.protected_items", *item_id));
+    }
+
+    for (name, item_id) in items {
+        if !data.item_exists(item_id) {
+            report.error(
+                "config/Config.toml",
+                format!("{} {} is not in the item pub", name, item_id),
+            );
+        }
+    }
+
+    let arenas = match data.arenas {
+        Some(ref arenas) => arenas,
+        None => return,
+    };
+
+    for arena in &arenas.arenas {
+        if !data.map_exists(arena.map) {
+            report.error(
+                "config/Arenas.ron",
+                format!("arena map {} has no EMF", arena.map),
+            );
+            continue;
+        }
+
+        for spawn in &arena.spawns {
+            for coords in [&spawn.from, &spawn.to] {
+                if !data.in_bounds(arena.map, coords.x, coords.y) {
+                    report.error(
+                        "config/Arenas.ron",
+                        format!(
+                            "arena spawn {},{} is outside map {}",
+                            coords.x, coords.y, arena.map
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use super::{validate::Data, Report};
+
+pub fn validate_maps(data: &Data, report: &mut Report) {
+    for (id, map) in &data.maps {
+        let source = format!("map {}", id);
+
+        for npc in &map.npcs {
+            if !data.npc_exists(npc.id) {
+                report.error(
+                    "maps",
+                    format!(
+                        "{} spawns missing npc {} at {},{}",
+                        source, npc.id, npc.coords.x, npc.coords.y
+                    ),
+                );
+            }
+        }
+
+        for item in &map.items {
+            if !data.item_exists(item.item_id) {
+                report.error(
+                    "maps",
+                    format!(
+                        "{} chest at {},{} holds missing item {}",
+                        source, item.coords.x, item.coords.y, item.item_id
+                    ),
+                );
+            }
+        }
+
+        for row in &map.warp_rows {
+            for tile in &row.tiles {
+                let warp = &tile.warp;
+                let destination = &warp.destination_coords;
+
+                if !data.map_exists(warp.destination_map) {
+                    report.error(
+                        "maps",
+                        format!(
+                            "{} warp at {},{} leads to missing map {}",
+                            source, tile.x, row.y, warp.destination_map
+                        ),
+                    );
+                } else if !data.in_bounds(warp.destination_map, destination.x, destination.y) {
+                    report.error(
+                        "maps",
+                        format!(
+                            "{} warp at {},{} leads outside map {} to {},{}",
+                            source,
+                            tile.x,
+                            row.y,
+                            warp.destination_map,
+                            destination.x,
+                            destination.y
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use eolib::protocol::r#pub::NpcType;
+
+use super::{validate::Data, Report};
+
+pub fn validate_pubs(data: &Data, report: &mut Report) {
+    for (index, item) in data.items.items.iter().enumerate() {
+        if item.class_requirement > 0 && !data.class_exists(item.class_requirement) {
+            report.error(
+                "items",
+                format!(
+                    "item {} ({}) requires missing class {}",
+                    index + 1,
+                    item.name,
+                    item.class_requirement
+                ),
+            );
+        }
+    }
+
+    for npc in &data.drops.npcs {
+        if !data.npc_exists(npc.npc_id) {
+            report.error("drops", format!("drops for missing npc {}", npc.npc_id));
+        }
+
+        for drop in &npc.drops {
+            if !data.item_exists(drop.item_id) {
+                report.error(
+                    "drops",
+                    format!("npc {} drops missing item {}", npc.npc_id, drop.item_id),
+                );
+            }
+
+            if drop.min_amount > drop.max_amount {
+                report.warn(
+                    "drops",
+                    format!(
+                        "npc {} drops item {} with min {} above max {}",
+                        npc.npc_id, drop.item_id, drop.min_amount, drop.max_amount
+                    ),
+                );
+            }
+        }
+    }
+
+    for talk in &data.talks.npcs {
+        if !data.npc_exists(talk.npc_id) {
+            report.error("talks", format!("messages for missing npc {}", talk.npc_id));
+        }
+    }
+
+    for shop in &data.shops.shops {
+        let source = format!("shop {} ({})", shop.behavior_id, shop.name);
+
+        if !has_behavior(data, NpcType::Shop, shop.behavior_id) {
+            report.error(
+                "shops",
+                format!("{} has no shop npc with that behavior id", source),
+            );
+        }
+
+        if shop.class_requirement > 0 && !data.class_exists(shop.class_requirement) {
+            report.error(
+                "shops",
+                format!(
+                    "{} requires missing class {}",
+                    source, shop.class_requirement
+                ),
+            );
+        }
+
+        for trade in &shop.trades {
+            if !data.item_exists(trade.item_id) {
+                report.error(
+                    "shops",
+                    format!("{} trades missing item {}", source, trade.item_id),
+                );
+            }
+        }
+
+        for craft in &shop.crafts {
+            if !data.item_exists(craft.item_id) {
+                report.error(
+                    "shops",
+                    format!("{} crafts missing item {}", source, craft.item_id),
+                );
+            }
+
+            for ingredient in &craft.ingredients {
+                if ingredient.item_id > 0 && !data.item_exists(ingredient.item_id) {
+                    report.error(
+                        "shops",
+                        format!(
+                            "{} crafts item {} from missing item {}",
+                            source, craft.item_id, ingredient.item_id
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    for inn in &data.inns.inns {
+        let source = format!("inn {} ({})", inn.behavior_id, inn.name);
+
+        if !has_behavior(data, NpcType::Inn, inn.behavior_id) {
+            report.error(
+                "inns",
+                format!("{} has no inn npc with that behavior id", source),
+            );
+        }
+
+        let mut locations = vec![
+            ("spawn", inn.spawn_map, inn.spawn_x, inn.spawn_y),
+            ("sleep", inn.sleep_map, inn.sleep_x, inn.sleep_y),
+        ];
+
+        if inn.alternate_spawn_enabled {
+            locations.push((
+                "alternate spawn",
+                inn.alternate_spawn_map,
+                inn.alternate_spawn_x,
+                inn.alternate_spawn_y,
+            ));
+        }
+
+        for (name, map, x, y) in locations {
+            if !data.map_exists(map) {
+                report.error(
+                    "inns",
+                    format!("{} {} map {} is not loaded", source, name, map),
+                );
+            } else if !data.in_bounds(map, x, y) {
+                report.error(
+                    "inns",
+                    format!("{} {} {},{} is outside map {}", source, name, x, y, map),
+                );
+            }
+        }
+    }
+
+    for skill_master in &data.skill_masters.skill_masters {
+        let source = format!(
+            "skill master {} ({})",
+            skill_master.behavior_id, skill_master.name
+        );
+
+        if !has_behavior(data, NpcType::Trainer, skill_master.behavior_id) {
+            report.error(
+                "skill masters",
+                format!("{} has no trainer npc with that behavior id", source),
+            );
+        }
+
+        for skill in &skill_master.skills {
+            if !data.spell_exists(skill.skill_id) {
+                report.error(
+                    "skill masters",
+                    format!("{} teaches missing spell {}", source, skill.skill_id),
+                );
+            }
+
+            if skill.class_requirement > 0 && !data.class_exists(skill.class_requirement) {
+                report.error(
+                    "skill masters",
+                    format!(
+                        "{} spell {} requires missing class {}",
+                        source, skill.skill_id, skill.class_requirement
+                    ),
+                );
+            }
+
+            for required in skill.skill_requirements {
+                if required > 0 && !data.spell_exists(required) {
+                    report.error(
+                        "skill masters",
+                        format!(
+                            "{} spell {} requires missing spell {}",
+                            source, skill.skill_id, required
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    for (index, npc) in data.npcs.npcs.iter().enumerate() {
+        let missing = match npc.r#type {
+            NpcType::Shop => !data
+                .shops
+                .shops
+                .iter()
+                .any(|shop| shop.behavior_id == npc.behavior_id),
+            NpcType::Inn => !data
+                .inns
+                .inns
+                .iter()
+                .any(|inn| inn.behavior_id == npc.behavior_id),
+            NpcType::Trainer => !data
+                .skill_masters
+                .skill_masters
+                .iter()
+                .any(|skill_master| skill_master.behavior_id == npc.behavior_id),
+            _ => false,
+        };
+
+        if missing {
+            report.warn(
+                "npcs",
+                format!(
+                    "npc {} ({}) has no {:?} record for behavior id {}",
+                    index + 1,
+                    npc.name,
+                    npc.r#type,
+                    npc.behavior_id
+                ),
+            );
+        }
+    }
+}
+
+pub fn has_behavior(data: &Data, npc_type: NpcType, behavior_id: i32) -> bool {
+    data.npcs
+        .npcs
+        .iter()
+        .any(|npc| npc.r#type == npc_type && npc.behavior_id == behavior_id)
+}
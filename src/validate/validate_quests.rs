@@ -0,0 +1,78 @@
+use eolib::protocol::r#pub::NpcType;
+use eoplus::Arg;
+
+use super::{validate::Data, validate_pubs::has_behavior, Report};
+
+pub fn validate_quests(data: &Data, report: &mut Report) {
+    for (id, quest) in &data.quests {
+        let source = format!("quest {} ({})", id, quest.name);
+
+        for state in &quest.states {
+            let mut references: Vec<(&str, &[Arg])> = state
+                .actions
+                .iter()
+                .map(|action| (action.name.as_str(), action.args.as_slice()))
+                .collect();
+
+            for rule in &state.rules {
+                references.push((rule.name.as_str(), rule.args.as_slice()));
+
+                if !quest.states.iter().any(|s| s.name == rule.goto) {
+                    report.error(
+                        "quests",
+                        format!(
+                            "{} state {} goes to missing state {}",
+                            source, state.name, rule.goto
+                        ),
+                    );
+                }
+            }
+
+            for (name, args) in references {
+                let id = match args.first() {
+                    Some(Arg::Int(id)) => *id,
+                    _ => continue,
+                };
+
+                let missing = match name {
+                    "GiveItem" | "RemoveItem" | "GotItems" | "LostItems" | "EquippedItem"
+                    | "UnequippedItem" => (!data.item_exists(id)).then_some("item"),
+                    "KilledNpcs" => (!data.npc_exists(id)).then_some("npc"),
+                    "TalkedToNpc" | "AddNpcText" | "AddNpcInput" | "AddNpcChat" => {
+                        (!has_behavior(data, NpcType::Quest, id)).then_some("quest npc")
+                    }
+                    "SetClass" => (!data.class_exists(id)).then_some("class"),
+                    "SetMap" | "EnterMap" | "LeaveMap" | "EnterCoord" => {
+                        (!data.map_exists(id)).then_some("map")
+                    }
+                    _ => None,
+                };
+
+                if let Some(kind) = missing {
+                    report.error(
+                        "quests",
+                        format!(
+                            "{} state {} {} uses missing {} {}",
+                            source, state.name, name, kind, id
+                        ),
+                    );
+                    continue;
+                }
+
+                if name == "SetMap" {
+                    if let (Some(Arg::Int(x)), Some(Arg::Int(y))) = (args.get(1), args.get(2)) {
+                        if !data.in_bounds(id, *x, *y) {
+                            report.error(
+                                "quests",
+                                format!(
+                                    "{} state {} SetMap {},{} is outside map {}",
+                                    source, state.name, x, y, id
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use super::{validate::Data, Report};
+
+pub fn validate_spawns(data: &Data, report: &mut Report) {
+    let spawns = match data.spawns {
+        Some(ref spawns) => spawns,
+        None => return,
+    };
+
+    for file in &spawns.files {
+        let map = match data.maps.get(&file.map) {
+            Some(map) => map,
+            None => {
+                report.error(&file.path, format!("map {} is not loaded", file.map));
+                continue;
+            }
+        };
+
+        for spawn_match in file
+            .remove
+            .iter()
+            .chain(file.replace.iter().map(|replacement| &replacement.at))
+        {
+            if !map.npcs.iter().any(|npc| spawn_match.matches(npc)) {
+                report.warn(
+                    &file.path,
+                    format!(
+                        "no spawn at {},{} on map {}",
+                        spawn_match.x, spawn_match.y, file.map
+                    ),
+                );
+            }
+        }
+
+        for spawn in file
+            .replace
+            .iter()
+            .map(|replacement| &replacement.with)
+            .chain(file.add.iter())
+        {
+            if !data.npc_exists(spawn.npc_id) {
+                report.error(
+                    &file.path,
+                    format!(
+                        "spawns missing npc {} at {},{}",
+                        spawn.npc_id, spawn.x, spawn.y
+                    ),
+                );
+            }
+
+            if !data.in_bounds(file.map, spawn.x, spawn.y) {
+                report.error(
+                    &file.path,
+                    format!("spawn {},{} is outside map {}", spawn.x, spawn.y, file.map),
+                );
+            }
+
+            if spawn.amount < 1 {
+                report.warn(
+                    &file.path,
+                    format!(
+                        "npc {} at {},{} spawns {} npcs",
+                        spawn.npc_id, spawn.x, spawn.y, spawn.amount
+                    ),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use crate::character::Character;
+
+#[derive(Debug)]
+pub struct AccountInfo {
+    pub name: String,
+    pub last_login_ip: Option<String>,
+}
+
+/// A character along with the account it belongs to
+pub type CharacterWithAccount = (Box<Character>, AccountInfo);
@@ -0,0 +1,11 @@
+use eolib::protocol::Coords;
+
+#[derive(Debug, Default)]
+pub struct AdminLogEntry {
+    pub admin_name: String,
+    pub command: String,
+    pub args: String,
+    pub target: Option<String>,
+    pub map_id: Option<i32>,
+    pub coords: Option<Coords>,
+}
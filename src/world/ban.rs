@@ -0,0 +1,99 @@
+use chrono::NaiveDateTime;
+
+/// What a ban matches on when a client connects or logs in
+#[derive(Debug, Default, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BanScope {
+    /// Only the banned account
+    #[default]
+    Account,
+    /// The account and its last login IP address
+    Ip,
+    /// The account and its hardware ID
+    Hdid,
+    /// The account, its IP address and its hardware ID
+    All,
+}
+
+impl BanScope {
+    pub fn parse(scope: &str) -> Option<Self> {
+        match scope.to_lowercase().as_str() {
+            "account" => Some(Self::Account),
+            "ip" => Some(Self::Ip),
+            "hdid" => Some(Self::Hdid),
+            "all" => Some(Self::All),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Account => "account",
+            Self::Ip => "ip",
+            Self::Hdid => "hdid",
+            Self::All => "all",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Ban {
+    pub id: i32,
+    pub account_name: Option<String>,
+    pub ip: String,
+    pub hdid: Option<u32>,
+    pub scope: BanScope,
+    pub duration: Option<i32>,
+    pub created_by: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl Ban {
+    pub fn summary(&self) -> String {
+        let duration = match self.duration {
+            Some(duration) => format!("{}m", duration),
+            None => "permanent".to_string(),
+        };
+
+        let hdid = match self.hdid {
+            Some(hdid) => format!(" hdid {}", hdid),
+            None => String::new(),
+        };
+
+        format!(
+            "#{} {} {} {}{} [{}] {} by {}",
+            self.id,
+            self.created_at.format("%Y-%m-%d %H:%M"),
+            self.account_name.as_deref().unwrap_or("-"),
+            self.ip,
+            hdid,
+            self.scope.as_str(),
+            duration,
+            self.created_by.as_deref().unwrap_or("Server"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scopes_parse_back_from_their_names() {
+        for scope in [
+            BanScope::Account,
+            BanScope::Ip,
+            BanScope::Hdid,
+            BanScope::All,
+        ] {
+            assert_eq!(BanScope::parse(scope.as_str()), Some(scope));
+            assert_eq!(BanScope::parse(&scope.as_str().to_uppercase()), Some(scope));
+        }
+    }
+
+    #[test]
+    fn unknown_scope_does_not_parse() {
+        assert_eq!(BanScope::parse(""), None);
+        assert_eq!(BanScope::parse("1d"), None);
+    }
+}
@@ -1,10 +1,12 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use eolib::protocol::net::{server::PartyExpShare, PartyRequestType};
 use tokio::sync::oneshot;
 
 use crate::{character::Character, map::MapHandle, player::PlayerHandle};
 
-use super::{Party, WorldHandle};
+use super::{AdminLogEntry, Ban, BanScope, CharacterWithAccount, Party, WorldHandle};
 
 #[derive(Debug)]
 pub enum Command {
@@ -26,9 +28,6 @@ pub enum Command {
         ip: String,
         respond_to: oneshot::Sender<()>,
     },
-    AddLoggedInAccount {
-        account_id: i32,
-    },
     AddPendingLogin {
         account_id: i32,
     },
@@ -44,6 +43,7 @@ pub enum Command {
         victim_name: String,
         admin_name: String,
         duration: String,
+        scope: BanScope,
         silent: bool,
     },
     BroadcastAdminMessage {
@@ -96,9 +96,31 @@ pub enum Command {
         respond_to:
             oneshot::Sender<Result<Box<Character>, Box<dyn std::error::Error + Sync + Send>>>,
     },
+    GetCharacterWithAccount {
+        name: String,
+        respond_to: oneshot::Sender<Result<Option<CharacterWithAccount>, String>>,
+    },
+    GetAuditLog {
+        name: Option<String>,
+        count: Option<i32>,
+        respond_to: oneshot::Sender<Result<(String, Vec<String>), String>>,
+    },
+    GetBans {
+        filter: Option<String>,
+        respond_to: oneshot::Sender<Result<Vec<Ban>, String>>,
+    },
+    GetMaintenanceMessage {
+        respond_to: oneshot::Sender<Option<String>>,
+    },
     GetConnectionCount {
         respond_to: oneshot::Sender<i32>,
     },
+    GetEconomy {
+        respond_to: oneshot::Sender<Result<(String, Vec<String>), String>>,
+    },
+    GetGuilds {
+        respond_to: oneshot::Sender<HashMap<String, Vec<i32>>>,
+    },
     GetIpConnectionCount {
         ip: String,
         respond_to: oneshot::Sender<i32>,
@@ -107,13 +129,27 @@ pub enum Command {
         ip: String,
         respond_to: oneshot::Sender<Option<DateTime<Utc>>>,
     },
+    GetItemTrace {
+        item_id: i32,
+        name: Option<String>,
+        respond_to: oneshot::Sender<Result<(String, Vec<String>), String>>,
+    },
     GetMap {
         map_id: i32,
         respond_to: oneshot::Sender<Result<MapHandle, Box<dyn std::error::Error + Send + Sync>>>,
     },
+    GetMaps {
+        respond_to: oneshot::Sender<Vec<MapHandle>>,
+    },
     GetNextPlayerId {
         respond_to: oneshot::Sender<i32>,
     },
+    GetOnlineCharacters {
+        respond_to: oneshot::Sender<Vec<Character>>,
+    },
+    GetParties {
+        respond_to: oneshot::Sender<Vec<Party>>,
+    },
     GetPlayer {
         player_id: i32,
         respond_to: oneshot::Sender<Option<PlayerHandle>>,
@@ -125,6 +161,15 @@ pub enum Command {
         player_id: i32,
         respond_to: oneshot::Sender<Option<Party>>,
     },
+    QueueLogin {
+        player_id: i32,
+        account_id: i32,
+        skip_queue: bool,
+        respond_to: oneshot::Sender<Option<usize>>,
+    },
+    IsShuttingDown {
+        respond_to: oneshot::Sender<bool>,
+    },
     IsLoggedIn {
         account_id: i32,
         respond_to: oneshot::Sender<bool>,
@@ -142,9 +187,12 @@ pub enum Command {
         world: WorldHandle,
         respond_to: oneshot::Sender<()>,
     },
+    LogAdminAction(AdminLogEntry),
     MutePlayer {
         victim_name: String,
         admin_name: String,
+        duration: String,
+        respond_to: oneshot::Sender<Result<(), String>>,
     },
     Quake {
         magnitude: i32,
@@ -179,6 +227,10 @@ pub enum Command {
         player_id: i32,
         target_player_id: i32,
     },
+    Reload {
+        target: String,
+        respond_to: oneshot::Sender<Result<(), String>>,
+    },
     ReloadMap {
         map_id: i32,
     },
@@ -196,6 +248,16 @@ pub enum Command {
         victim_name: String,
         experience: i32,
     },
+    SetMaintenance {
+        enabled: bool,
+        message: String,
+        admin_name: String,
+    },
+    ScheduleShutdown {
+        seconds: i32,
+        reason: String,
+        restart: bool,
+    },
     Shutdown {
         respond_to: oneshot::Sender<()>,
     },
@@ -203,6 +265,10 @@ pub enum Command {
     ToggleGlobal {
         admin_name: String,
     },
+    Unban {
+        target: String,
+        respond_to: oneshot::Sender<Result<Vec<BanScope>, String>>,
+    },
     UnfreezePlayer {
         victim_name: String,
         admin_name: String,
@@ -215,4 +281,7 @@ pub enum Command {
         player_id: i32,
         exp_gains: Vec<PartyExpShare>,
     },
+    WaitForShutdown {
+        respond_to: oneshot::Sender<i32>,
+    },
 }
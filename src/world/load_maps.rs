@@ -1,22 +1,10 @@
-use std::{
-    collections::HashMap,
-    io::{
-        prelude::{Read, Seek},
-        SeekFrom,
-    },
-    path::PathBuf,
-};
-
-use bytes::Bytes;
-use eolib::{
-    data::{EoReader, EoSerialize},
-    protocol::map::Emf,
-};
+use std::{collections::HashMap, path::PathBuf};
+
+use eolib::protocol::map::Emf;
 use futures::{stream, StreamExt};
 use glob::glob;
-use mysql_async::Pool;
 
-use crate::map::MapHandle;
+use crate::{db::Pool, map::MapHandle, utils::load_map_file};
 
 use super::WorldHandle;
 
@@ -24,23 +12,29 @@ pub async fn load_maps(
     pool: Pool,
     world: WorldHandle,
 ) -> Result<HashMap<i32, MapHandle>, Box<dyn std::error::Error + Send + Sync>> {
-    let entries = glob("data/maps/*.emf")?;
-    let mut map_files: HashMap<i32, MapHandle> = HashMap::new();
-    let mut load_handles = vec![];
-    for path in entries {
-        let path = match path {
-            Ok(ref path) => path,
+    let mut paths: HashMap<i32, PathBuf> = HashMap::new();
+    for entry in glob("data/maps/*.emf")?.chain(glob("data/maps/*.json")?) {
+        let path = match entry {
+            Ok(path) => path,
             Err(e) => {
                 error!("Failed to read path: {}", e);
                 continue;
             }
         };
 
-        load_handles.push(load_map(
-            path.to_path_buf(),
-            pool.to_owned(),
-            world.to_owned(),
-        ));
+        let id = match get_map_id(&path) {
+            Some(id) => id,
+            None => continue,
+        };
+
+        // JSON maps are globbed last so they replace an EMF with the same id
+        paths.insert(id, path);
+    }
+
+    let mut map_files: HashMap<i32, MapHandle> = HashMap::new();
+    let mut load_handles = vec![];
+    for (id, path) in paths {
+        load_handles.push(load_map(id, path, pool.to_owned(), world.to_owned()));
     }
 
     let mut stream = stream::iter(load_handles).buffer_unordered(100);
@@ -60,61 +54,29 @@ pub async fn load_maps(
     Ok(map_files)
 }
 
-async fn load_map(path: PathBuf, pool: Pool, world: WorldHandle) -> Option<(i32, MapHandle)> {
-    let file_name = match path.file_name() {
-        Some(file_name) => match file_name.to_str() {
-            Some(file_name) => file_name,
-            None => return None,
-        },
-        None => return None,
-    };
-
-    let left_part = file_name.split('.').collect::<Vec<&str>>()[0];
-
-    if left_part.chars().any(|c| !c.is_ascii_digit()) {
-        return None;
-    }
-
-    let id = left_part.parse::<i32>().unwrap();
-
-    let mut raw_file = match tokio::fs::File::open(path).await {
-        Ok(file) => file.into_std().await,
-        Err(e) => {
-            error!("Failed to open file: {}", e);
-            return None;
-        }
-    };
-
-    let file_size: u64 = match raw_file.metadata() {
-        Ok(metadata) => metadata.len(),
-        Err(e) => {
-            error!("Failed to get metadata: {}", e);
-            return None;
-        }
-    };
-
-    let mut data_buf: Vec<u8> = Vec::new();
-    if let Err(e) = raw_file.seek(SeekFrom::Start(0)) {
-        error!("Failed to seek file: {}", e);
-        return None;
-    }
+fn get_map_id(path: &std::path::Path) -> Option<i32> {
+    let file_stem = path.file_stem()?.to_str()?;
 
-    if let Err(e) = raw_file.read_to_end(&mut data_buf) {
-        error!("Failed to read file: {}", e);
+    if file_stem.chars().any(|c| !c.is_ascii_digit()) {
         return None;
     }
 
-    let data_buf = Bytes::from(data_buf);
-
-    let reader = EoReader::new(data_buf);
+    file_stem.parse::<i32>().ok()
+}
 
-    let file = match Emf::deserialize(&reader) {
+async fn load_map(
+    id: i32,
+    path: PathBuf,
+    pool: Pool,
+    world: WorldHandle,
+) -> Option<(i32, MapHandle)> {
+    let (file, file_size) = match load_map_file(&path) {
         Ok(file) => file,
         Err(e) => {
-            error!("Failed to deserialize Emf: {}", e);
+            error!("Failed to load map {}: {}", path.display(), e);
             return None;
         }
     };
 
-    Some((id, MapHandle::new(id, file_size as i32, pool, file, world)))
+    Some((id, MapHandle::new(id, file_size, pool, file, world)))
 }
@@ -1,3 +1,9 @@
+mod account_info;
+pub use account_info::{AccountInfo, CharacterWithAccount};
+mod admin_log_entry;
+pub use admin_log_entry::AdminLogEntry;
+mod ban;
+pub use ban::{Ban, BanScope};
 mod command;
 pub use command::Command;
 mod load_maps;
@@ -7,3 +13,7 @@ mod world_handle;
 pub use world_handle::WorldHandle;
 mod party;
 pub use party::Party;
+mod queued_login;
+pub use queued_login::QueuedLogin;
+mod pending_shutdown;
+pub use pending_shutdown::PendingShutdown;
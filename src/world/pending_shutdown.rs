@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug)]
+pub struct PendingShutdown {
+    pub at: DateTime<Utc>,
+    pub reason: String,
+    pub restart: bool,
+    pub last_warning: i64,
+}
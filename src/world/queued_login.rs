@@ -0,0 +1,5 @@
+#[derive(Debug)]
+pub struct QueuedLogin {
+    pub player_id: i32,
+    pub account_id: i32,
+}
@@ -1,11 +1,12 @@
 use crate::{
-    connection_log::ConnectionLog, errors::DataNotFoundError, map::MapHandle, player::PlayerHandle,
+    connection_log::ConnectionLog, db::Pool, errors::DataNotFoundError, map::MapHandle,
+    player::PlayerHandle, save_queue::SaveQueueHandle, SETTINGS,
 };
 
-use super::{load_maps::load_maps, Command, Party};
-use mysql_async::Pool;
-use std::collections::HashMap;
-use tokio::sync::mpsc::UnboundedReceiver;
+use super::{load_maps::load_maps, Command, Party, PendingShutdown, QueuedLogin};
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
+use tokio::sync::{mpsc::UnboundedReceiver, oneshot};
 
 #[derive(Debug)]
 pub struct World {
@@ -16,6 +17,7 @@ pub struct World {
     characters: HashMap<String, i32>,
     guilds: HashMap<String, Vec<i32>>,
     pool: Pool,
+    save_queue: SaveQueueHandle,
     maps: Option<HashMap<i32, MapHandle>>,
     parties: Vec<Party>,
     npc_act_ticks: i32,
@@ -30,6 +32,12 @@ pub struct World {
     auto_pickup_ticks: i32,
     global_locked: bool,
     connection_log: ConnectionLog,
+    pending_shutdown: Option<PendingShutdown>,
+    shutdown_waiter: Option<oneshot::Sender<i32>>,
+    maintenance: Option<String>,
+    maintenance_kick_at: Option<DateTime<Utc>>,
+    login_queue: VecDeque<QueuedLogin>,
+    login_queue_seconds: i32,
 }
 
 mod add_player;
@@ -40,19 +48,27 @@ mod drop_player;
 mod find_player;
 mod get_character_by_name;
 mod get_next_player_id;
+mod get_online_characters;
+mod login_queue;
 mod party;
 mod reload_map;
 mod request_player_list;
 mod request_player_name_list;
 mod save;
+mod schedule_shutdown;
+mod set_maintenance;
 mod shutdown;
 mod tick;
+mod timed_maintenance_kick;
+mod timed_shutdown;
+mod update_metrics;
 
 impl World {
-    pub fn new(rx: UnboundedReceiver<Command>, pool: Pool) -> Self {
+    pub fn new(rx: UnboundedReceiver<Command>, pool: Pool, save_queue: SaveQueueHandle) -> Self {
         Self {
             rx,
             pool,
+            save_queue,
             players: HashMap::new(),
             accounts: Vec::new(),
             pending_logins: Vec::new(),
@@ -72,6 +88,16 @@ impl World {
             auto_pickup_ticks: 0,
             global_locked: false,
             connection_log: ConnectionLog::new(),
+            pending_shutdown: None,
+            shutdown_waiter: None,
+            maintenance: if SETTINGS.maintenance.enabled {
+                Some(SETTINGS.maintenance.message.to_owned())
+            } else {
+                None
+            },
+            maintenance_kick_at: None,
+            login_queue: VecDeque::new(),
+            login_queue_seconds: 0,
         }
     }
 
@@ -91,11 +117,6 @@ impl World {
                 let _ = respond_to.send(());
             }
 
-            Command::AddLoggedInAccount { account_id } => {
-                self.pending_logins.retain(|id| *id != account_id);
-                self.accounts.push(account_id);
-            }
-
             Command::AddPendingLogin { account_id } => {
                 self.pending_logins.push(account_id);
             }
@@ -192,10 +213,32 @@ impl World {
                 let _ = respond_to.send(self.get_character_by_name(&name).await);
             }
 
+            Command::GetCharacterWithAccount { name, respond_to } => {
+                self.get_character_with_account(name, respond_to)
+            }
+
+            Command::GetAuditLog {
+                name,
+                count,
+                respond_to,
+            } => self.get_audit_log(name, count, respond_to),
+
+            Command::GetBans { filter, respond_to } => self.get_bans(filter, respond_to),
+
+            Command::GetMaintenanceMessage { respond_to } => {
+                let _ = respond_to.send(self.maintenance.clone());
+            }
+
             Command::GetConnectionCount { respond_to } => {
                 let _ = respond_to.send(self.connection_log.len());
             }
 
+            Command::GetEconomy { respond_to } => self.get_economy(respond_to),
+
+            Command::GetGuilds { respond_to } => {
+                let _ = respond_to.send(self.guilds.clone());
+            }
+
             Command::GetIpConnectionCount { ip, respond_to } => {
                 let _ = respond_to.send(self.connection_log.get_num_of_connections(&ip));
             }
@@ -204,6 +247,12 @@ impl World {
                 let _ = respond_to.send(self.connection_log.get_last_connect(&ip));
             }
 
+            Command::GetItemTrace {
+                item_id,
+                name,
+                respond_to,
+            } => self.get_item_trace(item_id, name, respond_to),
+
             Command::GetMap { map_id, respond_to } => {
                 let maps = self.maps.as_ref().expect("maps not loaded");
                 match maps.get(&map_id) {
@@ -220,10 +269,23 @@ impl World {
                 }
             }
 
+            Command::GetMaps { respond_to } => {
+                let _ = respond_to.send(match self.maps.as_ref() {
+                    Some(maps) => maps.values().cloned().collect(),
+                    None => Vec::new(),
+                });
+            }
+
             Command::GetNextPlayerId { respond_to } => {
                 let _ = respond_to.send(self.get_next_player_id(300));
             }
 
+            Command::GetOnlineCharacters { respond_to } => self.get_online_characters(respond_to),
+
+            Command::GetParties { respond_to } => {
+                let _ = respond_to.send(self.parties.clone());
+            }
+
             Command::GetPlayer {
                 player_id,
                 respond_to,
@@ -242,6 +304,21 @@ impl World {
                 let _ = respond_to.send(self.get_player_party(player_id));
             }
 
+            Command::LogAdminAction(entry) => self.log_admin_action(entry),
+
+            Command::QueueLogin {
+                player_id,
+                account_id,
+                skip_queue,
+                respond_to,
+            } => {
+                let _ = respond_to.send(self.queue_login(player_id, account_id, skip_queue));
+            }
+
+            Command::IsShuttingDown { respond_to } => {
+                let _ = respond_to.send(self.pending_shutdown.is_some());
+            }
+
             Command::IsLoggedIn {
                 account_id,
                 respond_to,
@@ -295,6 +372,8 @@ impl World {
 
             Command::RequestPartyList { player_id } => self.refresh_party(player_id).await,
 
+            Command::Reload { target, respond_to } => self.reload(target, respond_to),
+
             Command::ReloadMap { map_id } => self.reload_map(map_id).await,
 
             Command::Save => self.save().await,
@@ -314,12 +393,31 @@ impl World {
                 experience,
             } => self.show_captcha(victim_name, experience),
 
-            Command::Shutdown { respond_to } => self.shutdown(respond_to).await,
+            Command::SetMaintenance {
+                enabled,
+                message,
+                admin_name,
+            } => self.set_maintenance(enabled, message, admin_name),
+
+            Command::ScheduleShutdown {
+                seconds,
+                reason,
+                restart,
+            } => self.schedule_shutdown(seconds, reason, restart).await,
+
+            Command::Shutdown { respond_to } => {
+                self.shutdown().await;
+                let _ = respond_to.send(());
+            }
 
             Command::Tick => {
                 self.tick().await;
             }
 
+            Command::WaitForShutdown { respond_to } => {
+                self.shutdown_waiter = Some(respond_to);
+            }
+
             Command::UpdatePartyHP {
                 player_id,
                 hp_percentage,
@@ -334,9 +432,10 @@ impl World {
                 victim_name,
                 admin_name,
                 duration,
+                scope,
                 silent,
             } => {
-                self.ban_player(victim_name, admin_name, duration, silent)
+                self.ban_player(victim_name, admin_name, duration, scope, silent)
                     .await
             }
 
@@ -363,7 +462,9 @@ impl World {
             Command::MutePlayer {
                 victim_name,
                 admin_name,
-            } => self.mute_player(victim_name, admin_name),
+                duration,
+                respond_to,
+            } => self.mute_player(victim_name, admin_name, duration, respond_to),
 
             Command::Quake { magnitude } => self.quake(magnitude),
 
@@ -379,6 +480,8 @@ impl World {
 
             Command::ToggleGlobal { admin_name } => self.toggle_global(admin_name),
 
+            Command::Unban { target, respond_to } => self.unban(target, respond_to),
+
             Command::UnfreezePlayer {
                 victim_name,
                 admin_name,
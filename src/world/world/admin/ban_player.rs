@@ -1,8 +1,9 @@
-use mysql_async::{params, prelude::Queryable, Row};
-use mysql_common::Value;
-
 use super::super::World;
-use crate::LANG;
+use crate::{
+    db::{params, Queryable, Row, Value},
+    world::BanScope,
+    LANG,
+};
 
 impl World {
     pub async fn ban_player(
@@ -10,6 +11,7 @@ impl World {
         victim_name: String,
         admin_name: String,
         duration: String,
+        scope: BanScope,
         silent: bool,
     ) {
         if let Some(player_id) = self.characters.get(&victim_name) {
@@ -20,7 +22,7 @@ impl World {
 
         if !silent {
             self.broadcast_server_message(&get_lang_string!(
-                &LANG.announce_remove,
+                &LANG.load().announce_remove,
                 victim = victim_name,
                 name = admin_name,
                 method = "banned"
@@ -64,6 +66,8 @@ impl World {
                 None => return,
             };
 
+            let hdid: Option<u32> = row.get("hdid");
+
             let duration = duration_str::parse(&duration);
 
             match conn
@@ -73,6 +77,8 @@ impl World {
                         "account_id" => account_id,
                         "admin_name" => &admin_name,
                         "ip" => &ip,
+                        "hdid" => hdid,
+                        "scope" => scope.as_str(),
                         "duration" => &match duration {
                             Ok(duration) => Value::from(format!("{}", duration.as_secs() / 60)),
                             Err(_) => Value::NULL,
@@ -1,8 +1,9 @@
 use eolib::protocol::{net::server::WarpEffect, Coords};
-use mysql_async::prelude::Queryable;
-use mysql_common::params;
 
-use crate::SETTINGS;
+use crate::{
+    db::{params, Queryable},
+    SETTINGS,
+};
 
 use super::super::World;
 
@@ -0,0 +1,99 @@
+use chrono::NaiveDateTime;
+use tokio::sync::oneshot;
+
+use crate::db::{params, Queryable, Row};
+
+use super::super::World;
+
+const DEFAULT_COUNT: i32 = 10;
+const MAX_COUNT: i32 = 50;
+
+struct AdminLog {
+    admin_name: String,
+    command: String,
+    args: String,
+    map_id: Option<i32>,
+    x: Option<i32>,
+    y: Option<i32>,
+    created_at: NaiveDateTime,
+}
+
+impl World {
+    pub fn get_audit_log(
+        &self,
+        name: Option<String>,
+        count: Option<i32>,
+        respond_to: oneshot::Sender<Result<(String, Vec<String>), String>>,
+    ) {
+        let limit = count.unwrap_or(DEFAULT_COUNT).clamp(1, MAX_COUNT);
+
+        let pool = self.pool.clone();
+        tokio::spawn(async move {
+            let mut conn = match pool.get_conn().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    error!("Failed to get connection from pool: {}", e);
+                    let _ = respond_to.send(Err("Database unavailable".to_string()));
+                    return;
+                }
+            };
+
+            let entries = match conn
+                .exec_map(
+                    include_str!("../../../sql/get_admin_log.sql"),
+                    params! {
+                        "name" => &name,
+                        "limit" => limit,
+                    },
+                    |mut row: Row| AdminLog {
+                        admin_name: row.take("admin_name").unwrap(),
+                        command: row.take("command").unwrap(),
+                        args: row.take("args").unwrap(),
+                        map_id: row.take("map").unwrap(),
+                        x: row.take("x").unwrap(),
+                        y: row.take("y").unwrap(),
+                        created_at: row.take("created_at").unwrap(),
+                    },
+                )
+                .await
+            {
+                Ok(entries) => entries,
+                Err(e) => {
+                    error!("Failed to get admin log: {}", e);
+                    let _ = respond_to.send(Err("Failed to get admin log".to_string()));
+                    return;
+                }
+            };
+
+            let mut lines: Vec<String> = entries
+                .iter()
+                .map(|entry| {
+                    let location = match (entry.map_id, entry.x, entry.y) {
+                        (Some(map_id), Some(x), Some(y)) => format!(" @ {} {},{}", map_id, x, y),
+                        _ => String::new(),
+                    };
+
+                    format!(
+                        "{} {}: ${} {}{}",
+                        entry.created_at.format("%Y-%m-%d %H:%M"),
+                        entry.admin_name,
+                        entry.command,
+                        entry.args,
+                        location
+                    )
+                })
+                .collect();
+
+            if lines.is_empty() {
+                lines.push("No entries found".to_string());
+            }
+
+            let title = match name {
+                Some(name) => format!("Audit log - {}", name),
+                None => "Audit log".to_string(),
+            };
+
+            let _ = respond_to.send(Ok((title, lines)));
+        });
+    }
+}
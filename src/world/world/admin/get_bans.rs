@@ -0,0 +1,56 @@
+use tokio::sync::oneshot;
+
+use crate::{
+    db::{params, Queryable, Row},
+    world::{Ban, BanScope},
+};
+
+use super::super::World;
+
+impl World {
+    pub fn get_bans(
+        &self,
+        filter: Option<String>,
+        respond_to: oneshot::Sender<Result<Vec<Ban>, String>>,
+    ) {
+        let pool = self.pool.clone();
+        tokio::spawn(async move {
+            let mut conn = match pool.get_conn().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    error!("Failed to get connection from pool: {}", e);
+                    let _ = respond_to.send(Err("Database unavailable".to_string()));
+                    return;
+                }
+            };
+
+            let bans = conn
+                .exec_map(
+                    query!("get_bans"),
+                    params! {
+                        "filter" => &filter,
+                    },
+                    |mut row: Row| Ban {
+                        id: row.take("id").unwrap(),
+                        account_name: row.take("account_name").unwrap(),
+                        ip: row.take("ip").unwrap(),
+                        hdid: row.take("hdid").unwrap(),
+                        scope: BanScope::parse(&row.take::<String, _>("scope").unwrap())
+                            .unwrap_or_default(),
+                        duration: row.take("duration").unwrap(),
+                        created_by: row.take("created_by").unwrap(),
+                        created_at: row.take("created_at").unwrap(),
+                    },
+                )
+                .await;
+
+            let _ = respond_to.send(match bans {
+                Ok(bans) => Ok(bans),
+                Err(e) => {
+                    error!("Failed to get bans: {}", e);
+                    Err("Failed to get bans".to_string())
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,96 @@
+use tokio::sync::oneshot;
+
+use crate::{
+    character::Character,
+    db::{params, Pool, Queryable, Row},
+    player::PlayerHandle,
+    world::{AccountInfo, CharacterWithAccount},
+};
+
+use super::super::World;
+
+impl World {
+    pub fn get_character_with_account(
+        &self,
+        name: String,
+        respond_to: oneshot::Sender<Result<Option<CharacterWithAccount>, String>>,
+    ) {
+        let target = self
+            .characters
+            .get(&name)
+            .and_then(|player_id| self.players.get(player_id))
+            .cloned();
+
+        let pool = self.pool.clone();
+
+        tokio::spawn(async move {
+            let _ = respond_to.send(
+                get_character_with_account(&pool, &name, target)
+                    .await
+                    .map_err(|e| {
+                        error!("Failed to get character: {}", e);
+                        "Failed to get character".to_string()
+                    }),
+            );
+        });
+    }
+}
+
+/// Gets a character from the game if online otherwise loads it from the
+/// database, along with some details about the account it belongs to.
+pub async fn get_character_with_account(
+    pool: &Pool,
+    name: &str,
+    target: Option<PlayerHandle>,
+) -> Result<Option<CharacterWithAccount>, Box<dyn std::error::Error + Send + Sync>> {
+    // The saved row can be minutes behind an online character so only
+    // fall back to it when they aren't in game
+    if let Some(target) = target {
+        let character = target.get_character().await?;
+        let mut conn = pool.get_conn().await?;
+        let account = match conn
+            .exec_first(
+                include_str!("../../../sql/get_account_info.sql"),
+                params! {
+                    "account_id" => character.account_id,
+                },
+            )
+            .await?
+        {
+            Some(row) => get_account_info(row),
+            None => return Ok(None),
+        };
+
+        return Ok(Some((character, account)));
+    }
+
+    let mut conn = pool.get_conn().await?;
+
+    let mut row: Row = match conn
+        .exec_first(
+            include_str!("../../../sql/get_character_account_info.sql"),
+            params! {
+                "character_name" => name,
+            },
+        )
+        .await?
+    {
+        Some(row) => row,
+        None => return Ok(None),
+    };
+
+    let character_id: i32 = row.take("id").unwrap();
+    let account = get_account_info(row);
+
+    let mut character = Character::load(&mut conn, character_id).await?;
+    character.calculate_stats();
+
+    Ok(Some((Box::new(character), account)))
+}
+
+fn get_account_info(mut row: Row) -> AccountInfo {
+    AccountInfo {
+        name: row.take("account_name").unwrap(),
+        last_login_ip: row.take("last_login_ip").unwrap(),
+    }
+}
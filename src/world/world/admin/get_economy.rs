@@ -0,0 +1,155 @@
+use chrono::{Duration, Utc};
+use tokio::sync::oneshot;
+
+use crate::{
+    economy::{
+        get_economy_snapshot, get_economy_snapshot_items, get_gold_totals, get_item_supply,
+        get_top_gold_holders,
+    },
+    ITEM_DB, SETTINGS,
+};
+
+use super::super::World;
+
+const SUPPLY_CHANGES: usize = 5;
+
+impl World {
+    pub fn get_economy(&self, respond_to: oneshot::Sender<Result<(String, Vec<String>), String>>) {
+        let pool = self.pool.clone();
+        tokio::spawn(async move {
+            let mut conn = match pool.get_conn().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    error!("Failed to get connection from pool: {}", e);
+                    let _ = respond_to.send(Err("Database unavailable".to_string()));
+                    return;
+                }
+            };
+
+            let gold = match get_gold_totals(&mut conn).await {
+                Ok(gold) => gold,
+                Err(e) => {
+                    error!("Failed to get gold totals: {}", e);
+                    let _ = respond_to.send(Err("Failed to get gold totals".to_string()));
+                    return;
+                }
+            };
+
+            let supply = match get_item_supply(&mut conn).await {
+                Ok(supply) => supply,
+                Err(e) => {
+                    error!("Failed to get item supply: {}", e);
+                    let _ = respond_to.send(Err("Failed to get item supply".to_string()));
+                    return;
+                }
+            };
+
+            let holders = match get_top_gold_holders(&mut conn, SETTINGS.economy.top_holders).await
+            {
+                Ok(holders) => holders,
+                Err(e) => {
+                    error!("Failed to get top gold holders: {}", e);
+                    let _ = respond_to.send(Err("Failed to get top gold holders".to_string()));
+                    return;
+                }
+            };
+
+            let yesterday = (Utc::now() - Duration::try_days(1).unwrap()).naive_utc();
+            let snapshot = match get_economy_snapshot(&mut conn, yesterday).await {
+                Ok(snapshot) => snapshot,
+                Err(e) => {
+                    error!("Failed to get economy snapshot: {}", e);
+                    let _ = respond_to.send(Err("Failed to get economy snapshot".to_string()));
+                    return;
+                }
+            };
+
+            let mut lines = Vec::new();
+
+            let change = |now: i64, then: Option<i64>| match then {
+                Some(then) => format!(" ({:+})", now - then),
+                None => String::new(),
+            };
+
+            let old_gold = snapshot.as_ref().map(|snapshot| snapshot.gold);
+            lines.push(format!(
+                "Gold: {}{}",
+                gold.total(),
+                change(gold.total(), old_gold.map(|gold| gold.total()))
+            ));
+            lines.push(format!(
+                "Inventories: {}{}",
+                gold.inventory,
+                change(gold.inventory, old_gold.map(|gold| gold.inventory))
+            ));
+            lines.push(format!(
+                "Banks: {}{}",
+                gold.bank,
+                change(gold.bank, old_gold.map(|gold| gold.bank))
+            ));
+            lines.push(format!(
+                "Guild banks: {}{}",
+                gold.guild,
+                change(gold.guild, old_gold.map(|gold| gold.guild))
+            ));
+            lines.push(String::new());
+
+            lines.push("Top holders".to_string());
+            for (index, (name, gold)) in holders.iter().enumerate() {
+                lines.push(format!("{}. {} {}", index + 1, name, gold));
+            }
+            lines.push(String::new());
+
+            match snapshot {
+                Some(snapshot) => {
+                    let old_supply = match get_economy_snapshot_items(&mut conn, snapshot.id).await
+                    {
+                        Ok(old_supply) => old_supply,
+                        Err(e) => {
+                            error!("Failed to get economy snapshot items: {}", e);
+                            let _ = respond_to
+                                .send(Err("Failed to get economy snapshot items".to_string()));
+                            return;
+                        }
+                    };
+
+                    let mut changes: Vec<(i32, i64, i64)> = supply
+                        .keys()
+                        .chain(old_supply.keys())
+                        .filter(|item_id| **item_id != 1)
+                        .map(|item_id| {
+                            let now = supply.get(item_id).copied().unwrap_or(0);
+                            let then = old_supply.get(item_id).copied().unwrap_or(0);
+                            (*item_id, now, now - then)
+                        })
+                        .filter(|(_, _, change)| *change != 0)
+                        .collect();
+                    changes.sort_by_key(|(item_id, _, change)| (-change.abs(), *item_id));
+                    changes.dedup_by_key(|(item_id, _, _)| *item_id);
+
+                    let item_db = ITEM_DB.load();
+                    lines.push(format!(
+                        "Supply changes since {}",
+                        snapshot.created_at.format("%Y-%m-%d %H:%M")
+                    ));
+                    for (item_id, amount, change) in changes.iter().take(SUPPLY_CHANGES) {
+                        let name = match (*item_id as usize)
+                            .checked_sub(1)
+                            .and_then(|index| item_db.items.get(index))
+                        {
+                            Some(item) => item.name.to_owned(),
+                            None => format!("#{}", item_id),
+                        };
+                        lines.push(format!("{} {} ({:+})", name, amount, change));
+                    }
+                    if changes.is_empty() {
+                        lines.push("No changes".to_string());
+                    }
+                }
+                None => lines.push("No snapshot from yesterday yet".to_string()),
+            }
+
+            let _ = respond_to.send(Ok(("Economy".to_string(), lines)));
+        });
+    }
+}
@@ -0,0 +1,106 @@
+use chrono::NaiveDateTime;
+use tokio::sync::oneshot;
+
+use crate::{
+    db::{params, Queryable, Row},
+    ITEM_DB,
+};
+
+use super::super::World;
+
+const MAX_ENTRIES: i32 = 50;
+
+struct ItemLedgerEntry {
+    amount: i32,
+    source: String,
+    destination: String,
+    map_id: i32,
+    x: i32,
+    y: i32,
+    created_at: NaiveDateTime,
+}
+
+impl World {
+    pub fn get_item_trace(
+        &self,
+        item_id: i32,
+        name: Option<String>,
+        respond_to: oneshot::Sender<Result<(String, Vec<String>), String>>,
+    ) {
+        let pool = self.pool.clone();
+        tokio::spawn(async move {
+            let mut conn = match pool.get_conn().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    error!("Failed to get connection from pool: {}", e);
+                    let _ = respond_to.send(Err("Database unavailable".to_string()));
+                    return;
+                }
+            };
+
+            let entries = match conn
+                .exec_map(
+                    include_str!("../../../sql/get_item_ledger.sql"),
+                    params! {
+                        "item_id" => item_id,
+                        "name" => &name,
+                        "limit" => MAX_ENTRIES,
+                    },
+                    |mut row: Row| ItemLedgerEntry {
+                        amount: row.take("amount").unwrap(),
+                        source: row.take("source").unwrap(),
+                        destination: row.take("destination").unwrap(),
+                        map_id: row.take("map").unwrap(),
+                        x: row.take("x").unwrap(),
+                        y: row.take("y").unwrap(),
+                        created_at: row.take("created_at").unwrap(),
+                    },
+                )
+                .await
+            {
+                Ok(entries) => entries,
+                Err(e) => {
+                    error!("Failed to get item ledger: {}", e);
+                    let _ = respond_to.send(Err("Failed to get item ledger".to_string()));
+                    return;
+                }
+            };
+
+            let mut lines: Vec<String> = entries
+                .iter()
+                .map(|entry| {
+                    format!(
+                        "{} {} -> {} x{} @ {} {},{}",
+                        entry.created_at.format("%Y-%m-%d %H:%M"),
+                        entry.source,
+                        entry.destination,
+                        entry.amount,
+                        entry.map_id,
+                        entry.x,
+                        entry.y
+                    )
+                })
+                .collect();
+
+            if lines.is_empty() {
+                lines.push("No entries found".to_string());
+            }
+
+            let item_db = ITEM_DB.load();
+            let item_name = match (item_id as usize)
+                .checked_sub(1)
+                .and_then(|index| item_db.items.get(index))
+            {
+                Some(item) => item.name.to_owned(),
+                None => format!("#{}", item_id),
+            };
+
+            let title = match name {
+                Some(name) => format!("Item trace - {} - {}", item_name, name),
+                None => format!("Item trace - {}", item_name),
+            };
+
+            let _ = respond_to.send(Ok((title, lines)));
+        });
+    }
+}
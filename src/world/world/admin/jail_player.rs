@@ -1,8 +1,9 @@
 use eolib::protocol::{net::server::WarpEffect, Coords};
-use mysql_async::prelude::Queryable;
-use mysql_common::params;
 
-use crate::{LANG, SETTINGS};
+use crate::{
+    db::{params, Queryable},
+    LANG, SETTINGS,
+};
 
 use super::super::World;
 
@@ -25,7 +26,7 @@ impl World {
         }
 
         self.broadcast_server_message(&get_lang_string!(
-            &LANG.announce_remove,
+            &LANG.load().announce_remove,
             victim = victim_name,
             name = admin_name,
             method = "jailed"
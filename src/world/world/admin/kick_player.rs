@@ -18,7 +18,7 @@ impl World {
 
         if !silent {
             self.broadcast_server_message(&get_lang_string!(
-                &LANG.announce_remove,
+                &LANG.load().announce_remove,
                 victim = victim_name,
                 name = admin_name,
                 method = "kicked"
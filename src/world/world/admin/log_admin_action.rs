@@ -0,0 +1,39 @@
+use crate::{
+    db::{params, Queryable},
+    world::AdminLogEntry,
+};
+
+use super::super::World;
+
+impl World {
+    pub fn log_admin_action(&self, entry: AdminLogEntry) {
+        let pool = self.pool.clone();
+        tokio::spawn(async move {
+            let mut conn = match pool.get_conn().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    error!("Failed to get connection from pool: {}", e);
+                    return;
+                }
+            };
+
+            if let Err(e) = conn
+                .exec_drop(
+                    include_str!("../../../sql/create_admin_log.sql"),
+                    params! {
+                        "admin_name" => &entry.admin_name,
+                        "command" => &entry.command,
+                        "args" => &entry.args,
+                        "target" => &entry.target,
+                        "map" => entry.map_id,
+                        "x" => entry.coords.map(|coords| coords.x),
+                        "y" => entry.coords.map(|coords| coords.y),
+                    },
+                )
+                .await
+            {
+                error!("Failed to create admin log entry: {}", e);
+            }
+        });
+    }
+}
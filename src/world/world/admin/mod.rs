@@ -1,14 +1,22 @@
 mod ban_player;
 mod free_player;
 mod freeze_player;
+mod get_audit_log;
+mod get_bans;
+mod get_character_with_account;
+mod get_economy;
+mod get_item_trace;
 mod jail_player;
 mod kick_player;
+mod log_admin_action;
 mod mute_player;
 mod quake;
+mod reload;
 mod report_player;
 mod request_player_info;
 mod request_player_inventory;
 mod send_admin_message;
 mod show_captcha;
 mod toggle_global;
+mod unban;
 mod unfreeze_player;
@@ -1,35 +1,113 @@
+use chrono::{Duration, Utc};
 use eolib::protocol::net::{server::TalkSpecServerPacket, PacketAction, PacketFamily};
+use tokio::sync::oneshot;
 
-use crate::LANG;
+use crate::{
+    db::{params, Queryable},
+    LANG, SETTINGS,
+};
 
 use super::super::World;
 
 impl World {
-    // TODO: Server side mute?
-    // https://github.com/sorokya/reoserv/issues/187
-    pub fn mute_player(&mut self, victim_name: String, admin_name: String) {
-        let player_id = match self.characters.get(&victim_name) {
-            Some(player_id) => player_id,
-            None => return,
+    pub fn mute_player(
+        &self,
+        victim_name: String,
+        admin_name: String,
+        duration: String,
+        respond_to: oneshot::Sender<Result<(), String>>,
+    ) {
+        let duration = if duration.is_empty() {
+            SETTINGS.world.default_mute_duration.to_owned()
+        } else {
+            duration
         };
 
-        let player = match self.players.get(player_id) {
-            Some(player) => player,
-            None => return,
+        let muted_until = match duration_str::parse(&duration)
+            .map_err(|e| e.to_string())
+            .and_then(|duration| Duration::from_std(duration).map_err(|e| e.to_string()))
+        {
+            Ok(duration) => Utc::now() + duration,
+            Err(e) => {
+                let _ = respond_to.send(Err(format!(
+                    "Invalid mute duration \"{}\": {}",
+                    duration, e
+                )));
+                return;
+            }
         };
 
-        player.send(
-            PacketAction::Spec,
-            PacketFamily::Talk,
-            &TalkSpecServerPacket {
-                admin_name: admin_name.to_owned(),
-            },
-        );
-
-        self.broadcast_server_message(&get_lang_string!(
-            &LANG.announce_mute,
-            victim = victim_name,
-            name = admin_name
-        ));
+        let target = self.characters.get(&victim_name).and_then(|player_id| {
+            self.players
+                .get(player_id)
+                .map(|player| (*player_id, player.to_owned()))
+        });
+
+        let players: Vec<_> = self.players.values().cloned().collect();
+        let pool = self.pool.clone();
+
+        tokio::spawn(async move {
+            let updated = match pool.get_conn().await {
+                Ok(mut conn) => match conn
+                    .exec_drop(
+                        include_str!("../../../sql/update_character_muted_until.sql"),
+                        params! {
+                            "name" => &victim_name,
+                            "muted_until" => muted_until.naive_utc(),
+                        },
+                    )
+                    .await
+                {
+                    Ok(()) => Ok(conn.affected_rows() > 0),
+                    Err(e) => {
+                        error!("Failed to mute player: {}", e);
+                        Err(format!("Failed to mute {}", victim_name))
+                    }
+                },
+                Err(e) => {
+                    error!("Failed to get connection from pool: {}", e);
+                    Err("Database unavailable".to_string())
+                }
+            };
+
+            // Online players are muted in game even if the database is down,
+            // their next save stores it
+            match (&target, updated) {
+                (Some(_), _) | (None, Ok(true)) => {}
+                (None, Ok(false)) => {
+                    let _ = respond_to.send(Err(format!("{} does not exist", victim_name)));
+                    return;
+                }
+                (None, Err(message)) => {
+                    let _ = respond_to.send(Err(message));
+                    return;
+                }
+            }
+
+            if let Some((player_id, player)) = target {
+                player.send(
+                    PacketAction::Spec,
+                    PacketFamily::Talk,
+                    &TalkSpecServerPacket {
+                        admin_name: admin_name.to_owned(),
+                    },
+                );
+
+                if let Ok(map) = player.get_map().await {
+                    map.mute(player_id, muted_until);
+                }
+            }
+
+            let message = get_lang_string!(
+                &LANG.load().announce_mute,
+                victim = victim_name,
+                name = admin_name
+            );
+            for player in players {
+                player.send_server_message(&message);
+            }
+
+            let _ = respond_to.send(Ok(()));
+        });
     }
 }
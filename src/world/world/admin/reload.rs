@@ -0,0 +1,156 @@
+use crate::{
+    arenas::Arenas,
+    commands::Commands,
+    formulas::Formulas,
+    lang::Lang,
+    player_commands::PlayerCommands,
+    pub_files::PubFiles,
+    spawns::Spawns,
+    utils::{
+        load_class_file, load_drop_file, load_exp_table, load_inn_file, load_item_file,
+        load_npc_file, load_quests, load_shop_file, load_skill_master_file, load_spell_file,
+        load_talk_file,
+    },
+    ARENAS, CLASS_DB, COMMANDS, DROP_DB, EXP_TABLE, FORMULAS, INN_DB, ITEM_DB, LANG, NPC_DB,
+    PLAYER_COMMANDS, PUB_FILES, QUEST_DB, SHOP_DB, SKILL_MASTER_DB, SPAWNS, SPELL_DB, TALK_DB,
+};
+
+use tokio::sync::oneshot;
+
+use super::super::World;
+
+impl World {
+    pub fn reload(&self, target: String, respond_to: oneshot::Sender<Result<(), String>>) {
+        // Load everything up front so a bad file leaves the old data untouched
+        let result = match target.as_str() {
+            "pubs" => load_pubs(),
+            "quests" => load_quests_db(),
+            "formulas" => load_formulas(),
+            "arenas" => load_arenas(),
+            "commands" => load_commands(),
+            "lang" => load_lang(),
+            "spawns" => load_spawns(),
+            "all" => load_all(),
+            _ => {
+                let _ = respond_to.send(Err(format!(
+                    "Unknown reload target: {}. (expected pubs, quests, formulas, arenas, commands, lang, spawns or all)",
+                    target
+                )));
+                return;
+            }
+        };
+
+        let store = match result {
+            Ok(store) => store,
+            Err(e) => {
+                error!("Failed to reload {}: {}", target, e);
+                let _ = respond_to.send(Err(format!("Failed to reload {}: {}", target, e)));
+                return;
+            }
+        };
+
+        store();
+
+        info!("Reloaded {}", target);
+        let _ = respond_to.send(Ok(()));
+
+        if target == "pubs" || target == "all" {
+            for player in self.players.values() {
+                player.send_pub_files();
+            }
+        }
+
+        if target == "spawns" || target == "all" {
+            if let Some(maps) = self.maps.as_ref() {
+                for map in maps.values() {
+                    map.reload_spawns();
+                }
+            }
+        }
+    }
+}
+
+/// Swaps loaded data into the globals, run only once every load has succeeded
+type Store = Box<dyn FnOnce()>;
+
+fn load_all() -> Result<Store, Box<dyn std::error::Error>> {
+    let stores = [
+        load_pubs()?,
+        load_quests_db()?,
+        load_formulas()?,
+        load_arenas()?,
+        load_commands()?,
+        load_lang()?,
+        load_spawns()?,
+    ];
+
+    Ok(Box::new(move || {
+        for store in stores {
+            store();
+        }
+    }))
+}
+
+fn load_pubs() -> Result<Store, Box<dyn std::error::Error>> {
+    let class_file = load_class_file()?;
+    let drop_file = load_drop_file()?;
+    let inn_file = load_inn_file()?;
+    let item_file = load_item_file()?;
+    let npc_file = load_npc_file()?;
+    let shop_file = load_shop_file()?;
+    let skill_master_file = load_skill_master_file()?;
+    let spell_file = load_spell_file()?;
+    let talk_file = load_talk_file()?;
+
+    Ok(Box::new(move || {
+        CLASS_DB.store(class_file);
+        DROP_DB.store(drop_file);
+        INN_DB.store(inn_file);
+        ITEM_DB.store(item_file);
+        NPC_DB.store(npc_file);
+        SHOP_DB.store(shop_file);
+        SKILL_MASTER_DB.store(skill_master_file);
+        SPELL_DB.store(spell_file);
+        TALK_DB.store(talk_file);
+
+        PUB_FILES.store(PubFiles::new());
+    }))
+}
+
+fn load_quests_db() -> Result<Store, Box<dyn std::error::Error>> {
+    let quests = load_quests();
+    Ok(Box::new(move || QUEST_DB.store(quests)))
+}
+
+fn load_formulas() -> Result<Store, Box<dyn std::error::Error>> {
+    let formulas = Formulas::new()?;
+    let exp_table = load_exp_table(&formulas)?;
+    Ok(Box::new(move || {
+        FORMULAS.store(formulas);
+        EXP_TABLE.store(exp_table);
+    }))
+}
+
+fn load_arenas() -> Result<Store, Box<dyn std::error::Error>> {
+    let arenas = Arenas::new()?;
+    Ok(Box::new(move || ARENAS.store(arenas)))
+}
+
+fn load_commands() -> Result<Store, Box<dyn std::error::Error>> {
+    let commands = Commands::new()?;
+    let player_commands = PlayerCommands::new()?;
+    Ok(Box::new(move || {
+        COMMANDS.store(commands);
+        PLAYER_COMMANDS.store(player_commands);
+    }))
+}
+
+fn load_lang() -> Result<Store, Box<dyn std::error::Error>> {
+    let lang = Lang::new()?;
+    Ok(Box::new(move || LANG.store(lang)))
+}
+
+fn load_spawns() -> Result<Store, Box<dyn std::error::Error>> {
+    let spawns = Spawns::new()?;
+    Ok(Box::new(move || SPAWNS.store(spawns)))
+}
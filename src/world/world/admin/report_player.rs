@@ -8,11 +8,13 @@ use eolib::{
         PacketAction, PacketFamily,
     },
 };
-use mysql_async::prelude::Queryable;
-use mysql_common::params;
 
 use super::super::World;
-use crate::{utils::capitalize, SETTINGS};
+use crate::{
+    db::{params, Queryable},
+    utils::capitalize,
+    SETTINGS,
+};
 
 impl World {
     pub async fn report_player(&self, player_id: i32, reportee_name: String, message: String) {
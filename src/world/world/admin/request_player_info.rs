@@ -1,47 +1,75 @@
 use eolib::protocol::net::{
     server::{
-        AdminInteractTellServerPacket, BigCoords, CharacterBaseStats, CharacterElementalStats,
-        CharacterSecondaryStatsInfoLookup, CharacterStatsInfoLookup,
+        AdminInteractTellServerPacket, BigCoords, CharacterBaseStats, CharacterDetails,
+        CharacterElementalStats, CharacterSecondaryStatsInfoLookup, CharacterStatsInfoLookup,
+        PaperdollReplyServerPacket,
     },
     PacketAction, PacketFamily,
 };
 
-use super::super::World;
+use super::{super::World, get_character_with_account::get_character_with_account};
 
 impl World {
-    // TODO: Work when player offline
     pub fn request_player_info(&mut self, player_id: i32, name: String) {
         let player = match self.players.get(&player_id) {
             Some(player) => player.to_owned(),
             None => return,
         };
 
-        let target_player_id = match self.characters.get(&name) {
-            Some(player_id) => player_id,
-            None => return,
-        };
+        let target = self
+            .characters
+            .get(&name)
+            .and_then(|player_id| self.players.get(player_id))
+            .cloned();
 
-        let target = match self.players.get(target_player_id) {
-            Some(player) => player.to_owned(),
-            None => return,
-        };
+        let online = target.is_some();
+        let pool = self.pool.clone();
 
         tokio::spawn(async move {
-            let character = match target.get_character().await {
-                Ok(character) => character,
+            let (character, account) = match get_character_with_account(&pool, &name, target).await
+            {
+                Ok(Some(result)) => result,
+                Ok(None) => {
+                    player.send_server_message(&format!("{} does not exist", name));
+                    return;
+                }
                 Err(e) => {
                     error!("Failed to get character: {}", e);
                     return;
                 }
             };
 
+            if !online {
+                // Offline players can't be clicked on so show their paperdoll too
+                player.send(
+                    PacketAction::Reply,
+                    PacketFamily::Paperdoll,
+                    &PaperdollReplyServerPacket {
+                        details: CharacterDetails {
+                            name: character.name.to_owned(),
+                            home: character.home.to_owned(),
+                            admin: character.admin_level,
+                            partner: character.partner.to_owned().unwrap_or_default(),
+                            title: character.title.to_owned().unwrap_or_default(),
+                            guild: character.guild_name.to_owned().unwrap_or_default(),
+                            guild_rank: character.guild_rank_string.to_owned().unwrap_or_default(),
+                            player_id: 0,
+                            class_id: character.class,
+                            gender: character.gender,
+                        },
+                        equipment: character.equipment.to_owned(),
+                        icon: character.get_icon(false),
+                    },
+                );
+            }
+
             player.send(
                 PacketAction::Tell,
                 PacketFamily::AdminInteract,
                 &AdminInteractTellServerPacket {
                     name,
                     usage: character.usage,
-                    exp: character.experience,
+                    exp: character.get_packet_experience(),
                     level: character.level,
                     map_id: character.map_id,
                     map_coords: BigCoords {
@@ -74,6 +102,14 @@ impl World {
                     weight: character.get_weight(),
                 },
             );
+
+            player.send_server_message(&format!(
+                "{} account: {} last login ip: {}{}",
+                character.name,
+                account.name,
+                account.last_login_ip.unwrap_or_default(),
+                if online { "" } else { " (offline)" }
+            ));
         });
     }
 }
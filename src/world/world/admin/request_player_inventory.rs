@@ -2,29 +2,32 @@ use eolib::protocol::net::{
     server::AdminInteractListServerPacket, PacketAction, PacketFamily, ThreeItem,
 };
 
-use super::super::World;
+use super::{super::World, get_character_with_account::get_character_with_account};
 
 impl World {
-    // TODO: Work when player offline
     pub fn request_player_inventory(&mut self, player_id: i32, name: String) {
         let player = match self.players.get(&player_id) {
             Some(player) => player.to_owned(),
             None => return,
         };
 
-        let target_player_id = match self.characters.get(&name) {
-            Some(player_id) => player_id,
-            None => return,
-        };
+        let target = self
+            .characters
+            .get(&name)
+            .and_then(|player_id| self.players.get(player_id))
+            .cloned();
 
-        let target = match self.players.get(target_player_id) {
-            Some(player) => player.to_owned(),
-            None => return,
-        };
+        let online = target.is_some();
+        let pool = self.pool.clone();
 
         tokio::spawn(async move {
-            let character = match target.get_character().await {
-                Ok(character) => character,
+            let (character, account) = match get_character_with_account(&pool, &name, target).await
+            {
+                Ok(Some(result)) => result,
+                Ok(None) => {
+                    player.send_server_message(&format!("{} does not exist", name));
+                    return;
+                }
                 Err(e) => {
                     error!("Failed to get character: {}", e);
                     return;
@@ -46,9 +49,17 @@ impl World {
                         })
                         .collect(),
                     gold_bank: character.gold_bank,
-                    inventory: character.items,
+                    inventory: character.items.to_owned(),
                 },
             );
+
+            player.send_server_message(&format!(
+                "{} account: {} last login ip: {}{}",
+                character.name,
+                account.name,
+                account.last_login_ip.unwrap_or_default(),
+                if online { "" } else { " (offline)" }
+            ));
         });
     }
 }
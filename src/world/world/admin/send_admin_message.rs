@@ -8,10 +8,12 @@ use eolib::{
         PacketAction, PacketFamily,
     },
 };
-use mysql_async::prelude::Queryable;
-use mysql_common::params;
 
-use crate::{utils::capitalize, SETTINGS};
+use crate::{
+    db::{params, Queryable},
+    utils::capitalize,
+    SETTINGS,
+};
 
 use super::super::World;
 
@@ -7,14 +7,14 @@ impl World {
         if self.global_locked {
             self.global_locked = false;
             self.broadcast_server_message(&get_lang_string!(
-                &LANG.announce_global,
+                &LANG.load().announce_global,
                 name = admin_name,
                 state = "off"
             ));
         } else {
             self.global_locked = true;
             self.broadcast_server_message(&get_lang_string!(
-                &LANG.announce_global,
+                &LANG.load().announce_global,
                 name = admin_name,
                 state = "off"
             ));
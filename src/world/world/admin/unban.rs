@@ -0,0 +1,69 @@
+use tokio::sync::oneshot;
+
+use crate::{
+    db::{params, Queryable},
+    world::BanScope,
+};
+
+use super::super::World;
+
+impl World {
+    pub fn unban(
+        &self,
+        target: String,
+        respond_to: oneshot::Sender<Result<Vec<BanScope>, String>>,
+    ) {
+        let pool = self.pool.clone();
+        tokio::spawn(async move {
+            let mut conn = match pool.get_conn().await {
+                Ok(conn) => conn,
+                Err(e) => {
+                    error!("Failed to get connection from pool: {}", e);
+                    let _ = respond_to.send(Err("Database unavailable".to_string()));
+                    return;
+                }
+            };
+
+            // fetched first so the caller can say which IP/hardware bans went with it
+            let scopes = match conn
+                .exec_map(
+                    include_str!("../../../sql/get_unban_scopes.sql"),
+                    params! {
+                        "target" => &target,
+                    },
+                    |scope: String| BanScope::parse(&scope).unwrap_or_default(),
+                )
+                .await
+            {
+                Ok(scopes) => scopes,
+                Err(e) => {
+                    error!("Failed to get bans for {}: {}", target, e);
+                    let _ = respond_to.send(Err(format!("Failed to unban {}", target)));
+                    return;
+                }
+            };
+
+            if scopes.is_empty() {
+                let _ = respond_to.send(Ok(scopes));
+                return;
+            }
+
+            let result = conn
+                .exec_drop(
+                    include_str!("../../../sql/delete_ban.sql"),
+                    params! {
+                        "target" => &target,
+                    },
+                )
+                .await;
+
+            let _ = respond_to.send(match result {
+                Ok(()) => Ok(scopes),
+                Err(e) => {
+                    error!("Failed to unban {}: {}", target, e);
+                    Err(format!("Failed to unban {}", target))
+                }
+            });
+        });
+    }
+}
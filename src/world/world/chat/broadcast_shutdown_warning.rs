@@ -0,0 +1,24 @@
+use crate::LANG;
+
+use super::super::World;
+
+impl World {
+    pub fn broadcast_shutdown_warning(&self, seconds: i64) {
+        let pending_shutdown = match self.pending_shutdown.as_ref() {
+            Some(pending_shutdown) => pending_shutdown,
+            None => return,
+        };
+
+        let message = if pending_shutdown.restart {
+            get_lang_string!(&LANG.load().restart_warning, seconds = seconds)
+        } else {
+            get_lang_string!(&LANG.load().shutdown_warning, seconds = seconds)
+        };
+
+        if pending_shutdown.reason.is_empty() {
+            self.broadcast_server_message(&message);
+        } else {
+            self.broadcast_server_message(&format!("{} ({})", message, pending_shutdown.reason));
+        }
+    }
+}
@@ -4,4 +4,5 @@ mod broadcast_global_message;
 mod broadcast_guild_message;
 mod broadcast_party_message;
 mod broadcast_server_message;
+mod broadcast_shutdown_warning;
 mod send_private_message;
@@ -15,6 +15,11 @@ impl World {
         };
 
         if let Ok(from_character) = player.get_character().await {
+            if from_character.is_muted() {
+                from_character.send_muted_message();
+                return;
+            }
+
             match self.get_character_by_name(to).await {
                 Ok(character) => {
                     if let Some(player) = character.player.as_ref() {
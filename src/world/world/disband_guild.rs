@@ -1,5 +1,4 @@
-use mysql_async::prelude::Queryable;
-use mysql_common::params;
+use crate::db::{params, Queryable};
 
 use super::World;
 
@@ -20,9 +20,12 @@ impl World {
         }
 
         self.players.remove(&player_id);
+        self.login_queue
+            .retain(|queued_login| queued_login.player_id != player_id);
 
         if account_id > 0 {
             self.accounts.retain(|id| *id != account_id);
+            self.pending_logins.retain(|id| *id != account_id);
         }
 
         if self.characters.contains_key(character_name) {
@@ -43,6 +46,8 @@ impl World {
             }
         }
 
+        self.admit_queued_logins();
+
         let _ = respond_to.send(());
     }
 }
@@ -0,0 +1,27 @@
+use tokio::sync::oneshot;
+
+use crate::character::Character;
+
+use super::World;
+
+impl World {
+    pub fn get_online_characters(&self, respond_to: oneshot::Sender<Vec<Character>>) {
+        let players: Vec<_> = self
+            .characters
+            .values()
+            .filter_map(|player_id| self.players.get(player_id).cloned())
+            .collect();
+
+        tokio::spawn(async move {
+            let mut characters = Vec::with_capacity(players.len());
+
+            for player in players {
+                if let Ok(character) = player.get_character().await {
+                    characters.push(*character);
+                }
+            }
+
+            let _ = respond_to.send(characters);
+        });
+    }
+}
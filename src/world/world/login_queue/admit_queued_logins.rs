@@ -0,0 +1,22 @@
+use super::super::World;
+
+impl World {
+    pub fn admit_queued_logins(&mut self) {
+        while self.has_free_login_slot() {
+            let queued_login = match self.login_queue.pop_front() {
+                Some(queued_login) => queued_login,
+                None => return,
+            };
+
+            let player = match self.players.get(&queued_login.player_id) {
+                Some(player) => player,
+                None => continue,
+            };
+
+            self.pending_logins
+                .retain(|id| *id != queued_login.account_id);
+            self.accounts.push(queued_login.account_id);
+            player.admit_login();
+        }
+    }
+}
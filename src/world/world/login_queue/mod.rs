@@ -0,0 +1,3 @@
+mod admit_queued_logins;
+mod queue_login;
+mod timed_login_queue;
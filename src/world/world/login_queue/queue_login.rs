@@ -0,0 +1,34 @@
+use crate::{world::QueuedLogin, SETTINGS};
+
+use super::super::World;
+
+impl World {
+    /// Returns the player's position in the login queue, or None if they
+    /// were admitted straight away
+    pub fn queue_login(
+        &mut self,
+        player_id: i32,
+        account_id: i32,
+        skip_queue: bool,
+    ) -> Option<usize> {
+        if !SETTINGS.login_queue.enabled
+            || skip_queue
+            || (self.login_queue.is_empty() && self.has_free_login_slot())
+        {
+            self.pending_logins.retain(|id| *id != account_id);
+            self.accounts.push(account_id);
+            return None;
+        }
+
+        self.login_queue.push_back(QueuedLogin {
+            player_id,
+            account_id,
+        });
+
+        Some(self.login_queue.len())
+    }
+
+    pub(super) fn has_free_login_slot(&self) -> bool {
+        (self.accounts.len() as i32) < SETTINGS.login_queue.max_accounts
+    }
+}
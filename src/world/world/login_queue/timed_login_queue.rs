@@ -0,0 +1,28 @@
+use crate::{LANG, SETTINGS};
+
+use super::super::World;
+
+impl World {
+    pub fn timed_login_queue(&mut self) {
+        if self.login_queue.is_empty() {
+            self.login_queue_seconds = 0;
+            return;
+        }
+
+        self.login_queue_seconds += 1;
+        if self.login_queue_seconds < SETTINGS.login_queue.update_interval {
+            return;
+        }
+
+        self.login_queue_seconds = 0;
+
+        for (index, queued_login) in self.login_queue.iter().enumerate() {
+            if let Some(player) = self.players.get(&queued_login.player_id) {
+                player.send_server_message(&get_lang_string!(
+                    &LANG.load().login_queue_position,
+                    position = index + 1
+                ));
+            }
+        }
+    }
+}
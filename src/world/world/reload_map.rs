@@ -1,14 +1,4 @@
-use std::{
-    io::{Read, Seek, SeekFrom},
-    path::Path,
-};
-
-use bytes::Bytes;
-use eolib::{
-    data::{EoReader, EoSerialize},
-    protocol::map::Emf,
-};
-use tokio::fs::File;
+use crate::utils::{get_map_file_path, load_map_file};
 
 use super::World;
 
@@ -24,53 +14,22 @@ impl World {
             None => return,
         };
 
-        let raw_path = format!("data/maps/{:0>5}.emf", map_id);
-        let path = Path::new(&raw_path);
-
-        if !Path::exists(path) {
-            error!("Map file not found: {}", raw_path);
-            return;
-        }
-
-        let mut raw_file = match File::open(path).await {
-            Ok(file) => file.into_std().await,
-            Err(e) => {
-                error!("Failed to open file: {}", e);
+        let path = match get_map_file_path(map_id) {
+            Some(path) => path,
+            None => {
+                error!("Map file not found: {}", map_id);
                 return;
             }
         };
 
-        let file_size = match raw_file.metadata() {
-            Ok(meta) => meta.len(),
-            Err(e) => {
-                error!("Failed to get file metadata: {}", e);
-                return;
-            }
-        };
-
-        let mut data_buf: Vec<u8> = Vec::new();
-        if let Err(e) = raw_file.seek(SeekFrom::Start(0)) {
-            error!("Failed to seek to file start: {}", e);
-            return;
-        }
-
-        if let Err(e) = raw_file.read_to_end(&mut data_buf) {
-            error!("Failed to read file: {}", e);
-            return;
-        }
-
-        let data_buf = Bytes::from(data_buf);
-
-        let reader = EoReader::new(data_buf);
-
-        let file = match Emf::deserialize(&reader) {
+        let (file, file_size) = match load_map_file(&path) {
             Ok(file) => file,
             Err(e) => {
-                error!("Failed to deserialize Emf: {}", e);
+                error!("Failed to load map {}: {}", path.display(), e);
                 return;
             }
         };
 
-        map.reload(Box::new(file), file_size as i32);
+        map.reload(Box::new(file), file_size);
     }
 }
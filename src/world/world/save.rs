@@ -1,11 +1,17 @@
+use crate::metrics::SAVE_DURATION;
+
 use super::World;
 
 impl World {
     pub async fn save(&mut self) {
+        let timer = SAVE_DURATION.start_timer();
+
         if let Some(maps) = self.maps.as_ref() {
             for map in maps.values() {
                 map.save().await;
             }
         }
+
+        timer.observe_duration();
     }
 }
@@ -0,0 +1,38 @@
+use chrono::{Duration, Utc};
+
+use crate::world::PendingShutdown;
+
+use super::World;
+
+/// Longest countdown a shutdown or restart can be scheduled with (one day)
+const MAX_COUNTDOWN: i32 = 60 * 60 * 24;
+
+impl World {
+    pub async fn schedule_shutdown(&mut self, seconds: i32, reason: String, restart: bool) {
+        let seconds = seconds.clamp(0, MAX_COUNTDOWN);
+
+        info!(
+            "Server {} scheduled in {} seconds{}",
+            if restart { "restart" } else { "shutdown" },
+            seconds,
+            if reason.is_empty() {
+                String::new()
+            } else {
+                format!(" ({})", reason)
+            }
+        );
+
+        self.pending_shutdown = Some(PendingShutdown {
+            at: Utc::now() + Duration::seconds(seconds.into()),
+            reason,
+            restart,
+            last_warning: seconds.into(),
+        });
+
+        if seconds <= 0 {
+            self.timed_shutdown().await;
+        } else {
+            self.broadcast_shutdown_warning(seconds.into());
+        }
+    }
+}
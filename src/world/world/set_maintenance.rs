@@ -0,0 +1,42 @@
+use chrono::{Duration, Utc};
+
+use crate::{LANG, SETTINGS};
+
+use super::World;
+
+impl World {
+    pub fn set_maintenance(&mut self, enabled: bool, message: String, admin_name: String) {
+        if !enabled {
+            info!("Maintenance mode disabled by {}", admin_name);
+            self.maintenance = None;
+            self.maintenance_kick_at = None;
+            self.broadcast_server_message(&get_lang_string!(
+                &LANG.load().maintenance_off,
+                name = admin_name
+            ));
+            return;
+        }
+
+        info!("Maintenance mode enabled by {}", admin_name);
+
+        self.maintenance = Some(if message.is_empty() {
+            SETTINGS.maintenance.message.to_owned()
+        } else {
+            message
+        });
+
+        self.broadcast_server_message(&get_lang_string!(
+            &LANG.load().maintenance_on,
+            name = admin_name
+        ));
+
+        if SETTINGS.maintenance.kick_online {
+            self.maintenance_kick_at =
+                Some(Utc::now() + Duration::seconds(SETTINGS.maintenance.kick_delay));
+            self.broadcast_server_message(&get_lang_string!(
+                &LANG.load().maintenance_kick_warning,
+                seconds = SETTINGS.maintenance.kick_delay
+            ));
+        }
+    }
+}
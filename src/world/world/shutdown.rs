@@ -2,13 +2,17 @@ use eolib::{
     data::{EoSerialize, EoWriter},
     protocol::net::{server::MessageCloseServerPacket, PacketAction, PacketFamily},
 };
-use tokio::sync::oneshot;
 
 use super::World;
 
 impl World {
-    pub async fn shutdown(&mut self, respond_to: oneshot::Sender<()>) {
+    pub async fn shutdown(&mut self) {
+        for player in self.players.values() {
+            player.cancel_trade();
+        }
+
         self.save().await;
+        self.save_queue.flush().await;
 
         let packet = MessageCloseServerPacket::new();
 
@@ -26,7 +30,5 @@ impl World {
 
         // wait a bit for the packets to be sent
         tokio::time::sleep(std::time::Duration::from_millis(100)).await;
-
-        let _ = respond_to.send(());
     }
 }
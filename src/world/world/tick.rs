@@ -1,4 +1,4 @@
-use crate::SETTINGS;
+use crate::{metrics::TICK_DURATION, SETTINGS};
 
 use super::World;
 
@@ -11,6 +11,8 @@ impl World {
             None => return,
         };
 
+        let timer = TICK_DURATION.start_timer();
+
         if SETTINGS.auto_pickup.enabled {
             self.auto_pickup_ticks += 1;
         }
@@ -85,6 +87,12 @@ impl World {
 
         if self.second_ticks >= ONE_SECOND {
             self.second_ticks = 0;
+            self.timed_shutdown().await;
+            self.timed_maintenance_kick();
+            self.timed_login_queue();
+            if SETTINGS.metrics.enabled {
+                self.update_metrics();
+            }
         }
 
         if self.auto_pickup_ticks >= SETTINGS.auto_pickup.rate && SETTINGS.auto_pickup.enabled {
@@ -122,5 +130,7 @@ impl World {
         if self.polymorph_ticks >= SETTINGS.world.polymorph_rate {
             self.polymorph_ticks = 0;
         }  
+
+        timer.observe_duration();
     }
 }
@@ -0,0 +1,73 @@
+use chrono::Utc;
+
+use crate::{
+    db::{params, Pool, Queryable},
+    player::PlayerHandle,
+    SETTINGS,
+};
+
+use super::World;
+
+impl World {
+    pub fn timed_maintenance_kick(&mut self) {
+        match self.maintenance_kick_at {
+            Some(kick_at) if kick_at <= Utc::now() => self.maintenance_kick_at = None,
+            _ => return,
+        }
+
+        let players: Vec<_> = self.players.values().cloned().collect();
+        let pool = self.pool.clone();
+        tokio::spawn(async move {
+            for player in players {
+                let admin_level = match get_admin_level(&player, &pool).await {
+                    Some(admin_level) => admin_level,
+                    // not logged in yet, login.rs turns them away
+                    None => continue,
+                };
+
+                if admin_level >= i32::from(SETTINGS.maintenance.admin_level) {
+                    continue;
+                }
+
+                player.close("Server maintenance".to_string());
+            }
+        });
+    }
+}
+
+// Players at character select (or queued) are judged by the best admin level
+// on their account, the same as login.rs does
+async fn get_admin_level(player: &PlayerHandle, pool: &Pool) -> Option<i32> {
+    if let Ok(character) = player.get_character().await {
+        return Some(i32::from(character.admin_level));
+    }
+
+    let account_id = match player.get_account_id().await {
+        Ok(0) | Err(_) => return None,
+        Ok(account_id) => account_id,
+    };
+
+    let mut conn = match pool.get_conn().await {
+        Ok(conn) => conn,
+        Err(e) => {
+            error!("Failed to get connection from pool: {}", e);
+            return Some(0);
+        }
+    };
+
+    match conn
+        .exec_first::<i32, _, _>(
+            include_str!("../../sql/get_account_admin_level.sql"),
+            params! {
+                "account_id" => account_id,
+            },
+        )
+        .await
+    {
+        Ok(admin_level) => Some(admin_level.unwrap_or_default()),
+        Err(e) => {
+            error!("Failed to get admin level for account {}: {}", account_id, e);
+            Some(0)
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use chrono::Utc;
+
+use crate::SETTINGS;
+
+use super::World;
+
+impl World {
+    pub async fn timed_shutdown(&mut self) {
+        let pending_shutdown = match self.pending_shutdown.as_mut() {
+            Some(pending_shutdown) => pending_shutdown,
+            None => return,
+        };
+
+        let remaining_ms = (pending_shutdown.at - Utc::now()).num_milliseconds();
+        if remaining_ms > 0 {
+            let remaining = (remaining_ms + 999) / 1000;
+            let last_warning = pending_shutdown.last_warning;
+            if SETTINGS
+                .shutdown
+                .warnings
+                .iter()
+                .any(|warning| *warning >= remaining && *warning < last_warning)
+            {
+                pending_shutdown.last_warning = remaining;
+                self.broadcast_shutdown_warning(remaining);
+            }
+            return;
+        }
+
+        let restart = pending_shutdown.restart;
+        self.pending_shutdown = None;
+
+        info!(
+            "{} server...",
+            if restart {
+                "Restarting"
+            } else {
+                "Shutting down"
+            }
+        );
+
+        self.shutdown().await;
+
+        if let Some(respond_to) = self.shutdown_waiter.take() {
+            let _ = respond_to.send(if restart {
+                SETTINGS.shutdown.restart_exit_code
+            } else {
+                0
+            });
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::metrics::{set_map_stats, CONNECTIONS, PLAYERS_IN_GAME};
+
+use super::World;
+
+impl World {
+    pub fn update_metrics(&self) {
+        CONNECTIONS.set(self.connection_log.len() as i64);
+        PLAYERS_IN_GAME.set(self.characters.len() as i64);
+
+        let maps = match self.maps {
+            Some(ref maps) => maps.values().cloned().collect::<Vec<_>>(),
+            None => return,
+        };
+
+        tokio::spawn(async move {
+            let mut map_stats = Vec::with_capacity(maps.len());
+            for map in maps {
+                map_stats.push(map.get_stats().await);
+            }
+
+            set_map_stats(&map_stats);
+        });
+    }
+}
@@ -1,25 +1,35 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use eolib::protocol::net::{server::PartyExpShare, PartyRequestType};
-use mysql_async::Pool;
 use tokio::sync::{mpsc, oneshot};
 
-use crate::{character::Character, map::MapHandle, player::PlayerHandle};
+use crate::{
+    character::Character, db::Pool, map::MapHandle, player::PlayerHandle,
+    save_queue::SaveQueueHandle,
+};
 
-use super::{world::World, Command, Party};
+use super::{world::World, AdminLogEntry, Ban, BanScope, CharacterWithAccount, Command, Party};
 
 #[derive(Debug, Clone)]
 pub struct WorldHandle {
     tx: mpsc::UnboundedSender<Command>,
     pub is_alive: bool,
+    pub save_queue: SaveQueueHandle,
 }
 
 impl WorldHandle {
     pub fn new(pool: Pool) -> Self {
         let (tx, rx) = mpsc::unbounded_channel();
-        let world = World::new(rx, pool);
+        let save_queue = SaveQueueHandle::new(pool.clone());
+        let world = World::new(rx, pool, save_queue.clone());
         tokio::spawn(run_world(world));
 
-        Self { tx, is_alive: true }
+        Self {
+            tx,
+            is_alive: true,
+            save_queue,
+        }
     }
 
     pub fn accept_party_request(
@@ -35,10 +45,6 @@ impl WorldHandle {
         });
     }
 
-    pub fn add_logged_in_account(&self, account_id: i32) {
-        let _ = self.tx.send(Command::AddLoggedInAccount { account_id });
-    }
-
     pub fn add_pending_login(&self, account_id: i32) {
         let _ = self.tx.send(Command::AddPendingLogin { account_id });
     }
@@ -90,12 +96,14 @@ impl WorldHandle {
         &self,
         victim_name: String,
         duration: String,
+        scope: BanScope,
         admin_name: String,
         silent: bool,
     ) {
         let _ = self.tx.send(Command::BanPlayer {
             victim_name,
             duration,
+            scope,
             admin_name,
             silent,
         });
@@ -194,6 +202,45 @@ impl WorldHandle {
         rx.await.unwrap()
     }
 
+    /// Gets a character whether they are online or not, along with their account
+    pub async fn get_character_with_account(
+        &self,
+        name: String,
+    ) -> Result<Option<CharacterWithAccount>, String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetCharacterWithAccount {
+            name,
+            respond_to: tx,
+        });
+        rx.await.unwrap()
+    }
+
+    pub async fn get_economy(&self) -> Result<(String, Vec<String>), String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetEconomy { respond_to: tx });
+        rx.await.unwrap()
+    }
+
+    pub async fn get_guilds(&self) -> HashMap<String, Vec<i32>> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetGuilds { respond_to: tx });
+        rx.await.unwrap()
+    }
+
+    pub async fn get_item_trace(
+        &self,
+        item_id: i32,
+        name: Option<String>,
+    ) -> Result<(String, Vec<String>), String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetItemTrace {
+            item_id,
+            name,
+            respond_to: tx,
+        });
+        rx.await.unwrap()
+    }
+
     pub async fn get_map(
         &self,
         map_id: i32,
@@ -206,6 +253,12 @@ impl WorldHandle {
         rx.await.unwrap()
     }
 
+    pub async fn get_maps(&self) -> Vec<MapHandle> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetMaps { respond_to: tx });
+        rx.await.unwrap()
+    }
+
     pub async fn get_next_player_id(
         &self,
     ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
@@ -214,6 +267,37 @@ impl WorldHandle {
         Ok(rx.await.unwrap())
     }
 
+    pub async fn get_audit_log(
+        &self,
+        name: Option<String>,
+        count: Option<i32>,
+    ) -> Result<(String, Vec<String>), String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetAuditLog {
+            name,
+            count,
+            respond_to: tx,
+        });
+        rx.await.unwrap()
+    }
+
+    pub async fn get_bans(&self, filter: Option<String>) -> Result<Vec<Ban>, String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetBans {
+            filter,
+            respond_to: tx,
+        });
+        rx.await.unwrap()
+    }
+
+    pub async fn get_maintenance_message(&self) -> Option<String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(Command::GetMaintenanceMessage { respond_to: tx });
+        rx.await.unwrap()
+    }
+
     pub async fn get_connection_count(&self) -> i32 {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::GetConnectionCount { respond_to: tx });
@@ -238,6 +322,20 @@ impl WorldHandle {
         rx.await.unwrap()
     }
 
+    pub async fn get_online_characters(&self) -> Vec<Character> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self
+            .tx
+            .send(Command::GetOnlineCharacters { respond_to: tx });
+        rx.await.unwrap()
+    }
+
+    pub async fn get_parties(&self) -> Vec<Party> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::GetParties { respond_to: tx });
+        rx.await.unwrap()
+    }
+
     pub async fn get_player(&self, player_id: i32) -> Option<PlayerHandle> {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::GetPlayer {
@@ -262,6 +360,28 @@ impl WorldHandle {
         rx.await.unwrap()
     }
 
+    pub async fn queue_login(
+        &self,
+        player_id: i32,
+        account_id: i32,
+        skip_queue: bool,
+    ) -> Option<usize> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::QueueLogin {
+            player_id,
+            account_id,
+            skip_queue,
+            respond_to: tx,
+        });
+        rx.await.unwrap()
+    }
+
+    pub async fn is_shutting_down(&self) -> bool {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::IsShuttingDown { respond_to: tx });
+        rx.await.unwrap()
+    }
+
     pub async fn is_logged_in(&self, account_id: i32) -> bool {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::IsLoggedIn {
@@ -295,11 +415,24 @@ impl WorldHandle {
         rx.await.unwrap();
     }
 
-    pub fn mute_player(&self, victim_name: String, admin_name: String) {
+    pub fn log_admin_action(&self, entry: AdminLogEntry) {
+        let _ = self.tx.send(Command::LogAdminAction(entry));
+    }
+
+    pub async fn mute_player(
+        &self,
+        victim_name: String,
+        duration: String,
+        admin_name: String,
+    ) -> Result<(), String> {
+        let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::MutePlayer {
             victim_name,
             admin_name,
+            duration,
+            respond_to: tx,
         });
+        rx.await.unwrap()
     }
 
     pub fn quake(&self, magnitude: i32) {
@@ -354,6 +487,15 @@ impl WorldHandle {
         let _ = self.tx.send(Command::RequestPlayerList { player_id });
     }
 
+    pub async fn reload(&self, target: String) -> Result<(), String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::Reload {
+            target,
+            respond_to: tx,
+        });
+        rx.await.unwrap()
+    }
+
     pub fn reload_map(&self, map_id: i32) {
         let _ = self.tx.send(Command::ReloadMap { map_id });
     }
@@ -383,6 +525,22 @@ impl WorldHandle {
         });
     }
 
+    pub fn set_maintenance(&self, enabled: bool, message: String, admin_name: String) {
+        let _ = self.tx.send(Command::SetMaintenance {
+            enabled,
+            message,
+            admin_name,
+        });
+    }
+
+    pub fn schedule_shutdown(&self, seconds: i32, reason: String, restart: bool) {
+        let _ = self.tx.send(Command::ScheduleShutdown {
+            seconds,
+            reason,
+            restart,
+        });
+    }
+
     pub async fn shutdown(&self) {
         let (tx, rx) = oneshot::channel();
         let _ = self.tx.send(Command::Shutdown { respond_to: tx });
@@ -397,6 +555,15 @@ impl WorldHandle {
         let _ = self.tx.send(Command::ToggleGlobal { admin_name });
     }
 
+    pub async fn unban(&self, target: String) -> Result<Vec<BanScope>, String> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::Unban {
+            target,
+            respond_to: tx,
+        });
+        rx.await.unwrap()
+    }
+
     pub fn unfreeze_player(&self, victim_name: String, admin_name: String) {
         let _ = self.tx.send(Command::UnfreezePlayer {
             victim_name,
@@ -417,6 +584,12 @@ impl WorldHandle {
             exp_gains,
         });
     }
+
+    pub async fn wait_for_shutdown(&self) -> i32 {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.tx.send(Command::WaitForShutdown { respond_to: tx });
+        rx.await.unwrap()
+    }
 }
 
 async fn run_world(mut world: World) {